@@ -14,9 +14,18 @@
 # limitations under the License.
 #
 
-import json, pyodbc
+import json, pyodbc, sys
 
-conn = pyodbc.connect("Driver={DuckDB Driver}")
+# Usage:
+#   python resources\duckdb_settings.py > src\common\all_settings.rs
+#   python resources\duckdb_settings.py --tsv "DuckDB Driver (nightly)" > <driver dir>\duckdb_settings.tsv
+
+args = sys.argv[1:]
+tsv = "--tsv" in args
+drivers = [a for a in args if "--tsv" != a]
+driver = drivers[0] if len(drivers) > 0 else "DuckDB Driver"
+
+conn = pyodbc.connect("Driver={" + driver + "}")
 cur = conn.cursor()
 cur.execute("""
 select
@@ -30,6 +39,15 @@ order by lower(name)
 """)
 tuples = cur.fetchall()
 
+# settings catalog placed next to the driver DLL
+if tsv:
+    def escape(field):
+        text = "" if field is None else str(field)
+        return text.replace("\\", "\\\\").replace("\t", "\\t").replace("\n", "\\n")
+    for tup in tuples:
+        print("\t".join([escape(field) for field in tup]))
+    sys.exit(0)
+
 # prefix
 print(r"""/*
  * Copyright 2025, DuckDB Labs
@@ -27,20 +27,32 @@ pub(super) struct AddDsnDialogControls {
 
     pub(super) name_label: nwg::Label,
     pub(super) name_input: nwg::TextInput,
+    pub(super) name_check_label: nwg::Label,
     pub(super) dsn_type_label: nwg::Label,
     pub(super) dsn_type_combo: nwg::ComboBox<String>,
+    pub(super) driver_label: nwg::Label,
+    pub(super) driver_combo: nwg::ComboBox<String>,
+    pub(super) based_on_label: nwg::Label,
+    pub(super) based_on_combo: nwg::ComboBox<String>,
+    pub(super) review_button: nwg::Button,
     pub(super) dbpath_label: nwg::Label,
     pub(super) dbpath_input: nwg::TextInput,
     pub(super) dbpath_button: nwg::Button,
     pub(super) dbpath_chooser: nwg::FileDialog,
+    pub(super) new_db_button: nwg::Button,
     pub(super) use_memory_db_checkbox: nwg::CheckBox,
     pub(super) init_label: nwg::Label,
     pub(super) init_input: nwg::TextInput,
     pub(super) init_button: nwg::Button,
     pub(super) init_chooser: nwg::FileDialog,
 
+    pub(super) inspect_button: nwg::Button,
     pub(super) save_button: nwg::Button,
     pub(super) cancel_button: nwg::Button,
+
+    pub(super) inspect_notice: ui::SyncNotice,
+    pub(super) new_db_notice: ui::SyncNotice,
+    pub(super) review_notice: ui::SyncNotice,
 }
 
 impl ui::Controls for AddDsnDialogControls {
@@ -59,7 +71,7 @@ impl ui::Controls for AddDsnDialogControls {
             .build(&mut self.icon)?;
 
         nwg::Window::builder()
-            .size((380, 210))
+            .size((460, 285))
             .icon(Some(&self.icon))
             .center(true)
             .title("Add Data Source")
@@ -76,6 +88,12 @@ impl ui::Controls for AddDsnDialogControls {
             .font(Some(&self.font_normal))
             .parent(&self.window)
             .build(&mut self.name_input)?;
+        nwg::Label::builder()
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.name_check_label)?;
 
         nwg::Label::builder()
             .text("Type:")
@@ -94,6 +112,36 @@ impl ui::Controls for AddDsnDialogControls {
             .parent(&self.window)
             .build(&mut self.dsn_type_combo)?;
 
+        nwg::Label::builder()
+            .text("Driver:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.driver_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.driver_combo)?;
+
+        nwg::Label::builder()
+            .text("Based on:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.based_on_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.based_on_combo)?;
+        nwg::Button::builder()
+            .text("Review")
+            .font(Some(&self.font_normal))
+            .enabled(false)
+            .parent(&self.window)
+            .build(&mut self.review_button)?;
+
         nwg::Label::builder()
             .text("Database:")
             .font(Some(&self.font_normal))
@@ -114,6 +162,11 @@ impl ui::Controls for AddDsnDialogControls {
             .title("Choose database file")
             .action(nwg::FileDialogAction::Open)
             .build(&mut self.dbpath_chooser)?;
+        nwg::Button::builder()
+            .text("New database...")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.new_db_button)?;
 
         nwg::CheckBox::builder()
             .check_state(nwg::CheckBoxState::Unchecked)
@@ -143,6 +196,11 @@ impl ui::Controls for AddDsnDialogControls {
             .action(nwg::FileDialogAction::Open)
             .build(&mut self.init_chooser)?;
 
+        nwg::Button::builder()
+            .text("Inspect init SQL")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.inspect_button)?;
         nwg::Button::builder()
             .text("Save")
             .font(Some(&self.font_normal))
@@ -155,6 +213,16 @@ impl ui::Controls for AddDsnDialogControls {
             .parent(&self.window)
             .build(&mut self.cancel_button)?;
 
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.inspect_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.new_db_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.review_notice)?;
+
         self.layout.build(&self)?;
 
         Ok(())
@@ -164,11 +232,16 @@ impl ui::Controls for AddDsnDialogControls {
         ui::tab_order_builder()
             .control(&self.name_input)
             .control(&self.dsn_type_combo)
+            .control(&self.driver_combo)
+            .control(&self.based_on_combo)
+            .control(&self.review_button)
             .control(&self.dbpath_input)
             .control(&self.dbpath_button)
+            .control(&self.new_db_button)
             .control(&self.use_memory_db_checkbox)
             .control(&self.init_input)
             .control(&self.init_button)
+            .control(&self.inspect_button)
             .control(&self.save_button)
             .control(&self.cancel_button)
             .build();
@@ -16,6 +16,7 @@
 
 use super::*;
 use crate::common::DsnType;
+use crate::common::DuckDbDriver;
 
 #[derive(Default)]
 pub struct AddDsnDialog {
@@ -23,14 +24,73 @@ pub struct AddDsnDialog {
 
     added_dsn: AddDsnDialogResult,
     args: AddDsnDialogArgs,
+    drivers: Vec<DuckDbDriver>,
+    existing_dsns: Vec<ExistingDsn>,
+    sources: Vec<(String, Vec<RegistrySetting>)>,
+    inherited: Vec<(RegistrySetting, bool)>,
+    inspect_dialog_join_handle: ui::PopupJoinHandle<ReportDialogResult>,
+    new_db_dialog_join_handle: ui::PopupJoinHandle<NewDbDialogResult>,
+    review_dialog_join_handle: ui::PopupJoinHandle<ReportDialogResult>,
 }
 
 impl AddDsnDialog {
 
     pub(super) fn on_name_input(&mut self, _: nwg::EventData) {
+        let name = self.c.name_input.text();
+        let message = if name.is_empty() {
+            "".to_string()
+        } else {
+            self.check_name(&name).message()
+        };
+        self.c.name_check_label.set_text(&message);
         self.update_save_button_state();
     }
 
+    pub(super) fn on_based_on_changed(&mut self, _: nwg::EventData) {
+        let settings = match self.c.based_on_combo.selection() {
+            Some(idx) => self.sources[idx].1.clone(),
+            None => vec!()
+        };
+        self.inherited.clear();
+        self.c.init_input.set_text("");
+        for st in settings {
+            if registry::SESSION_INIT_SQL_FILE_SETTING_NAME == st.name {
+                self.c.init_input.set_text(&st.value);
+            } else if registry::DRIVER_SETTING_NAME != st.name && registry::DATABASE_SETTING_NAME != st.name {
+                self.inherited.push((st, true));
+            }
+        }
+        self.update_review_button();
+    }
+
+    pub(super) fn open_review_dialog(&mut self, _: nwg::EventData) {
+        let source = self.c.based_on_combo.selection_string().unwrap_or_default();
+        let rows = self.inherited.iter()
+            .map(|(st, _)| vec!(st.name.clone(), st.value.clone()))
+            .collect();
+        let toggles = self.inherited.iter()
+            .map(|(_, included)| *included)
+            .collect();
+        let args = ReportDialogArgs::new(&self.c.review_notice, "Inherited Settings",
+            &format!("Settings copied from: {}, double-click a row to include or exclude it", source),
+            vec!(("Setting", 200), ("Value", 260)), rows, Some("Apply"))
+            .with_toggles(toggles);
+        self.c.window.set_enabled(false);
+        self.review_dialog_join_handle = ReportDialog::popup(args);
+    }
+
+    pub(super) fn await_review_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.review_notice.receive();
+        let res = self.review_dialog_join_handle.join();
+        if res.applied {
+            for (idx, (_, included)) in self.inherited.iter_mut().enumerate() {
+                *included = res.selected.contains(&idx);
+            }
+            self.update_review_button();
+        }
+    }
+
     pub(super) fn on_choose_db_file(&mut self, _: nwg::EventData) {
         if let Ok(dir) = std::env::current_dir() {
             if let Some(d) = dir.to_str() {
@@ -47,6 +107,28 @@ impl AddDsnDialog {
         }
     }
 
+    pub(super) fn open_new_db_dialog(&mut self, _: nwg::EventData) {
+        let driver = match self.c.driver_combo.selection() {
+            Some(idx) => &self.drivers[idx],
+            None => return
+        };
+        let args = NewDbDialogArgs::new(&self.c.new_db_notice, driver, self.c.dbpath_input.text().trim());
+        self.c.window.set_enabled(false);
+        self.new_db_dialog_join_handle = NewDbDialog::popup(args);
+    }
+
+    pub(super) fn await_new_db_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.new_db_notice.receive();
+        let res = self.new_db_dialog_join_handle.join();
+        if let Some(path) = res.created_path {
+            self.c.use_memory_db_checkbox.set_check_state(nwg::CheckBoxState::Unchecked);
+            self.on_memory_checkbox_changed(nwg::EventData::NoData);
+            self.c.dbpath_input.set_text(&path);
+            self.update_save_button_state();
+        }
+    }
+
     pub(super) fn on_choose_init_file(&mut self, _: nwg::EventData) {
         if let Ok(dir) = std::env::current_dir() {
             if let Some(d) = dir.to_str() {
@@ -69,16 +151,38 @@ impl AddDsnDialog {
             self.c.dbpath_input.set_text("");
             self.c.dbpath_input.set_readonly(true);
             self.c.dbpath_button.set_enabled(false);
+            self.c.new_db_button.set_enabled(false);
         } else {
             self.c.dbpath_input.set_readonly(false);
             self.c.dbpath_button.set_enabled(true);
+            self.c.new_db_button.set_enabled(true);
         }
         self.update_save_button_state();
     }
 
+    pub(super) fn open_inspect_dialog(&mut self, _: nwg::EventData) {
+        let path = self.c.init_input.text().trim().to_string();
+        if path.is_empty() {
+            return;
+        }
+        let inspection = init_sql::inspect_file(&path);
+        let args = ReportDialogArgs::new(&self.c.inspect_notice, "Session Init SQL File", &inspection.summary(),
+            vec!(("Line", 50), ("Statement", 360), ("Warning", 220)), inspection.report_rows(), None);
+        self.c.window.set_enabled(false);
+        self.inspect_dialog_join_handle = ReportDialog::popup(args);
+    }
+
+    pub(super) fn await_inspect_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.inspect_notice.receive();
+        let _ = self.inspect_dialog_join_handle.join();
+    }
+
     pub(super) fn on_save_button(&mut self, _: nwg::EventData) {
         let name = self.c.name_input.text();
-        if name.is_empty() {
+        let name_check = self.check_name(&name);
+        if !name_check.is_valid() {
+            ui::message_box_error(&format!("Invalid Data Source name: '{}'\r\n\r\n{}", name, name_check.message()));
             return;
         }
         let in_memory = self.c.use_memory_db_checkbox.check_state() == nwg::CheckBoxState::Checked;
@@ -90,6 +194,10 @@ impl AddDsnDialog {
         if dbpath.is_empty() {
             return;
         }
+        let dbpath = match self.confirm_db_path(&dbpath) {
+            Some(path) => path,
+            None => return
+        };
         let session_init_sql_file = self.c.init_input.text().trim().to_string();
         let dsn_type_opt = self.c.dsn_type_combo.selection_string();
         let dsn_type_st = dsn_type_opt.unwrap_or("".to_string());
@@ -101,9 +209,37 @@ impl AddDsnDialog {
         } else {
             DsnType::USER
         };
-        match registry::create_dsn(dsn_type, &name, &dbpath, &session_init_sql_file) {
+        let driver = match self.c.driver_combo.selection() {
+            Some(idx) => self.drivers[idx].clone(),
+            None => return
+        };
+        let mut new_settings = vec!((registry::DATABASE_SETTING_NAME.to_string(), dbpath.clone()));
+        if !session_init_sql_file.is_empty() {
+            new_settings.push((registry::SESSION_INIT_SQL_FILE_SETTING_NAME.to_string(), session_init_sql_file.clone()));
+        }
+        for (st, _) in self.inherited.iter().filter(|(_, included)| *included) {
+            new_settings.push((st.name.clone(), st.value.clone()));
+        }
+        if let Err(e) = Policy::load().and_then(|pl| pl.check_complete(&new_settings)) {
+            ui::message_box_error(&e.to_string());
+            return;
+        }
+        match registry::create_dsn(dsn_type.clone(), &driver, &name, &dbpath, &session_init_sql_file) {
             Ok(()) => {
-                self.added_dsn = AddDsnDialogResult::success(&name);
+                let mut failed: Vec<String> = vec!();
+                for (st, _) in self.inherited.iter().filter(|(_, included)| *included) {
+                    let res = registry::set_dsn_value_with_type(
+                        dsn_type.clone(), &driver.bitness, &name, &st.name, &st.value, &st.value_type);
+                    if let Err(e) = res {
+                        failed.push(format!("{}: {}", st.name, e));
+                    }
+                }
+                if !failed.is_empty() {
+                    ui::message_box_error(&format!(
+                        "Data Source: '{}' is created, but some of the inherited settings cannot be set:\r\n\r\n{}",
+                        name, failed.join("\r\n")));
+                }
+                self.added_dsn = AddDsnDialogResult::success(&registry::display_name(&name, &driver.bitness));
                 self.close(nwg::EventData::NoData)
             },
             Err(e) => ui::message_box_error(&format!(
@@ -111,11 +247,75 @@ impl AddDsnDialog {
         }
     }
 
+    fn confirm_db_path(&mut self, dbpath: &str) -> Option<String> {
+        let read_only = self.inherited_read_only();
+        let mut check = db_path::check_database_path(dbpath, read_only);
+        if let Some(abs) = check.absolute_path.clone() {
+            let convert = ui::message_box_warning_yn(&format!(
+                "{}\r\n\r\nWould you like to convert it to the absolute path?", check.relative_warning().unwrap_or_default()));
+            if convert {
+                self.c.dbpath_input.set_text(&abs);
+                check = db_path::check_database_path(&abs, read_only);
+            }
+        }
+        if !check.problems.is_empty() {
+            ui::message_box_error(&format!(
+                "Invalid database path: '{}'\r\n\r\n{}", check.path, check.problems.join("\r\n")));
+            return None;
+        }
+        if !check.warnings.is_empty() {
+            let proceed = ui::message_box_warning_yn(&format!(
+                "Database path: '{}' ({})\r\n\r\n{}\r\n\r\nWould you like to proceed?",
+                check.path, check.kind.label(), check.warnings.join("\r\n")));
+            if !proceed {
+                return None;
+            }
+        }
+        Some(check.path)
+    }
+
+    fn inherited_read_only(&self) -> bool {
+        self.inherited.iter()
+            .filter(|(_, included)| *included)
+            .find(|(st, _)| db_path::ACCESS_MODE_SETTING_NAME == st.name)
+            .map(|(st, _)| db_path::is_read_only_access_mode(&st.value))
+            .unwrap_or(false)
+    }
+
+    fn update_review_button(&self) {
+        let included = self.inherited.iter()
+            .filter(|(_, included)| *included)
+            .count();
+        if self.inherited.is_empty() {
+            self.c.review_button.set_text("Review");
+        } else {
+            self.c.review_button.set_text(&format!("Review ({}/{})", included, self.inherited.len()));
+        }
+        self.c.review_button.set_enabled(!self.inherited.is_empty());
+    }
+
+    fn selected_dsn_type(&self) -> DsnType {
+        match self.c.dsn_type_combo.selection_string() {
+            Some(st) if "System" == st => DsnType::SYSTEM,
+            _ => DsnType::USER
+        }
+    }
+
+    fn check_name(&self, name: &str) -> DsnNameCheck {
+        let bitness = match self.c.driver_combo.selection() {
+            Some(idx) => self.drivers[idx].bitness.clone(),
+            None => Bitness::X64
+        };
+        dsn_name::check_dsn_name(name, &self.selected_dsn_type(), &bitness, &self.existing_dsns)
+    }
+
     fn update_save_button_state(&mut self) {
-        let has_name = self.c.name_input.text().len() > 0;
+        let name = self.c.name_input.text();
+        let has_name = !name.is_empty() && self.check_name(&name).is_valid();
         let has_db = self.c.dbpath_input.text().len() > 0;
         let in_memory = self.c.use_memory_db_checkbox.check_state() == nwg::CheckBoxState::Checked;
-        let can_save = has_name && (has_db || in_memory);
+        let has_driver = self.c.driver_combo.selection().is_some();
+        let can_save = has_name && (has_db || in_memory) && has_driver;
         self.c.save_button.set_enabled(can_save);
     }
 }
@@ -135,7 +335,36 @@ impl ui::PopupDialog<AddDsnDialogArgs, AddDsnDialogResult> for AddDsnDialog {
     }
 
     fn init(&mut self) {
-       self.added_dsn = AddDsnDialogResult::cancelled()
+        self.added_dsn = AddDsnDialogResult::cancelled();
+        self.existing_dsns = registry::list_all_dsns();
+        self.sources = vec!(("(none)".to_string(), vec!()));
+        if let Ok(dsns) = registry::load_duckdb_dsns() {
+            for dsn in dsns {
+                self.sources.push((format!("DSN: {} ({})", dsn.display_name(), dsn.dsn_type.label()), dsn.settings));
+            }
+        }
+        for tpl in dsn_templates::builtin_templates() {
+            self.sources.push((format!("Template: {}", tpl.name), tpl.settings));
+        }
+        let labels = self.sources.iter()
+            .map(|(label, _)| label.clone())
+            .collect();
+        self.c.based_on_combo.set_collection(labels);
+        self.c.based_on_combo.set_selection(Some(0));
+        match registry::list_duckdb_drivers() {
+            Ok(drivers) => {
+                let labels: Vec<String> = drivers.iter()
+                    .map(|d| d.display_name())
+                    .collect();
+                self.c.driver_combo.set_collection(labels);
+                let default_idx = drivers.iter()
+                    .position(|d| registry::DRIVER_NAME == d.name && Bitness::X64 == d.bitness)
+                    .unwrap_or(0);
+                self.c.driver_combo.set_selection(Some(default_idx));
+                self.drivers = drivers;
+            },
+            Err(e) => ui::message_box_error(&format!("{}", e))
+        }
     }
 
     fn result(&mut self) -> AddDsnDialogResult {
@@ -39,6 +39,32 @@ impl ui::Events<AddDsnDialogControls> for AddDsnDialogEvents {
             .event(nwg::Event::OnTextInput)
             .handler(AddDsnDialog::on_name_input)
             .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.dsn_type_combo)
+            .event(nwg::Event::OnComboxBoxSelection)
+            .handler(AddDsnDialog::on_name_input)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.driver_combo)
+            .event(nwg::Event::OnComboxBoxSelection)
+            .handler(AddDsnDialog::on_name_input)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.based_on_combo)
+            .event(nwg::Event::OnComboxBoxSelection)
+            .handler(AddDsnDialog::on_based_on_changed)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.review_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(AddDsnDialog::open_review_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.review_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AddDsnDialog::await_review_dialog)
+            .build(&mut self.events)?;
 
         ui::event_builder()
             .control(&c.dbpath_button)
@@ -46,6 +72,17 @@ impl ui::Events<AddDsnDialogControls> for AddDsnDialogEvents {
             .handler(AddDsnDialog::on_choose_db_file)
             .build(&mut self.events)?;
 
+        ui::event_builder()
+            .control(&c.new_db_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(AddDsnDialog::open_new_db_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.new_db_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AddDsnDialog::await_new_db_dialog)
+            .build(&mut self.events)?;
+
         ui::event_builder()
             .control(&c.init_button)
             .event(nwg::Event::OnButtonClick)
@@ -58,6 +95,16 @@ impl ui::Events<AddDsnDialogControls> for AddDsnDialogEvents {
             .handler(AddDsnDialog::on_memory_checkbox_changed)
             .build(&mut self.events)?;
 
+        ui::event_builder()
+            .control(&c.inspect_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(AddDsnDialog::open_inspect_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.inspect_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AddDsnDialog::await_inspect_dialog)
+            .build(&mut self.events)?;
         ui::event_builder()
             .control(&c.save_button)
             .event(nwg::Event::OnButtonClick)
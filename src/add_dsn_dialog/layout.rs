@@ -22,7 +22,10 @@ const LABEL_WIDTH_PT: u32 = 80;
 pub(super) struct AddDsnDialogLayout {
     root_layout: nwg::FlexboxLayout,
     name_layout: nwg::FlexboxLayout,
+    name_check_layout: nwg::FlexboxLayout,
     dsn_type_layout: nwg::FlexboxLayout,
+    driver_layout: nwg::FlexboxLayout,
+    based_on_layout: nwg::FlexboxLayout,
     init_layout: nwg::FlexboxLayout,
     dbpath_layout: nwg::FlexboxLayout,
     use_memory_layout: nwg::FlexboxLayout,
@@ -48,6 +51,21 @@ impl ui::Layout<AddDsnDialogControls> for AddDsnDialogLayout {
             .child_flex_grow(1.0)
             .build_partial(&self.name_layout)?;
 
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.name_check_label)
+            .child_size(ui::size_builder()
+                .width_auto()
+                .height_input_form_row()
+                .build())
+            .child_flex_grow(1.0)
+            .child_margin(ui::margin_builder()
+                .start_pt(85)
+                .build())
+            .build_partial(&self.name_check_layout)?;
+
         nwg::FlexboxLayout::builder()
             .parent(&c.window)
             .flex_direction(ui::FlexDirection::Row)
@@ -64,6 +82,46 @@ impl ui::Layout<AddDsnDialogControls> for AddDsnDialogLayout {
             .child_flex_grow(1.0)
             .build_partial(&self.dsn_type_layout)?;
 
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.driver_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.driver_combo)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .build_partial(&self.driver_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.based_on_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.based_on_combo)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .child(&c.review_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .build_partial(&self.based_on_layout)?;
+
         nwg::FlexboxLayout::builder()
             .parent(&c.window)
             .flex_direction(ui::FlexDirection::Row)
@@ -86,6 +144,14 @@ impl ui::Layout<AddDsnDialogControls> for AddDsnDialogLayout {
             .child_margin(ui::margin_builder()
                 .start_pt(5)
                 .build())
+            .child(&c.new_db_button)
+            .child_size(ui::size_builder()
+                .width_button_wide()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
             .build_partial(&self.dbpath_layout)?;
 
         nwg::FlexboxLayout::builder()
@@ -138,6 +204,11 @@ impl ui::Layout<AddDsnDialogControls> for AddDsnDialogLayout {
             .flex_direction(ui::FlexDirection::Row)
             .justify_content(ui::JustifyContent::FlexEnd)
             .auto_spacing(None)
+            .child(&c.inspect_button)
+            .child_size(ui::size_builder()
+                .width_button_xwide()
+                .height_button()
+                .build())
             .child(&c.save_button)
             .child_size(ui::size_builder()
                 .width_button_normal()
@@ -160,7 +231,10 @@ impl ui::Layout<AddDsnDialogControls> for AddDsnDialogLayout {
             .parent(&c.window)
             .flex_direction(ui::FlexDirection::Column)
             .child_layout(&self.name_layout)
+            .child_layout(&self.name_check_layout)
             .child_layout(&self.dsn_type_layout)
+            .child_layout(&self.driver_layout)
+            .child_layout(&self.based_on_layout)
             .child_layout(&self.dbpath_layout)
             .child_layout(&self.use_memory_layout)
             .child_layout(&self.init_layout)
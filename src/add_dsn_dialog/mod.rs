@@ -35,7 +35,22 @@ use ui::PopupArgs;
 use ui::PopupDialog;
 
 pub use args::AddDsnDialogArgs;
+use common::Bitness;
+use common::db_path;
+use common::dsn_name;
+use common::dsn_name::DsnNameCheck;
+use common::dsn_templates;
+use common::init_sql;
+use common::policy::Policy;
 use common::registry;
+use common::registry::ExistingDsn;
+use common::RegistrySetting;
+use new_db_dialog::NewDbDialog;
+use new_db_dialog::NewDbDialogArgs;
+use new_db_dialog::NewDbDialogResult;
+use report_dialog::ReportDialog;
+use report_dialog::ReportDialogArgs;
+use report_dialog::ReportDialogResult;
 use controls::AddDsnDialogControls;
 pub use dialog::AddDsnDialog;
 use events::AddDsnDialogEvents;
@@ -16,6 +16,25 @@
 
 use super::*;
 
+use std::os::raw::c_void;
+
+const SB_SETPARTS: u32 = 0x0404;
+
+#[repr(C)]
+#[derive(Default)]
+struct Rect {
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+}
+
+#[link(name = "user32")]
+extern "system" {
+    fn SendMessageW(hwnd: *mut c_void, msg: u32, wparam: usize, lparam: isize) -> isize;
+    fn GetClientRect(hwnd: *mut c_void, rect: *mut Rect) -> i32;
+}
+
 #[derive(Default)]
 pub(super) struct AppWindowControls {
     layout: AppWindowLayout,
@@ -28,6 +47,18 @@ pub(super) struct AppWindowControls {
 
     pub(super) file_menu: nwg::Menu,
     pub(super) file_add_dsn_menu_item: nwg::MenuItem,
+    pub(super) file_driver_menu_item: nwg::MenuItem,
+    pub(super) file_repair_dsns_menu_item: nwg::MenuItem,
+    pub(super) file_compare_menu_item: nwg::MenuItem,
+    pub(super) file_matrix_menu_item: nwg::MenuItem,
+    pub(super) file_bulk_menu_item: nwg::MenuItem,
+    pub(super) file_search_menu_item: nwg::MenuItem,
+    pub(super) file_security_menu_item: nwg::MenuItem,
+    pub(super) file_policy_menu_item: nwg::MenuItem,
+    pub(super) file_export_sql_menu_item: nwg::MenuItem,
+    pub(super) file_import_sql_menu_item: nwg::MenuItem,
+    pub(super) file_apply_preset_menu_item: nwg::MenuItem,
+    pub(super) file_save_preset_menu_item: nwg::MenuItem,
     pub(super) file_exit_menu_item: nwg::MenuItem,
     pub(super) help_menu: nwg::Menu,
     pub(super) help_about_menu_item: nwg::MenuItem,
@@ -39,20 +70,82 @@ pub(super) struct AppWindowControls {
     pub(super) filter_button: nwg::Button,
 
     pub(super) settings_view: nwg::ListView,
+    pub(super) settings_images: nwg::ImageList,
+    pub(super) differs_icon: nwg::Icon,
+    pub(super) live_only_icon: nwg::Icon,
+    pub(super) lint_error_icon: nwg::Icon,
+    pub(super) lint_warning_icon: nwg::Icon,
 
     pub(super) conn_str_input: nwg::TextInput,
     pub(super) copy_conn_str_button: nwg::Button,
+    pub(super) test_conn_button: nwg::Button,
     pub(super) add_dsn_button: nwg::Button,
     pub(super) delete_dsn_button: nwg::Button,
     pub(super) reload_button: nwg::Button,
     pub(super) close_button: nwg::Button,
     pub(super) status_bar: nwg::StatusBar,
+    pub(super) export_sql_chooser: nwg::FileDialog,
+    pub(super) import_sql_chooser: nwg::FileDialog,
+    pub(super) apply_preset_chooser: nwg::FileDialog,
+    pub(super) save_preset_chooser: nwg::FileDialog,
 
     pub(super) about_notice: ui::SyncNotice,
     pub(super) connect_notice: ui::SyncNotice,
     pub(super) load_settings_notice: ui::SyncNotice,
     pub(super) setting_notice: ui::SyncNotice,
     pub(super) add_dsn_notice: ui::SyncNotice,
+    pub(super) driver_notice: ui::SyncNotice,
+    pub(super) preset_notice: ui::SyncNotice,
+    pub(super) compare_notice: ui::SyncNotice,
+    pub(super) matrix_notice: ui::SyncNotice,
+    pub(super) bulk_notice: ui::SyncNotice,
+    pub(super) search_notice: ui::SyncNotice,
+    pub(super) security_notice: ui::SyncNotice,
+    pub(super) policy_notice: ui::SyncNotice,
+}
+
+impl AppWindowControls {
+    pub(super) const IMAGE_NONE: i32 = -2;
+    pub(super) const DIFFERS_IMAGE: i32 = 0;
+    pub(super) const LIVE_ONLY_IMAGE: i32 = 1;
+    pub(super) const LINT_ERROR_IMAGE: i32 = 2;
+    pub(super) const LINT_WARNING_IMAGE: i32 = 3;
+
+    // status bar parts, each feature writes only to its own one
+    pub(super) const STATUS_DSN: u8 = 0;
+    pub(super) const STATUS_DATABASE: u8 = 1;
+    pub(super) const STATUS_LINT: u8 = 2;
+    pub(super) const STATUS_ACTION: u8 = 3;
+    // percents of the status bar width, the last part takes the rest
+    const STATUS_PART_WIDTHS: [i32; 3] = [35, 25, 20];
+
+    pub(super) fn clear_status(&self) {
+        for part in Self::STATUS_DSN..=Self::STATUS_ACTION {
+            self.status_bar.set_text(part, "");
+        }
+    }
+
+    // nwg does not support status bar parts, their right edges
+    // are set in pixels and need to be updated on resize
+    pub(super) fn update_status_parts(&self) {
+        let hwnd = match self.status_bar.handle.hwnd() {
+            Some(hwnd) => hwnd as *mut c_void,
+            None => return
+        };
+        let mut rect = Rect::default();
+        if 0 == unsafe { GetClientRect(hwnd, &mut rect) } {
+            return;
+        }
+        let width = rect.right - rect.left;
+        let mut edges: Vec<i32> = vec!();
+        let mut percents = 0;
+        for pw in Self::STATUS_PART_WIDTHS.iter() {
+            percents += pw;
+            edges.push(width * percents / 100);
+        }
+        edges.push(-1);
+        unsafe { SendMessageW(hwnd, SB_SETPARTS, edges.len(), edges.as_ptr() as isize) };
+    }
 }
 
 impl ui::Controls for AppWindowControls {
@@ -94,6 +187,54 @@ impl ui::Controls for AppWindowControls {
             .parent(&self.file_menu)
             .text("Add Data Source")
             .build(&mut self.file_add_dsn_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("DuckDB Driver")
+            .build(&mut self.file_driver_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Repair Orphaned DSNs")
+            .build(&mut self.file_repair_dsns_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Compare Data Sources")
+            .build(&mut self.file_compare_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Settings Matrix")
+            .build(&mut self.file_matrix_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Bulk Apply Setting")
+            .build(&mut self.file_bulk_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Search Data Sources")
+            .build(&mut self.file_search_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Security Report")
+            .build(&mut self.file_security_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Policy Compliance Report")
+            .build(&mut self.file_policy_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Export Settings to Init SQL")
+            .build(&mut self.file_export_sql_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Import Settings from Init SQL")
+            .build(&mut self.file_import_sql_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Apply Preset")
+            .build(&mut self.file_apply_preset_menu_item)?;
+        nwg::MenuItem::builder()
+            .parent(&self.file_menu)
+            .text("Save Settings as Preset")
+            .build(&mut self.file_save_preset_menu_item)?;
         nwg::MenuItem::builder()
             .parent(&self.file_menu)
             .text("Exit")
@@ -146,6 +287,30 @@ impl ui::Controls for AppWindowControls {
             .ex_flags(nwg::ListViewExFlags::GRID | nwg::ListViewExFlags::FULL_ROW_SELECT)
             .build(&mut self.settings_view)?;
         self.settings_view.set_headers_enabled(true);
+        nwg::Icon::builder()
+            .source_system(Some(nwg::OemIcon::Warning))
+            .size(Some((16, 16)))
+            .build(&mut self.differs_icon)?;
+        nwg::Icon::builder()
+            .source_system(Some(nwg::OemIcon::Information))
+            .size(Some((16, 16)))
+            .build(&mut self.live_only_icon)?;
+        nwg::Icon::builder()
+            .source_system(Some(nwg::OemIcon::Error))
+            .size(Some((16, 16)))
+            .build(&mut self.lint_error_icon)?;
+        nwg::Icon::builder()
+            .source_system(Some(nwg::OemIcon::Ques))
+            .size(Some((16, 16)))
+            .build(&mut self.lint_warning_icon)?;
+        nwg::ImageList::builder()
+            .size((16, 16))
+            .build(&mut self.settings_images)?;
+        self.settings_images.add_icon(&self.differs_icon);
+        self.settings_images.add_icon(&self.live_only_icon);
+        self.settings_images.add_icon(&self.lint_error_icon);
+        self.settings_images.add_icon(&self.lint_warning_icon);
+        self.settings_view.set_image_list(Some(&self.settings_images), nwg::ListViewImageListType::Small);
         self.settings_view.insert_column(nwg::InsertListViewColumn{
             index: Some(0),
             fmt: Some(nwg::ListViewColumnFlags::LEFT),
@@ -170,6 +335,25 @@ impl ui::Controls for AppWindowControls {
         self.settings_view.insert_column(nwg::InsertListViewColumn{
             index: Some(3),
             fmt: Some(nwg::ListViewColumnFlags::LEFT),
+            width: Some(90),
+            text: Some("Effective value".to_string())
+        });
+        self.settings_view.set_column_sort_arrow(3, Some(nwg::ListViewColumnSortArrow::Down));
+        self.settings_view.insert_column(nwg::InsertListViewColumn{
+            index: Some(4),
+            fmt: Some(nwg::ListViewColumnFlags::LEFT),
+            width: Some(90),
+            text: Some("Value type".to_string())
+        });
+        self.settings_view.insert_column(nwg::InsertListViewColumn{
+            index: Some(5),
+            fmt: Some(nwg::ListViewColumnFlags::LEFT),
+            width: Some(200),
+            text: Some("Finding".to_string())
+        });
+        self.settings_view.insert_column(nwg::InsertListViewColumn{
+            index: Some(6),
+            fmt: Some(nwg::ListViewColumnFlags::LEFT),
             width: Some(400),
             text: Some("Description".to_string())
         });
@@ -186,6 +370,11 @@ impl ui::Controls for AppWindowControls {
             .font(Some(&self.font_normal))
             .parent(&self.window)
             .build(&mut self.copy_conn_str_button)?;
+        nwg::Button::builder()
+            .text("Test connection")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.test_conn_button)?;
         nwg::Button::builder()
             .text("Add DSN")
             .font(Some(&self.font_normal))
@@ -213,6 +402,28 @@ impl ui::Controls for AppWindowControls {
             .parent(&self.window)
             .font(Some(&self.font_small))
             .build(&mut self.status_bar)?;
+        self.update_status_parts();
+
+        nwg::FileDialog::builder()
+            .title("Export settings to init SQL file")
+            .action(nwg::FileDialogAction::Save)
+            .filters("SQL(*.sql)|Any(*.*)")
+            .build(&mut self.export_sql_chooser)?;
+        nwg::FileDialog::builder()
+            .title("Import settings from init SQL file")
+            .action(nwg::FileDialogAction::Open)
+            .filters("SQL(*.sql)|Any(*.*)")
+            .build(&mut self.import_sql_chooser)?;
+        nwg::FileDialog::builder()
+            .title("Apply settings preset")
+            .action(nwg::FileDialogAction::Open)
+            .filters("Preset(*.preset)|Any(*.*)")
+            .build(&mut self.apply_preset_chooser)?;
+        nwg::FileDialog::builder()
+            .title("Save settings as preset")
+            .action(nwg::FileDialogAction::Save)
+            .filters("Preset(*.preset)|Any(*.*)")
+            .build(&mut self.save_preset_chooser)?;
 
         ui::notice_builder()
             .parent(&self.window)
@@ -229,6 +440,30 @@ impl ui::Controls for AppWindowControls {
         ui::notice_builder()
             .parent(&self.window)
             .build(&mut self.add_dsn_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.driver_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.preset_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.compare_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.matrix_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.bulk_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.search_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.security_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.policy_notice)?;
 
         self.layout.build(&self)?;
 
@@ -241,6 +476,7 @@ impl ui::Controls for AppWindowControls {
             .control(&self.filter_input)
             .control(&self.filter_button)
             .control(&self.copy_conn_str_button)
+            .control(&self.test_conn_button)
             .control(&self.add_dsn_button)
             .control(&self.delete_dsn_button)
             .control(&self.reload_button)
@@ -33,6 +33,11 @@ impl ui::Events<AppWindowControls> for AppWindowEvents {
             .event(nwg::Event::OnResizeEnd)
             .handler(AppWindow::on_resize)
             .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowMaximize)
+            .handler(AppWindow::on_resize)
+            .build(&mut self.events)?;
         ui::event_builder()
             .control(&c.window)
             .event(nwg::Event::OnKeyEnter)
@@ -44,6 +49,66 @@ impl ui::Events<AppWindowControls> for AppWindowEvents {
             .event(nwg::Event::OnMenuItemSelected)
             .handler(AppWindow::open_add_dsn_dialog)
             .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_driver_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_driver_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_repair_dsns_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::on_repair_dsns_menu_item)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_compare_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_compare_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_matrix_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_matrix_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_bulk_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_bulk_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_search_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_search_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_security_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_security_report)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_policy_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_policy_report)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_export_sql_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::on_export_sql_menu_item)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_import_sql_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::on_import_sql_menu_item)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_apply_preset_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::open_preset_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.file_save_preset_menu_item)
+            .event(nwg::Event::OnMenuItemSelected)
+            .handler(AppWindow::on_save_preset_menu_item)
+            .build(&mut self.events)?;
         ui::event_builder()
             .control(&c.file_exit_menu_item)
             .event(nwg::Event::OnMenuItemSelected)
@@ -88,6 +153,11 @@ impl ui::Events<AppWindowControls> for AppWindowEvents {
             .event(nwg::Event::OnButtonClick)
             .handler(AppWindow::on_copy_conn_str_button)
             .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.test_conn_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(AppWindow::open_connect_dialog)
+            .build(&mut self.events)?;
         ui::event_builder()
             .control(&c.add_dsn_button)
             .event(nwg::Event::OnButtonClick)
@@ -114,6 +184,11 @@ impl ui::Events<AppWindowControls> for AppWindowEvents {
             .event(nwg::Event::OnNotice)
             .handler(AppWindow::await_about_dialog)
             .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.connect_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_connect_dialog)
+            .build(&mut self.events)?;
         ui::event_builder()
             .control(&c.load_settings_notice.notice)
             .event(nwg::Event::OnNotice)
@@ -129,6 +204,46 @@ impl ui::Events<AppWindowControls> for AppWindowEvents {
             .event(nwg::Event::OnNotice)
             .handler(AppWindow::await_add_dsn_dialog)
             .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.driver_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_driver_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.preset_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_preset_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.compare_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_compare_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.matrix_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_matrix_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.bulk_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_bulk_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.search_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_search_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.security_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_security_report)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.policy_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(AppWindow::await_policy_report)
+            .build(&mut self.events)?;
 
         Ok(())
     }
@@ -98,6 +98,15 @@ impl ui::Layout<AppWindowControls> for AppWindowLayout {
             .child_margin(ui::margin_builder()
                 .bottom_pt(BOTTOM_MARGIN_PT)
                 .build())
+            .child(&c.test_conn_button)
+            .child_size(ui::size_builder()
+                .width_button_xwide()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .bottom_pt(BOTTOM_MARGIN_PT)
+                .start_pt(5)
+                .build())
             .child(&c.add_dsn_button)
             .child_size(ui::size_builder()
                 .width_button_normal()
@@ -32,13 +32,50 @@ use about_dialog::AboutDialogArgs;
 use add_dsn_dialog::AddDsnDialog;
 use add_dsn_dialog::AddDsnDialogArgs;
 use add_dsn_dialog::AddDsnDialogResult;
-use common::all_settings;
+use bulk_dialog::BulkDialog;
+use bulk_dialog::BulkDialogArgs;
+use bulk_dialog::BulkDialogResult;
+use compare_dialog::CompareDialog;
+use compare_dialog::CompareDialogArgs;
+use compare_dialog::CompareDialogResult;
+use connect_dialog::ConnectDialog;
+use connect_dialog::ConnectDialogArgs;
+use connect_dialog::ConnectDialogResult;
+use driver_dialog::DriverDialog;
+use driver_dialog::DriverDialogArgs;
+use driver_dialog::DriverDialogResult;
+use common::db_conflicts;
+use common::db_conflicts::DbFileGroup;
+use common::db_header;
+use common::init_sql;
+use common::lint;
+use common::lint::LintReport;
+use common::lint::Severity;
+use common::lint::SystemLimits;
+use common::odbc;
+use common::paths;
+use common::pe_file;
+use common::policy;
+use common::policy::Policy;
+use common::presets;
+use common::presets::PresetChange;
+use common::settings_catalog;
+use common::settings_matrix;
 use common::registry;
+use common::security;
 use common::DuckDbSetting;
 use common::RegistryDsn;
+use report_dialog::ReportDialog;
+use report_dialog::ReportDialogArgs;
+use report_dialog::ReportDialogResult;
 use load_dsns_dialog::LoadDsnsDialog;
 use load_dsns_dialog::LoadDsnsDialogArgs;
 use load_dsns_dialog::LoadDsnsDialogResult;
+use matrix_dialog::MatrixDialog;
+use matrix_dialog::MatrixDialogArgs;
+use search_dialog::SearchDialog;
+use search_dialog::SearchDialogArgs;
+use search_dialog::SearchDialogResult;
 use setting_dialog::SettingDialog;
 use setting_dialog::SettingDialogArgs;
 use setting_dialog::SettingDialogResult;
@@ -15,12 +15,13 @@
  */
 
 use std::env;
+use std::fs;
 use std::os::windows::process::CommandExt;
+use std::path::Path;
+use std::path::PathBuf;
 use std::process::Command;
 use std::process::Stdio;
 
-use wildmatch::WildMatch;
-
 use super::*;
 
 #[derive(Default)]
@@ -31,11 +32,25 @@ pub struct AppWindow {
     settings: Vec<DuckDbSetting>,
 
     last_added_dsn: Option<String>,
+    effective_dsn: Option<String>,
+    effective_settings: Vec<DuckDbSetting>,
+    lint: LintReport,
+    db_conflicts: Vec<DbFileGroup>,
+    pending_preset: Option<(String, Vec<PresetChange>)>,
 
     about_dialog_join_handle: ui::PopupJoinHandle<()>,
+    connect_dialog_join_handle: ui::PopupJoinHandle<ConnectDialogResult>,
     load_settings_dialog_join_handle: ui::PopupJoinHandle<LoadDsnsDialogResult>,
     setting_dialog_join_handle: ui::PopupJoinHandle<SettingDialogResult>,
     add_dsn_dialog_join_handle: ui::PopupJoinHandle<AddDsnDialogResult>,
+    driver_dialog_join_handle: ui::PopupJoinHandle<DriverDialogResult>,
+    preset_dialog_join_handle: ui::PopupJoinHandle<ReportDialogResult>,
+    compare_dialog_join_handle: ui::PopupJoinHandle<CompareDialogResult>,
+    matrix_dialog_join_handle: ui::PopupJoinHandle<()>,
+    bulk_dialog_join_handle: ui::PopupJoinHandle<BulkDialogResult>,
+    search_dialog_join_handle: ui::PopupJoinHandle<SearchDialogResult>,
+    security_dialog_join_handle: ui::PopupJoinHandle<ReportDialogResult>,
+    policy_dialog_join_handle: ui::PopupJoinHandle<ReportDialogResult>,
 }
 
 impl AppWindow {
@@ -67,6 +82,155 @@ impl AppWindow {
         self.c.conn_str_input.set_enabled(true);
     }
 
+    pub(super) fn open_connect_dialog(&mut self, _: nwg::EventData) {
+        if let Some(name) = self.c.dsn_combo.selection_string() {
+            if let Some(dsn) = self.dsns.iter().find(|d| d.display_name() == name) {
+                let hint = format!("use a {} ODBC application (for example, the {} ODBC Data Source Administrator) to test it",
+                    dsn.bitness.label(), dsn.bitness.label());
+                if let Err(e) = odbc::check_bitness(&dsn.bitness, &format!("Data source: '{}'", dsn.name), &hint) {
+                    ui::message_box_error(&format!("Connection cannot be tested.\r\n\r\n{}", e));
+                    return;
+                }
+                self.c.window.set_enabled(false);
+                self.effective_dsn = Some(dsn.display_name());
+                let args = ConnectDialogArgs::new(&self.c.connect_notice, &dsn.name);
+                self.connect_dialog_join_handle = ConnectDialog::popup(args);
+            }
+        }
+    }
+
+    pub(super) fn await_connect_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.connect_notice.receive();
+        let res = self.connect_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+        self.effective_settings = res.effective_settings;
+        self.on_dsn_changed(nwg::EventData::NoData);
+    }
+
+    pub(super) fn open_compare_dialog(&mut self, _: nwg::EventData) {
+        let left = self.c.dsn_combo.selection_string().unwrap_or_default();
+        self.c.window.set_enabled(false);
+        let args = CompareDialogArgs::new(&self.c.compare_notice, &self.dsns, &left);
+        self.compare_dialog_join_handle = CompareDialog::popup(args);
+    }
+
+    pub(super) fn await_compare_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.compare_notice.receive();
+        let res = self.compare_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+        if res.changed {
+            self.open_load_dialog(nwg::EventData::NoData);
+        }
+    }
+
+    pub(super) fn open_matrix_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(false);
+        let args = MatrixDialogArgs::new(&self.c.matrix_notice, &self.dsns, &self.c.filter_input.text());
+        self.matrix_dialog_join_handle = MatrixDialog::popup(args);
+    }
+
+    pub(super) fn await_matrix_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.matrix_notice.receive();
+        self.matrix_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+    }
+
+    pub(super) fn open_bulk_dialog(&mut self, _: nwg::EventData) {
+        let setting = match self.c.settings_view.selected_item() {
+            Some(row_idx) => self.c.settings_view.item(row_idx, 0, 1<<16)
+                .map(|item| item.text)
+                .unwrap_or_default(),
+            None => "".to_string()
+        };
+        self.c.window.set_enabled(false);
+        let args = BulkDialogArgs::new(&self.c.bulk_notice, &self.dsns, &setting);
+        self.bulk_dialog_join_handle = BulkDialog::popup(args);
+    }
+
+    pub(super) fn await_bulk_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.bulk_notice.receive();
+        let res = self.bulk_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+        if res.changed {
+            self.open_load_dialog(nwg::EventData::NoData);
+        }
+    }
+
+    pub(super) fn open_search_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(false);
+        let args = SearchDialogArgs::new(&self.c.search_notice, &self.dsns);
+        self.search_dialog_join_handle = SearchDialog::popup(args);
+    }
+
+    pub(super) fn await_search_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.search_notice.receive();
+        let res = self.search_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+        if res.changed {
+            self.open_load_dialog(nwg::EventData::NoData);
+        }
+    }
+
+    pub(super) fn open_security_report(&mut self, _: nwg::EventData) {
+        let findings = security::security_report(&self.dsns);
+        let summary = security::report_summary(&self.dsns, &findings);
+        let rows = findings.iter().map(|f| f.report_row()).collect();
+        self.c.window.set_enabled(false);
+        let args = ReportDialogArgs::new(&self.c.security_notice, "Security Report", &summary,
+            vec!(("Data source", 140), ("Type", 60), ("Setting", 180), ("Value", 120), ("Risk", 60), ("Reason", 300)),
+            rows, None);
+        self.security_dialog_join_handle = ReportDialog::popup(args);
+    }
+
+    pub(super) fn await_security_report(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.security_notice.receive();
+        let _ = self.security_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+    }
+
+    pub(super) fn open_policy_report(&mut self, _: nwg::EventData) {
+        let policy = match Policy::load() {
+            Ok(policy) => policy,
+            Err(e) => {
+                ui::message_box_error(&e.to_string());
+                return;
+            }
+        };
+        if policy.rules.is_empty() {
+            nwg::modal_info_message(&self.c.window, "Policy Compliance Report", &format!(
+                "No policy rules found, policy file path: '{}'", paths::expand_env_vars(policy::POLICY_FILE)));
+            return;
+        }
+        let findings = policy::compliance_report(&policy, &self.dsns);
+        let summary = policy::report_summary(&policy, &self.dsns, &findings);
+        let rows = findings.iter().map(|f| f.report_row()).collect();
+        self.c.window.set_enabled(false);
+        let args = ReportDialogArgs::new(&self.c.policy_notice, "Policy Compliance Report", &summary,
+            vec!(("Data source", 140), ("Type", 60), ("Setting", 160), ("Value", 120), ("Rule", 200), ("Problem", 220)),
+            rows, None);
+        self.policy_dialog_join_handle = ReportDialog::popup(args);
+    }
+
+    pub(super) fn await_policy_report(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.policy_notice.receive();
+        let _ = self.policy_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+    }
+
     pub(super) fn open_load_dialog(&mut self, _: nwg::EventData) {
         self.dsns.truncate(0);
         self.c.window.set_enabled(false);
@@ -79,7 +243,10 @@ impl AppWindow {
         self.c.load_settings_notice.receive();
         let res = self.load_settings_dialog_join_handle.join();
         self.dsns = res.dsns;
+        self.db_conflicts = db_conflicts::find_conflicts(&self.dsns);
         self.settings.truncate(0);
+        self.effective_dsn = None;
+        self.effective_settings.truncate(0);
         self.c.filter_input.set_enabled(true);
         self.c.conn_str_input.set_enabled(true);
         self.reload_dsns_combo();
@@ -90,35 +257,72 @@ impl AppWindow {
         self.on_dsn_changed(nwg::EventData::NoData);
     }
     
-    fn non_engine_setting_description(name: &str) -> String {
-        if registry::DATABASE_SETTING_NAME == name {
-            registry::DATABASE_SETTING_DESCRIPTION.to_string()
-        } else if registry::SESSION_INIT_SQL_FILE_SETTING_NAME == name{
-            registry::SESSION_INIT_SQL_FILE_SETTING_DESCRIPTION.to_string()
-        } else {
-            "".to_string()
+    fn describe_database_file(&mut self, driver_path: Option<String>) {
+        let compat = self.settings.iter()
+            .find(|s| db_header::STORAGE_COMPATIBILITY_SETTING_NAME == s.name)
+            .map(|s| s.dsn_value.clone())
+            .unwrap_or_default();
+        let st = match self.settings.iter_mut().find(|s| registry::DATABASE_SETTING_NAME == s.name) {
+            Some(st) => st,
+            None => return
+        };
+        let driver_version = driver_path.and_then(|path| pe_file::dll_file_version(&path).ok());
+        if let Some(insp) = db_header::inspect_file(&st.dsn_value, &compat, driver_version.as_deref()) {
+            st.description = format!("{}; {}", registry::DATABASE_SETTING_DESCRIPTION, insp.description());
+            if let Some(warning) = insp.warnings.first() {
+                self.c.status_bar.set_text(AppWindowControls::STATUS_DATABASE, &format!("Database file: {}", warning));
+            }
         }
     }
 
     pub(super) fn on_dsn_changed(&mut self, _: nwg::EventData) {
-        self.settings = all_settings();
+        let dsn_opt = match self.c.dsn_combo.selection_string() {
+            Some(dname) => self.dsns.iter().find(|d| d.display_name() == dname),
+            None => None
+        };
+        let catalog = settings_catalog(dsn_opt.and_then(|d| d.driver.as_ref()));
+        self.lint = match dsn_opt {
+            Some(dsn) => lint::lint_dsn(dsn, &catalog, &SystemLimits::current(), &self.db_conflicts),
+            None => LintReport::default()
+        };
+        self.settings = catalog;
         self.c.conn_str_input.set_text("");
-        if let Some(dname) = self.c.dsn_combo.selection_string() {
-            if let Some(dsn) = self.dsns.iter().find(|d| d.name == dname) {
-                self.c.conn_str_input.set_text(&format!("DSN={{{}}};", &dname));
-                for rs in &dsn.settings {
-                    if let Some(mut s) = self.settings.iter_mut().find(|s| s.name == rs.name) {
-                        s.dsn_value = rs.value.to_string();
-                    } else if "Driver" != rs.name {
-                        self.settings.push(DuckDbSetting {
-                            name: rs.name.to_string(),
-                            dsn_value: rs.value.to_string(),
-                            description: Self::non_engine_setting_description(&rs.name),
-                            ..Default::default()
-                        })
-                    }
+        self.c.clear_status();
+        if let Some(dsn) = dsn_opt {
+            self.c.conn_str_input.set_text(&format!("DSN={{{}}};", &dsn.name));
+            if dsn.is_orphaned() {
+                self.c.status_bar.set_text(AppWindowControls::STATUS_DSN, &format!(
+                    "DSN points to a driver that is not registered: '{}', use 'File -> Repair Orphaned DSNs' to fix it",
+                    dsn.driver_path().unwrap_or("")));
+            } else if let Some(warning) = registry::driver_bitness_warning(dsn) {
+                self.c.status_bar.set_text(AppWindowControls::STATUS_DSN, &warning);
+            } else if let Some(driver) = &dsn.driver {
+                self.c.status_bar.set_text(AppWindowControls::STATUS_DSN, &format!("Driver: {}, path: '{}'", driver.display_name(), driver.path));
+            }
+            for rs in &dsn.settings {
+                if let Some(s) = self.settings.iter_mut().find(|s| s.name == rs.name) {
+                    s.dsn_value = rs.value.to_string();
+                    s.dsn_value_type = Some(rs.value_type.clone());
+                } else if "Driver" != rs.name {
+                    self.settings.push(DuckDbSetting {
+                        name: rs.name.to_string(),
+                        dsn_value: rs.value.to_string(),
+                        dsn_value_type: Some(rs.value_type.clone()),
+                        description: registry::non_engine_setting_description(&rs.name),
+                        ..Default::default()
+                    })
                 }
             }
+            let driver_path = dsn.driver.as_ref().map(|d| d.path.clone());
+            let has_effective = Some(dsn.display_name()) == self.effective_dsn;
+            if let Some(first) = self.lint.findings.first() {
+                self.c.status_bar.set_text(AppWindowControls::STATUS_LINT,
+                    &format!("Lint: {}, {}", self.lint.summary(), first.report_line()));
+            }
+            self.describe_database_file(driver_path);
+            if has_effective {
+                self.apply_effective_settings();
+            }
         }
         self.sort_settings(0, false);
         self.sort_settings(1, true);
@@ -127,7 +331,7 @@ impl AppWindow {
 
     pub(super) fn open_setting_dialog(&mut self, ed: nwg::EventData) {
         if let Some(name) = self.c.dsn_combo.selection_string() {
-            if let Some(dsn) = self.dsns.iter().find(|d| d.name == name) {
+            if let Some(dsn) = self.dsns.iter().find(|d| d.display_name() == name) {
                 let row_idx = if let nwg::EventData::OnListViewItemIndex
                 { row_index: row_idx, .. } = ed {
                     row_idx
@@ -178,15 +382,32 @@ impl AppWindow {
         }
     }
 
+    pub(super) fn open_driver_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(false);
+        let args = DriverDialogArgs::new(&self.c.driver_notice);
+        self.driver_dialog_join_handle = DriverDialog::popup(args);
+    }
+
+    pub(super) fn await_driver_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.driver_notice.receive();
+        let res = self.driver_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+        if res.changed {
+            self.open_load_dialog(nwg::EventData::NoData)
+        }
+    }
+
     pub(super) fn on_delete_dsn_button(&mut self, _: nwg::EventData) {
         if let Some(name) = self.c.dsn_combo.selection_string() {
-            if let Some(dsn) = self.dsns.iter().find(|d| d.name == name) {
+            if let Some(dsn) = self.dsns.iter().find(|d| d.display_name() == name) {
                 let confirmed = ui::message_box_warning_yn(&format!(
                     "Data source: '{}' will be removed from registry, would you like to proceed?", dsn.name));
                 if !confirmed {
                     return;
                 }
-                match registry::delete_dsn(dsn.dsn_type.clone(), &dsn.name) {
+                match registry::delete_dsn(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name) {
                     Ok(_) => self.open_load_dialog(nwg::EventData::NoData),
                     Err(e) => ui::message_box_error(&format!(
                         "Error removing DNS from registry, type: {:?}, name: {}, message: {}", &dsn.dsn_type, &dsn.name, e))
@@ -195,6 +416,237 @@ impl AppWindow {
         }
     }
 
+    pub(super) fn on_repair_dsns_menu_item(&mut self, _: nwg::EventData) {
+        let orphaned: Vec<&RegistryDsn> = self.dsns.iter()
+            .filter(|d| d.is_orphaned())
+            .collect();
+        if orphaned.is_empty() {
+            nwg::modal_info_message(&self.c.window, "Repair Orphaned DSNs", "No orphaned DSNs found");
+            return;
+        }
+        let listing: Vec<String> = orphaned.iter()
+            .map(|d| format!("{} ({:?}, {}): '{}'", d.name, d.dsn_type, d.bitness.label(), d.driver_path().unwrap_or("")))
+            .collect();
+        let confirmed = ui::message_box_warning_yn(&format!(
+            "Following data sources point to a driver that is not registered:\r\n\r\n{}\r\n\r\n\
+            Their 'Driver' value will be changed to a registered DuckDB driver, would you like to proceed?",
+            listing.join("\r\n")));
+        if !confirmed {
+            return;
+        }
+        let mut failures: Vec<String> = vec!();
+        for dsn in orphaned {
+            if let Err(e) = registry::repoint_dsn_driver(dsn) {
+                failures.push(format!("{} ({:?}, {}): {}", dsn.name, dsn.dsn_type, dsn.bitness.label(), e));
+            }
+        }
+        if !failures.is_empty() {
+            ui::message_box_error(&format!(
+                "Error repairing data sources:\r\n\r\n{}", failures.join("\r\n")));
+        }
+        self.open_load_dialog(nwg::EventData::NoData);
+    }
+
+    pub(super) fn on_export_sql_menu_item(&mut self, _: nwg::EventData) {
+        if let Some(name) = self.c.dsn_combo.selection_string() {
+            if let Some(dsn) = self.dsns.iter().find(|d| d.display_name() == name) {
+                if !self.c.export_sql_chooser.run(Some(&self.c.window)) {
+                    return;
+                }
+                let path = match self.c.export_sql_chooser.get_selected_item() {
+                    Ok(file) => file.to_string_lossy().to_string(),
+                    Err(_) => return
+                };
+                let sql = init_sql::render_set_statements(&dsn.name, &self.settings);
+                match fs::write(&path, sql) {
+                    Ok(_) => self.c.status_bar.set_text(AppWindowControls::STATUS_ACTION, &format!("Settings exported to: '{}'", path)),
+                    Err(e) => ui::message_box_error(&format!(
+                        "Error writing init SQL file, path: '{}', message: {}", path, e))
+                }
+            }
+        }
+    }
+
+    pub(super) fn on_import_sql_menu_item(&mut self, _: nwg::EventData) {
+        if let Some(name) = self.c.dsn_combo.selection_string() {
+            if let Some(dsn) = self.dsns.iter().find(|d| d.display_name() == name) {
+                if !self.c.import_sql_chooser.run(Some(&self.c.window)) {
+                    return;
+                }
+                let path = match self.c.import_sql_chooser.get_selected_item() {
+                    Ok(file) => file.to_string_lossy().to_string(),
+                    Err(_) => return
+                };
+                let script = match fs::read_to_string(&path) {
+                    Ok(script) => script,
+                    Err(e) => {
+                        ui::message_box_error(&format!(
+                            "Error reading init SQL file, path: '{}', message: {}", path, e));
+                        return;
+                    }
+                };
+                let known_names: Vec<String> = self.settings.iter()
+                    .filter(|s| !s.input_type.is_empty())
+                    .map(|s| s.name.clone())
+                    .collect();
+                let imp = init_sql::import_script(&script, &known_names);
+                let untranslated = if imp.untranslated.is_empty() {
+                    "".to_string()
+                } else {
+                    format!("\r\n\r\nFollowing statements cannot be translated and will be left in the script:\r\n\r\n{}",
+                        imp.untranslated.join("\r\n"))
+                };
+                if imp.settings.is_empty() {
+                    nwg::modal_info_message(&self.c.window, "Import Settings from Init SQL", &format!(
+                        "No SET or PRAGMA statements that can be moved to DSN values found in file: '{}'{}", path, untranslated));
+                    return;
+                }
+                if let Err(e) = Policy::load().and_then(|pl| pl.check_all(&imp.settings)) {
+                    ui::message_box_error(&e.to_string());
+                    return;
+                }
+                let listing: Vec<String> = imp.settings.iter()
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect();
+                let confirmed = ui::message_box_warning_yn(&format!(
+                    "Following settings will be written to the data source: '{}' and removed from the script:\r\n\r\n{}{}\r\n\r\n\
+                    Would you like to proceed?", dsn.name, listing.join("\r\n"), untranslated));
+                if !confirmed {
+                    return;
+                }
+                for (st_name, value) in &imp.settings {
+                    if let Err(e) = registry::set_dsn_value(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name, st_name, value) {
+                        ui::message_box_error(&format!(
+                            "Error writing DSN value, name: '{}', value: '{}', message: {}", st_name, value, e));
+                        self.open_load_dialog(nwg::EventData::NoData);
+                        return;
+                    }
+                }
+                if let Err(e) = fs::write(&path, &imp.remaining_script) {
+                    ui::message_box_error(&format!(
+                        "Settings were written to DSN, but the init SQL file cannot be updated, path: '{}', message: {}", path, e));
+                }
+                self.open_load_dialog(nwg::EventData::NoData);
+            }
+        }
+    }
+
+    pub(super) fn open_preset_dialog(&mut self, _: nwg::EventData) {
+        let dsn_name = match self.c.dsn_combo.selection_string() {
+            Some(name) if self.dsns.iter().any(|d| d.display_name() == name) => name,
+            _ => return
+        };
+        let dir = match presets::ensure_presets_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                ui::message_box_error(&e.to_string());
+                return;
+            }
+        };
+        let _ = self.c.apply_preset_chooser.set_default_folder(&dir.to_string_lossy());
+        if !self.c.apply_preset_chooser.run(Some(&self.c.window)) {
+            return;
+        }
+        let path = match self.c.apply_preset_chooser.get_selected_item() {
+            Ok(file) => file,
+            Err(_) => return
+        };
+        let preset = match presets::read_preset(Path::new(&path)) {
+            Ok(preset) => preset,
+            Err(e) => {
+                ui::message_box_error(&e.to_string());
+                return;
+            }
+        };
+        let changes = presets::preset_changes(&preset, &self.settings);
+        if changes.is_empty() {
+            nwg::modal_info_message(&self.c.window, "Apply Preset", &format!(
+                "Data source: '{}' already has all values from preset: '{}'", dsn_name, preset.name));
+            return;
+        }
+        let summary = format!("Preset: '{}' {}, data source: '{}', values to change: {}, double-click a row to exclude it",
+            preset.name, preset.description, dsn_name, changes.len());
+        let rows = changes.iter()
+            .map(|ch| ch.report_row())
+            .collect();
+        let toggles = vec!(true; changes.len());
+        let args = ReportDialogArgs::new(&self.c.preset_notice, "Apply Preset", &summary,
+            vec!(("Setting", 200), ("Current value", 160), ("New value", 160)), rows, Some("Apply"))
+            .with_toggles(toggles);
+        self.pending_preset = Some((dsn_name, changes));
+        self.c.window.set_enabled(false);
+        self.preset_dialog_join_handle = ReportDialog::popup(args);
+    }
+
+    pub(super) fn await_preset_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.preset_notice.receive();
+        let res = self.preset_dialog_join_handle.join();
+        self.c.filter_input.set_enabled(true);
+        self.c.conn_str_input.set_enabled(true);
+        let (dsn_name, changes) = match self.pending_preset.take() {
+            Some(pending) if res.applied => pending,
+            _ => return
+        };
+        let selected: Vec<(String, String)> = res.selected.iter()
+            .map(|idx| (changes[*idx].name.clone(), changes[*idx].new.clone()))
+            .collect();
+        if let Err(e) = Policy::load().and_then(|pl| pl.check_all(&selected)) {
+            ui::message_box_error(&e.to_string());
+            return;
+        }
+        if let Some(dsn) = self.dsns.iter().find(|d| d.display_name() == dsn_name) {
+            for idx in res.selected {
+                let ch = &changes[idx];
+                if let Err(e) = registry::set_dsn_value(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name, &ch.name, &ch.new) {
+                    ui::message_box_error(&format!(
+                        "Error writing DSN value, name: '{}', value: '{}', message: {}", ch.name, ch.new, e));
+                    break;
+                }
+            }
+            self.open_load_dialog(nwg::EventData::NoData);
+        }
+    }
+
+    pub(super) fn on_save_preset_menu_item(&mut self, _: nwg::EventData) {
+        let dsn_name = match self.c.dsn_combo.selection_string() {
+            Some(name) if self.dsns.iter().any(|d| d.display_name() == name) => name,
+            _ => return
+        };
+        let mut preset = presets::preset_from_settings("", &format!("Saved from data source: {}", dsn_name), &self.settings);
+        if preset.settings.is_empty() {
+            nwg::modal_info_message(&self.c.window, "Save Settings as Preset", &format!(
+                "Data source: '{}' has no values that differ from defaults", dsn_name));
+            return;
+        }
+        let dir = match presets::ensure_presets_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                ui::message_box_error(&e.to_string());
+                return;
+            }
+        };
+        let _ = self.c.save_preset_chooser.set_default_folder(&dir.to_string_lossy());
+        if !self.c.save_preset_chooser.run(Some(&self.c.window)) {
+            return;
+        }
+        let mut path = match self.c.save_preset_chooser.get_selected_item() {
+            Ok(file) => PathBuf::from(file),
+            Err(_) => return
+        };
+        if path.extension().is_none() {
+            path.set_extension(presets::PRESET_FILE_EXTENSION);
+        }
+        preset.name = path.file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        match presets::write_preset(&path, &preset) {
+            Ok(()) => self.c.status_bar.set_text(AppWindowControls::STATUS_ACTION, &format!(
+                "Preset saved: '{}', values: {}", path.to_string_lossy(), preset.settings.len())),
+            Err(e) => ui::message_box_error(&e.to_string())
+        }
+    }
+
     pub(super) fn open_website(&mut self, _: nwg::EventData) {
         let create_no_window: u32 = 0x08000000;
         let _ = Command::new("cmd")
@@ -215,7 +667,7 @@ impl AppWindow {
         } else {
            return;
         };
-        if col_idx > 2 {
+        if col_idx > 3 {
             return;
         }
         let old_arrow = self.c.settings_view
@@ -248,6 +700,7 @@ impl AppWindow {
 
     pub(super) fn on_resize(&mut self, _: nwg::EventData) {
         self.c.update_tab_order();
+        self.c.update_status_parts();
     }
 
     #[allow(dead_code)]
@@ -263,14 +716,25 @@ impl AppWindow {
     }
 
     fn setting_matches_filters(&self, name: &str) -> bool {
-        let filter = self.c.filter_input.text();
-        if 0 == filter.len() {
-            return true;
-        }
-        if name.starts_with(&filter) {
-            return true;
+        settings_matrix::setting_matches_filter(name, &self.c.filter_input.text())
+    }
+
+    fn apply_effective_settings(&mut self) {
+        for live in &self.effective_settings {
+            if let Some(st) = self.settings.iter_mut().find(|s| s.name == live.name) {
+                st.effective_value = live.effective_value.clone();
+            } else {
+                self.settings.push(DuckDbSetting {
+                    live_only: true,
+                    ..live.clone()
+                });
+            }
         }
-        WildMatch::new(&filter).matches(name)
+        let differs_count = self.settings.iter().filter(|s| s.effective_differs()).count();
+        let live_only_count = self.settings.iter().filter(|s| s.live_only).count();
+        self.c.status_bar.set_text(AppWindowControls::STATUS_ACTION, &format!(
+            "Effective values: {} differ from DSN values, {} settings are not in the bundled catalog",
+            differs_count, live_only_count));
     }
 
     fn reload_dsns_combo(&self) {
@@ -280,7 +744,7 @@ impl AppWindow {
             dc.remove(0);
         }
         for dsn in &self.dsns {
-            dc.push(dsn.name.clone())
+            dc.push(dsn.display_name())
         }
         if self.dsns.len() > 0 {
             if let Some(sel) = sel_opt {
@@ -303,30 +767,65 @@ impl AppWindow {
                 break;
             }
         };
-        let mut idx = 0 as i32;
+        let mut idx: i32 = 0;
         for rec in &self.settings {
             if self.setting_matches_filters(&rec.name) {
+                let finding = self.lint.finding_for(&rec.name);
+                let image = if let Some(f) = finding {
+                    match f.severity {
+                        Severity::Error => AppWindowControls::LINT_ERROR_IMAGE,
+                        Severity::Warning => AppWindowControls::LINT_WARNING_IMAGE,
+                    }
+                } else if rec.effective_differs() {
+                    AppWindowControls::DIFFERS_IMAGE
+                } else if rec.live_only {
+                    AppWindowControls::LIVE_ONLY_IMAGE
+                } else {
+                    AppWindowControls::IMAGE_NONE
+                };
                 sv.insert_item(nwg::InsertListViewItem {
-                    index: Some(idx as i32),
+                    index: Some(idx),
                     column_index: 0,
                     text: Some(rec.name.clone()),
-                    image: None
+                    image: Some(image)
                 });
                 sv.insert_item(nwg::InsertListViewItem {
-                    index: Some(idx as i32),
+                    index: Some(idx),
                     column_index: 1,
                     text: Some(rec.dsn_value.clone()),
                     image: None
                 });
                 sv.insert_item(nwg::InsertListViewItem {
-                    index: Some(idx as i32),
+                    index: Some(idx),
                     column_index: 2,
                     text: Some(rec.default_value.clone()),
                     image: None
                 });
                 sv.insert_item(nwg::InsertListViewItem {
-                    index: Some(idx as i32),
+                    index: Some(idx),
                     column_index: 3,
+                    text: Some(rec.effective_value.clone().unwrap_or_default()),
+                    image: None
+                });
+                let value_type = match &rec.dsn_value_type {
+                    Some(vt) => vt.label().to_string(),
+                    None => "".to_string()
+                };
+                sv.insert_item(nwg::InsertListViewItem {
+                    index: Some(idx),
+                    column_index: 4,
+                    text: Some(value_type),
+                    image: None
+                });
+                sv.insert_item(nwg::InsertListViewItem {
+                    index: Some(idx),
+                    column_index: 5,
+                    text: Some(finding.map(|f| f.message.clone()).unwrap_or_default()),
+                    image: None
+                });
+                sv.insert_item(nwg::InsertListViewItem {
+                    index: Some(idx),
+                    column_index: 6,
                     text: Some(rec.description.clone()),
                     image: None
                 });
@@ -361,6 +860,16 @@ impl AppWindow {
                     a.default_value.to_lowercase().cmp(&b.default_value.to_lowercase())
                 }
             });
+        } else if 3 == col_idx {
+            self.settings.sort_by(|a, b| {
+                let av = a.effective_value.clone().unwrap_or_default().to_lowercase();
+                let bv = b.effective_value.clone().unwrap_or_default().to_lowercase();
+                if desc {
+                    bv.cmp(&av)
+                } else {
+                    av.cmp(&bv)
+                }
+            });
         }
     }
 }
@@ -0,0 +1,35 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// Console subsystem companion of the GUI executable for scripts: the
+// shell waits for it and its exit code is available in %ERRORLEVEL%.
+
+#[allow(dead_code)]
+#[path = "../common/mod.rs"]
+mod common;
+#[path = "../cli.rs"]
+mod cli;
+
+use std::env;
+use std::process;
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        args.push("help".to_string());
+    }
+    process::exit(cli::run(&args));
+}
@@ -0,0 +1,183 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct BulkDialogControls {
+    layout: BulkDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) setting_label: nwg::Label,
+    pub(super) setting_input: nwg::TextInput,
+    pub(super) value_label: nwg::Label,
+    pub(super) value_input: nwg::TextInput,
+    pub(super) unset_checkbox: nwg::CheckBox,
+    pub(super) names_label: nwg::Label,
+    pub(super) names_input: nwg::TextInput,
+    pub(super) pattern_label: nwg::Label,
+    pub(super) pattern_input: nwg::TextInput,
+    pub(super) type_label: nwg::Label,
+    pub(super) type_combo: nwg::ComboBox<&'static str>,
+    pub(super) preview_button: nwg::Button,
+
+    pub(super) summary_label: nwg::Label,
+    pub(super) preview_view: nwg::ListView,
+
+    pub(super) apply_button: nwg::Button,
+    pub(super) close_button: nwg::Button,
+}
+
+impl ui::Controls for BulkDialogControls {
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((640, 520))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("Bulk Apply Setting")
+            .build(&mut self.window)?;
+
+        nwg::Label::builder()
+            .text("Setting:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.setting_label)?;
+        nwg::TextInput::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.setting_input)?;
+        nwg::Label::builder()
+            .text("Value:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.value_label)?;
+        nwg::TextInput::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.value_input)?;
+        nwg::CheckBox::builder()
+            .check_state(nwg::CheckBoxState::Unchecked)
+            .text("Unset value")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.unset_checkbox)?;
+        nwg::Label::builder()
+            .text("Data sources:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.names_label)?;
+        nwg::TextInput::builder()
+            .placeholder_text(Some("Comma-separated names, empty for all"))
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.names_input)?;
+        nwg::Label::builder()
+            .text("Name pattern:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.pattern_label)?;
+        nwg::TextInput::builder()
+            .placeholder_text(Some("Wildcard, for example: analytics_*"))
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.pattern_input)?;
+        nwg::Label::builder()
+            .text("Type:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.type_label)?;
+        nwg::ComboBox::builder()
+            .collection(vec!("User and System", "User", "System"))
+            .selected_index(Some(0))
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.type_combo)?;
+        nwg::Button::builder()
+            .text("Preview")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.preview_button)?;
+
+        nwg::Label::builder()
+            .text("")
+            .font(Some(&self.font_normal))
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.summary_label)?;
+
+        nwg::ListView::builder()
+            .parent(&self.window)
+            .list_style(nwg::ListViewStyle::Detailed)
+            .ex_flags(nwg::ListViewExFlags::GRID | nwg::ListViewExFlags::FULL_ROW_SELECT)
+            .build(&mut self.preview_view)?;
+        self.preview_view.set_headers_enabled(true);
+
+        nwg::Button::builder()
+            .text("Apply")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.apply_button)?;
+        nwg::Button::builder()
+            .text("Close")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.close_button)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.setting_input)
+            .control(&self.value_input)
+            .control(&self.unset_checkbox)
+            .control(&self.names_input)
+            .control(&self.pattern_input)
+            .control(&self.type_combo)
+            .control(&self.preview_button)
+            .control(&self.preview_view)
+            .control(&self.apply_button)
+            .control(&self.close_button)
+            .build();
+    }
+}
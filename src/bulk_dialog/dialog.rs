@@ -0,0 +1,161 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct BulkDialog {
+    pub(super) c: BulkDialogControls,
+
+    args: BulkDialogArgs,
+    changes: Vec<BulkChange>,
+    outcomes: Vec<BulkOutcome>,
+    changed: bool,
+}
+
+impl BulkDialog {
+    pub(super) fn on_unset_checkbox(&mut self, _: nwg::EventData) {
+        let unset = self.c.unset_checkbox.check_state() == nwg::CheckBoxState::Checked;
+        self.c.value_input.set_enabled(!unset);
+        self.on_preview_button(nwg::EventData::NoData);
+    }
+
+    pub(super) fn on_preview_button(&mut self, _: nwg::EventData) {
+        self.outcomes.clear();
+        self.changes = self.plan_changes().unwrap_or_default();
+        self.reload_preview_view();
+    }
+
+    pub(super) fn on_apply_button(&mut self, _: nwg::EventData) {
+        self.outcomes.clear();
+        self.changes = match self.plan_changes() {
+            Some(changes) => changes,
+            None => return
+        };
+        self.reload_preview_view();
+        let pending = self.changes.iter().filter(|c| !c.is_noop()).count();
+        if 0 == pending {
+            nwg::modal_info_message(&self.c.window, "Bulk Apply Setting", "Selected data sources already have this value");
+            return;
+        }
+        let setting = self.c.setting_input.text().trim().to_string();
+        let confirmed = ui::message_box_warning_yn(&format!(
+            "Setting: '{}' will be changed in {} data sources. Would you like to proceed?", setting, pending));
+        if !confirmed {
+            return;
+        }
+        let outcomes = bulk_apply::apply_changes(&self.changes);
+        let failed = outcomes.iter().filter(|o| o.error.is_some()).count();
+        self.changed = true;
+        match registry::load_duckdb_dsns() {
+            Ok(dsns) => self.args.dsns = dsns,
+            Err(e) => ui::message_box_error(&format!("Error reloading data sources, message: '{}'", e))
+        }
+        self.changes = self.plan_changes().unwrap_or_default();
+        self.outcomes = outcomes;
+        self.reload_preview_view();
+        self.c.summary_label.set_text(&format!(
+            "Applied: {}, failed: {}", self.outcomes.len() - failed, failed));
+    }
+
+    fn plan_changes(&self) -> Option<Vec<BulkChange>> {
+        let setting = self.c.setting_input.text().trim().to_string();
+        if let Err(e) = bulk_apply::check_setting_name(&setting) {
+            ui::message_box_error(&e.to_string());
+            return None;
+        }
+        let unset = self.c.unset_checkbox.check_state() == nwg::CheckBoxState::Checked;
+        let value = self.c.value_input.text().trim().to_string();
+        let selector = DsnSelector {
+            names: DsnSelector::parse_names(&self.c.names_input.text()),
+            pattern: self.c.pattern_input.text().trim().to_string(),
+            dsn_type: match self.c.type_combo.selection() {
+                Some(1) => Some(DsnType::USER),
+                Some(2) => Some(DsnType::SYSTEM),
+                _ => None
+            },
+        };
+        let value_opt = if unset { None } else { Some(value.as_str()) };
+        Some(bulk_apply::plan_changes(&self.args.dsns, &selector, &setting, value_opt))
+    }
+
+    fn reload_preview_view(&self) {
+        let pv = &self.c.preview_view;
+        pv.set_redraw(false);
+        pv.clear();
+        for (idx, change) in self.changes.iter().enumerate() {
+            let mut cells = change.report_row();
+            if let Some(outcome) = self.outcomes.iter().find(|o| o.dsn == change.dsn.display_name()) {
+                cells[4] = outcome.status();
+            }
+            for (col_idx, cell) in cells.into_iter().enumerate() {
+                pv.insert_item(nwg::InsertListViewItem {
+                    index: Some(idx as i32),
+                    column_index: col_idx as i32,
+                    text: Some(cell),
+                    image: None
+                });
+            }
+        }
+        pv.set_redraw(true);
+        let pending = self.changes.iter().filter(|c| !c.is_noop()).count();
+        self.c.summary_label.set_text(&format!(
+            "Selected data sources: {}, to be changed: {}", self.changes.len(), pending));
+    }
+}
+
+impl ui::PopupDialog<BulkDialogArgs, BulkDialogResult> for BulkDialog {
+    fn popup(args: BulkDialogArgs) -> ui::PopupJoinHandle<BulkDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        let columns = [("Data source", 160), ("Type", 60), ("Before", 130), ("After", 130), ("Status", 140)];
+        for (idx, (name, width)) in columns.iter().enumerate() {
+            self.c.preview_view.insert_column(nwg::InsertListViewColumn{
+                index: Some(idx as i32),
+                fmt: Some(nwg::ListViewColumnFlags::LEFT),
+                width: Some(*width),
+                text: Some(name.to_string())
+            });
+        }
+        self.c.setting_input.set_text(&self.args.setting);
+        self.c.summary_label.set_text(&format!(
+            "Loaded data sources: {}, use 'Preview' to see values before and after the change", self.args.dsns.len()));
+    }
+
+    fn result(&mut self) -> BulkDialogResult {
+        BulkDialogResult::new(self.changed)
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+const LABEL_WIDTH_PT: u32 = 90;
+
+#[derive(Default)]
+pub(super) struct BulkDialogLayout {
+    root_layout: nwg::FlexboxLayout,
+    setting_layout: nwg::FlexboxLayout,
+    value_layout: nwg::FlexboxLayout,
+    names_layout: nwg::FlexboxLayout,
+    pattern_layout: nwg::FlexboxLayout,
+    type_layout: nwg::FlexboxLayout,
+    buttons_layout: nwg::FlexboxLayout,
+}
+
+impl ui::Layout<BulkDialogControls> for BulkDialogLayout {
+    fn build(&self, c: &BulkDialogControls) -> Result<(), nwg::NwgError> {
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.setting_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.setting_input)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .build_partial(&self.setting_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.value_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.value_input)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .child(&c.unset_checkbox)
+            .child_size(ui::size_builder()
+                .width_pt(110)
+                .height_input_form_row()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(10)
+                .build())
+            .build_partial(&self.value_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.names_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.names_input)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .build_partial(&self.names_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.pattern_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.pattern_input)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .build_partial(&self.pattern_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.type_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.type_combo)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .child(&c.preview_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(10)
+                .build())
+            .build_partial(&self.type_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .justify_content(ui::JustifyContent::FlexEnd)
+            .auto_spacing(None)
+            .child(&c.apply_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child(&c.close_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .build_partial(&self.buttons_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Column)
+            .child_layout(&self.setting_layout)
+            .child_layout(&self.value_layout)
+            .child_layout(&self.names_layout)
+            .child_layout(&self.pattern_layout)
+            .child_layout(&self.type_layout)
+
+            .child(&c.summary_label)
+            .child_size(ui::size_builder()
+                .height_input_form_row()
+                .width_auto()
+                .build())
+            .child_align_self(ui::AlignSelf::Stretch)
+
+            .child(&c.preview_view)
+            .child_size(ui::size_builder()
+                .height_auto()
+                .width_auto()
+                .build())
+            .child_align_self(ui::AlignSelf::Stretch)
+            .child_flex_grow(1.0)
+
+            .child_layout(&self.buttons_layout)
+            .child_align_self(ui::AlignSelf::Stretch)
+
+            .build(&self.root_layout)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+mod args;
+mod controls;
+mod dialog;
+mod events;
+mod layout;
+mod nui;
+mod result;
+
+use std::thread;
+
+use nwg::NativeUi;
+
+use crate::*;
+use nwg_ui as ui;
+use ui::Controls;
+use ui::Events;
+use ui::Layout;
+use ui::PopupArgs;
+use ui::PopupDialog;
+
+pub use args::BulkDialogArgs;
+use common::bulk_apply;
+use common::bulk_apply::BulkChange;
+use common::bulk_apply::BulkOutcome;
+use common::bulk_apply::DsnSelector;
+use common::registry;
+use common::DsnType;
+use common::RegistryDsn;
+use controls::BulkDialogControls;
+pub use dialog::BulkDialog;
+use events::BulkDialogEvents;
+use layout::BulkDialogLayout;
+pub use result::BulkDialogResult;
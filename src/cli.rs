@@ -0,0 +1,210 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use std::io::Write;
+
+use crate::*;
+use common::bulk_apply;
+use common::bulk_apply::DsnSelector;
+use common::db_conflicts;
+use common::dsn_diff;
+use common::lint;
+use common::lint::SystemLimits;
+use common::registry;
+use common::settings_catalog;
+use common::ConfigError;
+use common::DsnType;
+use common::RegistryDsn;
+
+const ATTACH_PARENT_PROCESS: u32 = 0xFFFFFFFF;
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn AttachConsole(process_id: u32) -> i32;
+}
+
+const USAGE: &str = "Usage:
+  duckdb_odbc_config_cli.exe diff <left DSN> <right DSN> [--json] [--all]
+  duckdb_odbc_config_cli.exe set <setting> <value>|--unset [--dsn <name>]... [--match <pattern>] [--type user|system] [--yes]
+  duckdb_odbc_config_cli.exe lint [<DSN>]...
+  duckdb_odbc_config_cli.exe conflicts
+
+Commands:
+  diff       compare values of two DuckDB data sources, --all includes equal values
+  set        set or unset a value in all selected DuckDB data sources, without --yes
+             only the preview of before and after values is printed
+  lint       check configuration of the specified or all DuckDB data sources,
+             exits with a non-zero code when errors are found
+  conflicts  list database files used by more than one data source where at least
+             one opens it read-write, exits with a non-zero code when found";
+
+pub fn run(args: &[String]) -> i32 {
+    // the GUI app is built for the windows subsystem, output goes
+    // to the console of the calling shell if there is one
+    unsafe { AttachConsole(ATTACH_PARENT_PROCESS) };
+    let res = match args[0].as_str() {
+        "diff" => diff(&args[1..]).map(|out| (out, 0)),
+        "set" => bulk_set(&args[1..]).map(|out| (out, 0)),
+        "lint" => lint(&args[1..]),
+        "conflicts" => conflicts(&args[1..]),
+        "help" | "--help" | "/?" => Ok((USAGE.to_string(), 0)),
+        other => Err(ConfigError::from_string(format!("Unknown command: '{}'\n\n{}", other, USAGE)))
+    };
+    match res {
+        Ok((out, code)) => {
+            let _ = writeln!(std::io::stdout(), "{}", out);
+            code
+        },
+        Err(e) => {
+            let _ = writeln!(std::io::stderr(), "Error: {}", e);
+            1
+        }
+    }
+}
+
+fn diff(args: &[String]) -> Result<String, ConfigError> {
+    let names: Vec<&String> = args.iter().filter(|a| !a.starts_with("--")).collect();
+    let json = args.iter().any(|a| "--json" == a);
+    let include_equal = args.iter().any(|a| "--all" == a);
+    if 2 != names.len() {
+        return Err(ConfigError::from_string(format!("Two data source names are required\n\n{}", USAGE)));
+    }
+    let dsns = registry::load_duckdb_dsns()?;
+    let left = find_dsn(&dsns, names[0])?;
+    let right = find_dsn(&dsns, names[1])?;
+    let catalog = settings_catalog(left.driver.as_ref());
+    let diff = dsn_diff::diff_dsns(left, right, &catalog);
+    if json {
+        Ok(diff.render_json(include_equal))
+    } else {
+        Ok(diff.render_text(include_equal).replace("\r\n", "\n"))
+    }
+}
+
+fn bulk_set(args: &[String]) -> Result<String, ConfigError> {
+    let mut positional: Vec<&String> = vec!();
+    let mut selector = DsnSelector::default();
+    let mut unset = false;
+    let mut confirmed = false;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--unset" => unset = true,
+            "--yes" => confirmed = true,
+            "--dsn" => selector.names.push(option_value(arg, iter.next())?.to_string()),
+            "--match" => selector.pattern = option_value(arg, iter.next())?.to_string(),
+            "--type" => selector.dsn_type = match option_value(arg, iter.next())?.to_lowercase().as_str() {
+                "user" => Some(DsnType::USER),
+                "system" => Some(DsnType::SYSTEM),
+                other => return Err(ConfigError::from_string(format!(
+                    "Invalid data source type: '{}', expected 'user' or 'system'", other)))
+            },
+            _ => positional.push(arg)
+        }
+    }
+    let expected = if unset { 1 } else { 2 };
+    if expected != positional.len() {
+        return Err(ConfigError::from_string(format!("Setting name and a value or '--unset' are required\n\n{}", USAGE)));
+    }
+    let setting = positional[0].as_str();
+    bulk_apply::check_setting_name(setting)?;
+    let value = positional.get(1).map(|v| v.as_str());
+    let dsns = registry::load_duckdb_dsns()?;
+    for name in &selector.names {
+        find_dsn(&dsns, name)?;
+    }
+    let changes = bulk_apply::plan_changes(&dsns, &selector, setting, value);
+    if changes.is_empty() {
+        return Err(ConfigError::from_string("No DuckDB data sources match the selection".to_string()));
+    }
+    let mut lines: Vec<String> = changes.iter()
+        .map(|c| {
+            let row = c.report_row();
+            format!("{} ({}): {} -> {} {}", row[0], row[1], row[2], row[3], row[4]).trim_end().to_string()
+        })
+        .collect();
+    if !confirmed {
+        lines.push("".to_string());
+        lines.push("Preview only, run again with '--yes' to apply".to_string());
+        return Ok(lines.join("\n"));
+    }
+    let outcomes = bulk_apply::apply_changes(&changes);
+    lines.push("".to_string());
+    lines.extend(outcomes.iter().map(|o| format!("{}: {}", o.dsn, o.status())));
+    if outcomes.iter().any(|o| o.error.is_some()) {
+        return Err(ConfigError::from_string(lines.join("\n")));
+    }
+    Ok(lines.join("\n"))
+}
+
+// Report is printed to stdout in both cases, exit code is 1 when any data source has errors.
+fn lint(args: &[String]) -> Result<(String, i32), ConfigError> {
+    let dsns = registry::load_duckdb_dsns()?;
+    let selected: Vec<&RegistryDsn> = if args.is_empty() {
+        dsns.iter().collect()
+    } else {
+        args.iter()
+            .map(|name| find_dsn(&dsns, name))
+            .collect::<Result<Vec<&RegistryDsn>, ConfigError>>()?
+    };
+    let limits = SystemLimits::current();
+    let conflicts = db_conflicts::find_conflicts(&dsns);
+    let reports: Vec<lint::LintReport> = selected.into_iter()
+        .map(|dsn| lint::lint_dsn(dsn, &settings_catalog(dsn.driver.as_ref()), &limits, &conflicts))
+        .collect();
+    let code = if reports.iter().any(|r| r.has_errors()) { 1 } else { 0 };
+    let text: Vec<String> = reports.iter().map(|r| r.render_text()).collect();
+    Ok((text.join("\n"), code))
+}
+
+fn conflicts(args: &[String]) -> Result<(String, i32), ConfigError> {
+    if !args.is_empty() {
+        return Err(ConfigError::from_string(format!("Unexpected arguments: {}\n\n{}", args.join(" "), USAGE)));
+    }
+    let dsns = registry::load_duckdb_dsns()?;
+    let conflicts = db_conflicts::find_conflicts(&dsns);
+    if conflicts.is_empty() {
+        return Ok(("No database files are shared in a conflicting mode".to_string(), 0));
+    }
+    let text: Vec<String> = conflicts.iter().map(|g| g.render_text()).collect();
+    Ok((text.join("\n"), 1))
+}
+
+fn option_value<'a>(option: &str, value: Option<&'a String>) -> Result<&'a str, ConfigError> {
+    match value {
+        Some(v) => Ok(v.as_str()),
+        None => Err(ConfigError::from_string(format!("Value is required for option: '{}'", option)))
+    }
+}
+
+// Matches the name shown in the GUI first, plain names
+// are accepted when they are not ambiguous.
+pub fn find_dsn<'a>(dsns: &'a [RegistryDsn], name: &str) -> Result<&'a RegistryDsn, ConfigError> {
+    if let Some(dsn) = dsns.iter().find(|d| d.display_name() == name) {
+        return Ok(dsn);
+    }
+    let matching: Vec<&RegistryDsn> = dsns.iter()
+        .filter(|d| d.name.eq_ignore_ascii_case(name))
+        .collect();
+    match matching.len() {
+        1 => Ok(matching[0]),
+        0 => Err(ConfigError::from_string(format!("DuckDB data source not found: '{}'", name))),
+        _ => {
+            let options: Vec<String> = matching.iter().map(|d| format!("'{}'", d.display_name())).collect();
+            Err(ConfigError::from_string(format!(
+                "Data source name: '{}' is ambiguous, use one of: {}", name, options.join(", "))))
+        }
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use wildmatch::WildMatch;
+
+const NOT_SET_LABEL: &str = "(not set)";
+
+#[derive(Debug, Clone, Default)]
+pub struct DsnSelector {
+    pub names: Vec<String>,
+    pub pattern: String,
+    pub dsn_type: Option<DsnType>,
+}
+
+impl DsnSelector {
+    // Explicit names are matched against the name shown in the GUI or the plain
+    // DSN name, empty names list and empty pattern select all data sources.
+    pub fn matches(&self, dsn: &RegistryDsn) -> bool {
+        if let Some(dt) = &self.dsn_type {
+            if dt.label() != dsn.dsn_type.label() {
+                return false;
+            }
+        }
+        let by_name = self.names.is_empty() || self.names.iter()
+            .any(|n| dsn.display_name() == *n || dsn.name.eq_ignore_ascii_case(n));
+        let by_pattern = self.pattern.is_empty() || WildMatch::new(&self.pattern).matches(&dsn.name);
+        by_name && by_pattern
+    }
+
+    pub fn parse_names(list: &str) -> Vec<String> {
+        list.split([',', ';'])
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BulkChange {
+    pub dsn: RegistryDsn,
+    pub setting: String,
+    pub before: Option<RegistrySetting>,
+    pub after: Option<String>,
+}
+
+impl BulkChange {
+    pub fn is_noop(&self) -> bool {
+        match (&self.before, &self.after) {
+            (Some(b), Some(a)) => b.value.trim() == a.trim(),
+            (None, None) => true,
+            _ => false
+        }
+    }
+
+    pub fn before_value(&self) -> &str {
+        self.before.as_ref().map(|s| s.value.as_str()).unwrap_or(NOT_SET_LABEL)
+    }
+
+    pub fn after_value(&self) -> &str {
+        self.after.as_deref().unwrap_or(NOT_SET_LABEL)
+    }
+
+    pub fn report_row(&self) -> Vec<String> {
+        let status = if self.is_noop() { "unchanged" } else { "" };
+        vec!(self.dsn.display_name(), self.dsn.dsn_type.label().to_string(),
+            self.before_value().to_string(), self.after_value().to_string(), status.to_string())
+    }
+
+    fn apply(&self) -> Result<(), ConfigError> {
+        let dsn = &self.dsn;
+        match &self.after {
+            Some(value) => {
+                // existing values keep their registry type
+                let value_type = self.before.as_ref()
+                    .map(|s| s.value_type.clone())
+                    .unwrap_or(RegistryValueType::SZ);
+                registry::set_dsn_value_with_type(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name, &self.setting, value, &value_type)
+            },
+            None => registry::delete_dsn_value(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name, &self.setting)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BulkOutcome {
+    pub dsn: String,
+    pub error: Option<String>,
+}
+
+impl BulkOutcome {
+    pub fn status(&self) -> String {
+        match &self.error {
+            Some(e) => format!("failed: {}", e),
+            None => "applied".to_string()
+        }
+    }
+}
+
+pub fn plan_changes(dsns: &[RegistryDsn], selector: &DsnSelector, setting: &str, value: Option<&str>) -> Vec<BulkChange> {
+    dsns.iter()
+        .filter(|d| selector.matches(d))
+        .map(|d| BulkChange {
+            dsn: d.clone(),
+            setting: setting.to_string(),
+            before: d.settings.iter().find(|s| s.name.eq_ignore_ascii_case(setting)).cloned(),
+            after: value.map(|v| v.to_string()),
+        })
+        .collect()
+}
+
+// Failure on one data source does not stop the batch,
+// unchanged values are skipped.
+pub fn apply_changes(changes: &[BulkChange]) -> Vec<BulkOutcome> {
+    let policy = policy::Policy::load();
+    changes.iter()
+        .filter(|c| !c.is_noop())
+        .map(|c| {
+            let res = match &policy {
+                Ok(pl) => pl.check(&c.setting, c.after.as_deref()).and_then(|_| c.apply()),
+                Err(e) => Err(ConfigError::new(e))
+            };
+            BulkOutcome {
+                dsn: c.dsn.display_name(),
+                error: res.err().map(|e| e.to_string()),
+            }
+        })
+        .collect()
+}
+
+pub fn check_setting_name(setting: &str) -> Result<(), ConfigError> {
+    if setting.trim().is_empty() {
+        return Err(ConfigError::from_string("Setting name must be specified".to_string()));
+    }
+    if registry::DRIVER_SETTING_NAME.eq_ignore_ascii_case(setting.trim()) {
+        return Err(ConfigError::from_string(format!(
+            "Setting: '{}' cannot be changed in bulk", registry::DRIVER_SETTING_NAME)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selector() {
+        let user = RegistryDsn::with_settings("Sales", &[]);
+        let system = RegistryDsn {
+            dsn_type: DsnType::SYSTEM,
+            ..RegistryDsn::with_settings("Sales_Archive", &[])
+        };
+        assert!(DsnSelector::default().matches(&user));
+        let by_names = DsnSelector {
+            names: DsnSelector::parse_names(" sales ;, other,"),
+            ..DsnSelector::default()
+        };
+        assert_eq!(vec!("sales", "other"), by_names.names);
+        assert!(by_names.matches(&user));
+        assert!(!by_names.matches(&system));
+        let by_pattern = DsnSelector {
+            pattern: "Sales*".to_string(),
+            dsn_type: Some(DsnType::SYSTEM),
+            ..DsnSelector::default()
+        };
+        assert!(!by_pattern.matches(&user));
+        assert!(by_pattern.matches(&system));
+    }
+
+    #[test]
+    fn planned_changes() {
+        let dsns = vec!(
+            RegistryDsn::with_settings("a", &[("Threads", "4")]),
+            RegistryDsn::with_settings("b", &[("threads", "8")]),
+            RegistryDsn::with_settings("c", &[]),
+        );
+        let changes = plan_changes(&dsns, &DsnSelector::default(), "threads", Some("4 "));
+        assert_eq!(3, changes.len());
+        assert!(changes[0].is_noop());
+        assert!(!changes[1].is_noop());
+        assert_eq!(vec!("c", "User", "(not set)", "4 ", ""), changes[2].report_row());
+        let changes = plan_changes(&dsns, &DsnSelector::default(), "threads", None);
+        assert_eq!("(not set)", changes[0].after_value());
+        assert!(!changes[0].is_noop());
+        assert!(changes[2].is_noop());
+    }
+
+    #[test]
+    fn setting_names() {
+        assert!(check_setting_name("threads").is_ok());
+        assert!(check_setting_name(" ").is_err());
+        assert!(check_setting_name(" driver ").is_err());
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use std::fs;
+
+use duckdb_setting::parse_bool;
+use duckdb_setting::unquote;
+use lint::Severity;
+
+const ENABLE_EXTERNAL_ACCESS: &str = "enable_external_access";
+
+// Values of a data source with a pending change applied, setting
+// names are matched case-insensitively.
+#[derive(Default, Debug, Clone)]
+pub struct SettingValues {
+    values: Vec<(String, String)>,
+}
+
+impl SettingValues {
+    pub fn from_dsn(dsn: &RegistryDsn) -> Self {
+        Self {
+            values: dsn.settings.iter()
+                .map(|s| (s.name.to_lowercase(), s.value.clone()))
+                .collect()
+        }
+    }
+
+    pub fn with_change(mut self, setting: &str, value: Option<&str>) -> Self {
+        let name = setting.to_lowercase();
+        self.values.retain(|(n, _)| *n != name);
+        if let Some(value) = value {
+            self.values.push((name, value.to_string()));
+        }
+        self
+    }
+
+    // Empty values are the same as not set
+    fn get(&self, name: &str) -> Option<&str> {
+        self.values.iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| unquote(v.trim()).trim())
+            .filter(|v| !v.is_empty())
+    }
+
+    fn is_true(&self, name: &str, default: bool) -> bool {
+        self.get(name).and_then(parse_bool).unwrap_or(default)
+    }
+}
+
+pub struct ConsistencyRule {
+    pub settings: &'static [&'static str],
+    pub severity: Severity,
+    pub message: &'static str,
+    violated: fn(&SettingValues) -> bool,
+}
+
+impl ConsistencyRule {
+    pub fn involves(&self, setting: &str) -> bool {
+        self.settings.iter().any(|s| s.eq_ignore_ascii_case(setting))
+    }
+}
+
+pub const CONSISTENCY_RULES: &[ConsistencyRule] = &[
+    ConsistencyRule {
+        settings: &["allowed_paths", ENABLE_EXTERNAL_ACCESS],
+        severity: Severity::Warning,
+        message: "Setting: 'allowed_paths' only takes effect when 'enable_external_access' is set to false",
+        violated: |sv| sv.get("allowed_paths").is_some() && sv.is_true(ENABLE_EXTERNAL_ACCESS, true),
+    },
+    ConsistencyRule {
+        settings: &["allowed_directories", ENABLE_EXTERNAL_ACCESS],
+        severity: Severity::Warning,
+        message: "Setting: 'allowed_directories' only takes effect when 'enable_external_access' is set to false",
+        violated: |sv| sv.get("allowed_directories").is_some() && sv.is_true(ENABLE_EXTERNAL_ACCESS, true),
+    },
+    ConsistencyRule {
+        settings: &["autoinstall_known_extensions", ENABLE_EXTERNAL_ACCESS],
+        severity: Severity::Warning,
+        message: "Setting: 'autoinstall_known_extensions' has no effect when 'enable_external_access' is set to false, \
+            extensions cannot be downloaded",
+        violated: |sv| sv.is_true("autoinstall_known_extensions", false) && !sv.is_true(ENABLE_EXTERNAL_ACCESS, true),
+    },
+    ConsistencyRule {
+        settings: &["lock_configuration", registry::SESSION_INIT_SQL_FILE_SETTING_NAME],
+        severity: Severity::Error,
+        message: "Setting: 'lock_configuration' is enabled, but the session init SQL file contains SET or PRAGMA statements, \
+            connection will fail",
+        violated: |sv| sv.is_true("lock_configuration", false) &&
+            sv.get(registry::SESSION_INIT_SQL_FILE_SETTING_NAME).map(init_file_sets_values).unwrap_or(false),
+    },
+    ConsistencyRule {
+        settings: &[db_path::ACCESS_MODE_SETTING_NAME, registry::DATABASE_SETTING_NAME],
+        severity: Severity::Warning,
+        message: "Setting: 'access_mode' is READ_ONLY, but the database is in-memory, it will always be empty",
+        violated: |sv| sv.get(db_path::ACCESS_MODE_SETTING_NAME).map(db_path::is_read_only_access_mode).unwrap_or(false) &&
+            sv.get(registry::DATABASE_SETTING_NAME).unwrap_or(db_path::MEMORY_DB_PATH).to_lowercase()
+                .starts_with(db_path::MEMORY_DB_PATH),
+    },
+    ConsistencyRule {
+        settings: &["profile_output", "enable_profiling"],
+        severity: Severity::Warning,
+        message: "Setting: 'profile_output' has no effect unless 'enable_profiling' is set",
+        violated: |sv| sv.get("profile_output").is_some() && sv.get("enable_profiling").is_none(),
+    },
+];
+
+// Unreadable file is reported by the linter separately
+fn init_file_sets_values(path: &str) -> bool {
+    match fs::read_to_string(paths::expand_env_vars(path)) {
+        Ok(script) => init_sql::split_statements(&script).iter()
+            .any(|st| init_sql::parse_setting_statement(&st.text).is_some()),
+        Err(_) => false
+    }
+}
+
+pub fn check_values(values: &SettingValues) -> Vec<&'static ConsistencyRule> {
+    CONSISTENCY_RULES.iter()
+        .filter(|rule| (rule.violated)(values))
+        .collect()
+}
+
+// Only the rules that involve the changed setting are reported.
+pub fn check_change(dsn: &RegistryDsn, setting: &str, value: Option<&str>) -> Vec<&'static ConsistencyRule> {
+    let values = SettingValues::from_dsn(dsn).with_change(setting, value);
+    check_values(&values).into_iter()
+        .filter(|rule| rule.involves(setting))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(rules: &[&ConsistencyRule]) -> Vec<&'static str> {
+        rules.iter().map(|r| r.message).collect()
+    }
+
+    #[test]
+    fn external_access() {
+        let values = SettingValues::default()
+            .with_change("Allowed_Paths", Some("['Q:\\Data']"));
+        let violated = check_values(&values);
+        assert_eq!(1, violated.len());
+        assert!(violated[0].involves("ENABLE_EXTERNAL_ACCESS"));
+        let values = values.with_change(ENABLE_EXTERNAL_ACCESS, Some("false"));
+        assert!(check_values(&values).is_empty());
+        let values = values.with_change("autoinstall_known_extensions", Some("'true'"));
+        assert_eq!(vec!(CONSISTENCY_RULES[2].message), messages(&check_values(&values)));
+    }
+
+    #[test]
+    fn empty_values_not_set() {
+        let values = SettingValues::default()
+            .with_change("profile_output", Some("Q:\\profile.json"))
+            .with_change("enable_profiling", Some(" '' "));
+        assert_eq!(vec!(CONSISTENCY_RULES[5].message), messages(&check_values(&values)));
+        let values = values.with_change("profile_output", None);
+        assert!(check_values(&values).is_empty());
+    }
+
+    #[test]
+    fn read_only_memory() {
+        let values = SettingValues::default()
+            .with_change(db_path::ACCESS_MODE_SETTING_NAME, Some("read_only"));
+        assert_eq!(vec!(CONSISTENCY_RULES[4].message), messages(&check_values(&values)));
+        let values = values.with_change(registry::DATABASE_SETTING_NAME, Some("Q:\\test.duckdb"));
+        assert!(check_values(&values).is_empty());
+    }
+
+    #[test]
+    fn change_involves_setting() {
+        let dsn = RegistryDsn::with_settings("test", &[("profile_output", "Q:\\profile.json")]);
+        assert!(check_change(&dsn, "allowed_paths", Some("['Q:\\Data']")).iter()
+            .all(|rule| rule.involves("allowed_paths")));
+        assert_eq!(0, check_change(&dsn, "threads", Some("4")).len());
+        assert_eq!(1, check_change(&dsn, "enable_profiling", None).len());
+        assert_eq!(0, check_change(&dsn, "enable_profiling", Some("json")).len());
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct DbFileUser {
+    pub dsn: String,
+    pub read_only: bool,
+}
+
+impl DbFileUser {
+    fn label(&self) -> String {
+        let mode = if self.read_only { "read-only" } else { "read-write" };
+        format!("'{}' ({})", self.dsn, mode)
+    }
+}
+
+// Data sources that point to the same database file
+#[derive(Debug, Clone)]
+pub struct DbFileGroup {
+    pub path: String,
+    pub users: Vec<DbFileUser>,
+}
+
+impl DbFileGroup {
+    // DuckDB allows a single read-write process per file, read-only
+    // processes cannot open the file while it is locked for writing.
+    pub fn is_conflict(&self) -> bool {
+        self.users.len() > 1 && self.users.iter().any(|u| !u.read_only)
+    }
+
+    pub fn problem(&self) -> String {
+        let writers = self.users.iter().filter(|u| !u.read_only).count();
+        if writers > 1 {
+            format!("{} data sources open the database file read-write, only one process can open it for writing at a time",
+                writers)
+        } else {
+            "Database file is opened both read-write and read-only, read-only connections fail while \
+                the read-write one is open".to_string()
+        }
+    }
+
+    pub fn users_listing(&self, except_dsn: &str) -> String {
+        let listing: Vec<String> = self.users.iter()
+            .filter(|u| u.dsn != except_dsn)
+            .map(|u| u.label())
+            .collect();
+        listing.join(", ")
+    }
+
+    pub fn render_text(&self) -> String {
+        format!("{}\n  {}\n  {}", self.path, self.users_listing(""), self.problem())
+    }
+}
+
+// In-memory databases and remote URIs are not shared between processes
+pub fn database_file_key(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    let lower = trimmed.to_lowercase();
+    if trimmed.is_empty() || lower.starts_with(db_path::MEMORY_DB_PATH) || lower.contains("://") || lower.starts_with("md:") {
+        return None;
+    }
+    Some(paths::normalize_path(trimmed))
+}
+
+pub fn group_by_database(dsns: &[RegistryDsn]) -> Vec<DbFileGroup> {
+    let mut res: Vec<DbFileGroup> = vec!();
+    for dsn in dsns {
+        let key = match dsn.settings.iter()
+            .find(|s| registry::DATABASE_SETTING_NAME == s.name)
+            .and_then(|s| database_file_key(&s.value)) {
+            Some(key) => key,
+            None => continue
+        };
+        let read_only = dsn.settings.iter()
+            .find(|s| db_path::ACCESS_MODE_SETTING_NAME == s.name)
+            .map(|s| db_path::is_read_only_access_mode(&s.value))
+            .unwrap_or(false);
+        let user = DbFileUser {
+            dsn: dsn.display_name(),
+            read_only,
+        };
+        match res.iter_mut().find(|g| g.path == key) {
+            Some(group) => group.users.push(user),
+            None => res.push(DbFileGroup {
+                path: key,
+                users: vec!(user),
+            })
+        }
+    }
+    res
+}
+
+pub fn find_conflicts(dsns: &[RegistryDsn]) -> Vec<DbFileGroup> {
+    group_by_database(dsns).into_iter()
+        .filter(|g| g.is_conflict())
+        .collect()
+}
+
+pub fn conflict_for<'a>(conflicts: &'a [DbFileGroup], dsn: &RegistryDsn) -> Option<&'a DbFileGroup> {
+    let name = dsn.display_name();
+    conflicts.iter().find(|g| g.users.iter().any(|u| u.dsn == name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dsn(name: &str, database: &str, access_mode: &str) -> RegistryDsn {
+        let mut settings = vec!((registry::DATABASE_SETTING_NAME, database));
+        if !access_mode.is_empty() {
+            settings.push((db_path::ACCESS_MODE_SETTING_NAME, access_mode));
+        }
+        RegistryDsn::with_settings(name, &settings)
+    }
+
+    #[test]
+    fn file_keys() {
+        assert_eq!(None, database_file_key(":memory:"));
+        assert_eq!(None, database_file_key(":memory:shared"));
+        assert_eq!(None, database_file_key("md:my_db"));
+        assert_eq!(None, database_file_key("s3://bucket/test.duckdb"));
+        assert_eq!(None, database_file_key("  "));
+        assert_eq!(database_file_key("q:\\test.DUCKDB"), database_file_key("Q:/Data/../Test.duckdb"));
+    }
+
+    #[test]
+    fn conflicts() {
+        let dsns = vec!(
+            dsn("a", "Q:\\test.duckdb", ""),
+            dsn("b", "q:/TEST.duckdb", "READ_ONLY"),
+            dsn("c", "Q:\\other.duckdb", ""),
+            dsn("d", "Q:\\shared.duckdb", "read_only"),
+            dsn("e", "Q:\\shared.duckdb", "'READ_ONLY'"),
+            dsn("f", ":memory:", ""),
+            dsn("g", ":memory:", ""),
+        );
+        assert_eq!(3, group_by_database(&dsns).len());
+        let conflicts = find_conflicts(&dsns);
+        assert_eq!(1, conflicts.len());
+        assert_eq!("'b' (read-only)", conflicts[0].users_listing("a"));
+        assert!(conflicts[0].problem().starts_with("Database file is opened both read-write and read-only"));
+        assert!(conflict_for(&conflicts, &dsns[1]).is_some());
+        assert!(conflict_for(&conflicts, &dsns[3]).is_none());
+    }
+
+    #[test]
+    fn multiple_writers() {
+        let dsns = vec!(
+            dsn("a", "Q:\\test.duckdb", ""),
+            dsn("b", "Q:\\test.duckdb", "READ_WRITE"),
+        );
+        let conflicts = find_conflicts(&dsns);
+        assert_eq!(1, conflicts.len());
+        assert!(conflicts[0].problem().starts_with("2 data sources open the database file read-write"));
+    }
+}
@@ -0,0 +1,498 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use std::fs;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use super::*;
+
+pub const STORAGE_COMPATIBILITY_SETTING_NAME: &str = "storage_compatibility_version";
+pub const WAL_FILE_SUFFIX: &str = ".wal";
+
+// Main header follows the 8-byte block checksum at the start of the file,
+// two database headers follow it in the next 4KB blocks, the one with
+// the higher iteration is the active one.
+const FILE_HEADER_SIZE: usize = 4096;
+const CHECKSUM_SIZE: usize = 8;
+const MAGIC_BYTES: &[u8; 4] = b"DUCK";
+const MAIN_HEADER_FLAG_COUNT: usize = 4;
+const VERSION_FIELD_SIZE: usize = 32;
+const ENCRYPTED_DATABASE_FLAG: u64 = 1;
+
+// First library release that writes each storage version, storage
+// versions before 64 cannot be read by v0.9.0 and later.
+const STORAGE_VERSIONS: [(u64, &str); 4] = [
+    (64, "v0.9.0"),
+    (65, "v1.2.0"),
+    (66, "v1.3.0"),
+    (67, "v1.4.0"),
+];
+
+// First library release for each serialization version recorded as
+// storage_compatibility_version in the database header, v0.10.2 is the
+// default compatibility version and still writes version 1.
+const SERIALIZATION_VERSIONS: [(u64, &str); 6] = [
+    (1, "v0.10.0"),
+    (2, "v0.10.3"),
+    (3, "v1.1.0"),
+    (4, "v1.2.0"),
+    (5, "v1.3.0"),
+    (6, "v1.4.0"),
+];
+
+#[derive(Default, Debug, Clone)]
+pub struct DbFileHeader {
+    pub storage_version: u64,
+    pub flags: [u64; MAIN_HEADER_FLAG_COUNT],
+    pub library_version: String,
+    pub source_id: String,
+    pub iteration: u64,
+    pub block_count: u64,
+    pub block_alloc_size: u64,
+    pub vector_size: u64,
+    pub serialization_compatibility: u64,
+}
+
+impl DbFileHeader {
+    pub fn is_encrypted(&self) -> bool {
+        0 != (self.flags[0] & ENCRYPTED_DATABASE_FLAG)
+    }
+
+    pub fn storage_version_label(&self) -> String {
+        match STORAGE_VERSIONS.iter().rev().find(|(sv, _)| *sv <= self.storage_version) {
+            Some((sv, lib)) if *sv == self.storage_version => format!("{} ({}+)", sv, lib),
+            Some(_) => format!("{} (unknown, newer than {})", self.storage_version,
+                STORAGE_VERSIONS[STORAGE_VERSIONS.len() - 1].1),
+            None => format!("{} (before v0.9.0)", self.storage_version)
+        }
+    }
+
+    pub fn compatibility_version(&self) -> Option<&'static str> {
+        SERIALIZATION_VERSIONS.iter()
+            .find(|(sv, _)| *sv == self.serialization_compatibility)
+            .map(|(_, lib)| *lib)
+    }
+
+    pub fn summary(&self) -> String {
+        let mut res = format!("DuckDB file, storage version: {}", self.storage_version_label());
+        if !self.library_version.is_empty() {
+            res.push_str(&format!(", written by: {}", self.library_version));
+            if !self.source_id.is_empty() {
+                res.push_str(&format!(" ({})", self.source_id));
+            }
+        }
+        match self.compatibility_version() {
+            Some(lib) => res.push_str(&format!(", compatibility: {}", lib)),
+            None if 0 != self.serialization_compatibility => res.push_str(&format!(
+                ", compatibility: unknown ({})", self.serialization_compatibility)),
+            None => {}
+        }
+        if self.is_encrypted() {
+            res.push_str(", encrypted");
+        }
+        res
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct DbFileInspection {
+    pub header: Option<DbFileHeader>,
+    pub warnings: Vec<String>,
+}
+
+impl DbFileInspection {
+    pub fn description(&self) -> String {
+        let mut parts: Vec<String> = self.header.iter().map(|h| h.summary()).collect();
+        for warning in &self.warnings {
+            parts.push(format!("warning: {}", warning));
+        }
+        parts.join("; ")
+    }
+}
+
+pub fn read_header(path: &str) -> Result<DbFileHeader, ConfigError> {
+    let mut file = File::open(path).map_err(|e| ConfigError::from_string(format!(
+        "Cannot open database file, path: '{}', message: {}", path, e)))?;
+    let mut buf = vec!(0u8; FILE_HEADER_SIZE * 3);
+    file.read_exact(&mut buf).map_err(|e| ConfigError::from_string(format!(
+        "Database file is too short, path: '{}', message: {}", path, e)))?;
+    parse_header(&buf).map_err(|e| ConfigError::from_string(format!("{}, path: '{}'", e, path)))
+}
+
+pub fn parse_header(buf: &[u8]) -> Result<DbFileHeader, ConfigError> {
+    if buf.len() < FILE_HEADER_SIZE * 3 {
+        return Err(ConfigError::from("Database file is too short"));
+    }
+    let mut pos = CHECKSUM_SIZE;
+    if MAGIC_BYTES != &buf[pos..pos + MAGIC_BYTES.len()] {
+        return Err(ConfigError::from("Not a DuckDB database file, magic bytes not found"));
+    }
+    pos += MAGIC_BYTES.len();
+    let mut res = DbFileHeader {
+        storage_version: read_u64(buf, &mut pos),
+        ..Default::default()
+    };
+    for flag in res.flags.iter_mut() {
+        *flag = read_u64(buf, &mut pos);
+    }
+    res.library_version = read_version_field(buf, &mut pos);
+    res.source_id = read_version_field(buf, &mut pos);
+    let (first, second) = (&buf[FILE_HEADER_SIZE..FILE_HEADER_SIZE * 2], &buf[FILE_HEADER_SIZE * 2..]);
+    let active = if peek_iteration(second) > peek_iteration(first) {
+        second
+    } else {
+        first
+    };
+    let mut pos = CHECKSUM_SIZE;
+    res.iteration = read_u64(active, &mut pos);
+    // meta block and free list pointers
+    pos += 16;
+    res.block_count = read_u64(active, &mut pos);
+    res.block_alloc_size = read_u64(active, &mut pos);
+    res.vector_size = read_u64(active, &mut pos);
+    res.serialization_compatibility = read_u64(active, &mut pos);
+    Ok(res)
+}
+
+pub fn inspect_file(db_path: &str, compatibility_setting: &str, driver_version: Option<&str>) -> Option<DbFileInspection> {
+    let expanded = paths::expand_env_vars(db_path.trim());
+    if expanded.starts_with(':') || !Path::new(&expanded).is_file() {
+        return None;
+    }
+    let header = match read_header(&expanded) {
+        Ok(header) => header,
+        Err(e) => return Some(DbFileInspection {
+            header: None,
+            warnings: vec!(e.to_string()),
+        })
+    };
+    let mut res = DbFileInspection::default();
+    if header.storage_version < STORAGE_VERSIONS[0].0 {
+        res.warnings.push(format!(
+            "File uses storage version {} that cannot be read by {} and later, it needs to be exported and re-imported",
+            header.storage_version, STORAGE_VERSIONS[0].1));
+    }
+    if let Some(dv) = driver_version.and_then(parse_version) {
+        let max_storage = STORAGE_VERSIONS.iter()
+            .filter(|(_, lib)| parse_version(lib).map(|lv| lv <= dv).unwrap_or(false))
+            .map(|(sv, _)| *sv)
+            .max();
+        if let Some(max) = max_storage {
+            if header.storage_version > max {
+                res.warnings.push(format!(
+                    "File was written with storage version {} that is newer than the one supported by the installed driver {} ({})",
+                    header.storage_version, driver_version.unwrap_or(""), max));
+            }
+        }
+        let max_serialization = SERIALIZATION_VERSIONS.iter()
+            .filter(|(_, lib)| parse_version(lib).map(|lv| lv <= dv).unwrap_or(false))
+            .map(|(sv, _)| *sv)
+            .max();
+        if let Some(max) = max_serialization {
+            if header.serialization_compatibility > max {
+                res.warnings.push(format!(
+                    "File requires compatibility version {} that is newer than the installed driver {}",
+                    header.compatibility_version().unwrap_or("(unknown)"), driver_version.unwrap_or("")));
+            }
+        }
+    }
+    let compat = compatibility_setting.trim().trim_matches('\'');
+    if !compat.is_empty() {
+        let file_compat = header.compatibility_version();
+        let conflicts = if "latest".eq_ignore_ascii_case(compat) {
+            file_compat.is_some() && file_compat != Some(SERIALIZATION_VERSIONS[SERIALIZATION_VERSIONS.len() - 1].1)
+        } else {
+            match (parse_version(compat), file_compat.and_then(parse_version)) {
+                (Some(cv), Some(fv)) => cv != fv && !same_serialization(cv, fv),
+                _ => false
+            }
+        };
+        if conflicts {
+            res.warnings.push(format!(
+                "DSN sets '{}' to '{}', but the existing file uses '{}', the setting only applies to newly created files",
+                STORAGE_COMPATIBILITY_SETTING_NAME, compat, file_compat.unwrap_or("(unknown)")));
+        }
+    }
+    let wal_path = format!("{}{}", expanded, WAL_FILE_SUFFIX);
+    if let Ok(meta) = fs::metadata(&wal_path) {
+        if meta.len() > 0 {
+            res.warnings.push(format!(
+                "WAL file exists: '{}' ({} bytes), it is left after an unclean shutdown or is in use by another process \
+                and will be replayed on next open", wal_path, meta.len()));
+        }
+    }
+    res.header = Some(header);
+    Some(res)
+}
+
+fn same_serialization(left: (u32, u32, u32), right: (u32, u32, u32)) -> bool {
+    let serialization = |v: (u32, u32, u32)| SERIALIZATION_VERSIONS.iter()
+        .filter(|(_, lib)| parse_version(lib).map(|lv| lv <= v).unwrap_or(false))
+        .map(|(sv, _)| *sv)
+        .max();
+    serialization(left) == serialization(right)
+}
+
+pub fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let trimmed = version.trim().trim_start_matches(['v', 'V']);
+    let mut parts = trimmed.split(['.', '-']);
+    let major = parts.next()?.parse::<u32>().ok()?;
+    let minor = parts.next()?.parse::<u32>().ok()?;
+    let patch = parts.next().and_then(|p| p.parse::<u32>().ok()).unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+fn peek_iteration(block: &[u8]) -> u64 {
+    let mut pos = CHECKSUM_SIZE;
+    read_u64(block, &mut pos)
+}
+
+fn read_u64(buf: &[u8], pos: &mut usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buf[*pos..*pos + 8]);
+    *pos += 8;
+    u64::from_le_bytes(bytes)
+}
+
+fn read_version_field(buf: &[u8], pos: &mut usize) -> String {
+    let field = &buf[*pos..*pos + VERSION_FIELD_SIZE];
+    *pos += VERSION_FIELD_SIZE;
+    let len = field.iter().position(|b| 0 == *b).unwrap_or(VERSION_FIELD_SIZE);
+    String::from_utf8_lossy(&field[..len]).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixture {
+        storage_version: u64,
+        flags: u64,
+        library_version: &'static str,
+        iterations: (u64, u64),
+        serialization_compatibility: u64,
+    }
+
+    impl Fixture {
+        fn current() -> Self {
+            Self {
+                storage_version: 67,
+                flags: 0,
+                library_version: "v1.4.0",
+                iterations: (3, 4),
+                serialization_compatibility: 6,
+            }
+        }
+
+        fn bytes(&self) -> Vec<u8> {
+            let mut buf = vec!(0u8; FILE_HEADER_SIZE * 3);
+            let mut pos = CHECKSUM_SIZE;
+            buf[pos..pos + MAGIC_BYTES.len()].copy_from_slice(MAGIC_BYTES);
+            pos += MAGIC_BYTES.len();
+            write_u64(&mut buf, &mut pos, self.storage_version);
+            write_u64(&mut buf, &mut pos, self.flags);
+            pos += (MAIN_HEADER_FLAG_COUNT - 1) * 8;
+            buf[pos..pos + self.library_version.len()].copy_from_slice(self.library_version.as_bytes());
+            pos += VERSION_FIELD_SIZE;
+            buf[pos..pos + 10].copy_from_slice(b"0123456789");
+            let active = if self.iterations.1 > self.iterations.0 { 1 } else { 0 };
+            for (idx, iteration) in [self.iterations.0, self.iterations.1].iter().enumerate() {
+                let mut pos = FILE_HEADER_SIZE * (idx + 1) + CHECKSUM_SIZE;
+                write_u64(&mut buf, &mut pos, *iteration);
+                pos += 16;
+                write_u64(&mut buf, &mut pos, 10 + *iteration);
+                write_u64(&mut buf, &mut pos, 262144);
+                write_u64(&mut buf, &mut pos, 2048);
+                // older header carries a different value to detect the active one
+                let serialization = if idx == active {
+                    self.serialization_compatibility
+                } else {
+                    1
+                };
+                write_u64(&mut buf, &mut pos, serialization);
+            }
+            buf
+        }
+
+        fn write(&self, name: &str) -> String {
+            let path = std::env::temp_dir().join(format!("db_header_test_{}_{}.duckdb", std::process::id(), name));
+            fs::write(&path, self.bytes()).unwrap();
+            path.to_string_lossy().to_string()
+        }
+    }
+
+    fn write_u64(buf: &mut [u8], pos: &mut usize, value: u64) {
+        buf[*pos..*pos + 8].copy_from_slice(&value.to_le_bytes());
+        *pos += 8;
+    }
+
+    // Leading bytes of the three header blocks in the layout written by
+    // release builds, the rest of each block is zeros, checksums are not
+    // verified and are left empty.
+    const V1_1_3_DEFAULT_DUMP: [&str; 3] = [
+        "00 00 00 00 00 00 00 00 44 55 43 4b 40 00 00 00
+         00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+         00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+         00 00 00 00 76 31 2e 31 2e 33 00 00 00 00 00 00
+         00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+         00 00 00 00 31 39 38 36 34 34 35 33 66 37 00 00",
+        "00 00 00 00 00 00 00 00 02 00 00 00 00 00 00 00
+         00 00 00 00 00 00 00 00 ff ff ff ff ff ff ff ff
+         03 00 00 00 00 00 00 00 00 00 04 00 00 00 00 00
+         00 08 00 00 00 00 00 00 01 00 00 00 00 00 00 00",
+        "00 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00
+         00 00 00 00 00 00 00 00 ff ff ff ff ff ff ff ff
+         01 00 00 00 00 00 00 00 00 00 04 00 00 00 00 00
+         00 08 00 00 00 00 00 00 01 00 00 00 00 00 00 00",
+    ];
+
+    // Written with storage_compatibility_version set to 'latest'
+    const V1_4_1_LATEST_DUMP: [&str; 3] = [
+        "00 00 00 00 00 00 00 00 44 55 43 4b 43 00 00 00
+         00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+         00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+         00 00 00 00 76 31 2e 34 2e 31 00 00 00 00 00 00
+         00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+         00 00 00 00 62 33 39 30 61 37 63 33 37 36 00 00",
+        "00 00 00 00 00 00 00 00 03 00 00 00 00 00 00 00
+         00 00 00 00 00 00 00 00 ff ff ff ff ff ff ff ff
+         04 00 00 00 00 00 00 00 00 00 04 00 00 00 00 00
+         00 08 00 00 00 00 00 00 06 00 00 00 00 00 00 00",
+        "00 00 00 00 00 00 00 00 04 00 00 00 00 00 00 00
+         01 00 00 00 00 00 00 00 ff ff ff ff ff ff ff ff
+         06 00 00 00 00 00 00 00 00 00 04 00 00 00 00 00
+         00 08 00 00 00 00 00 00 06 00 00 00 00 00 00 00",
+    ];
+
+    fn dump_bytes(dump: &[&str; 3]) -> Vec<u8> {
+        let mut buf = vec!();
+        for block in dump {
+            let start = buf.len();
+            buf.extend(block.split_whitespace().map(|b| u8::from_str_radix(b, 16).unwrap()));
+            buf.resize(start + FILE_HEADER_SIZE, 0);
+        }
+        buf
+    }
+
+    #[test]
+    fn parse_default_file() {
+        let header = parse_header(&dump_bytes(&V1_1_3_DEFAULT_DUMP)).unwrap();
+        assert_eq!(64, header.storage_version);
+        assert_eq!("v1.1.3", header.library_version);
+        assert_eq!("19864453f7", header.source_id);
+        assert_eq!(2, header.iteration);
+        assert_eq!(3, header.block_count);
+        assert_eq!(262144, header.block_alloc_size);
+        assert_eq!(2048, header.vector_size);
+        assert_eq!(Some("v0.10.0"), header.compatibility_version());
+        assert!(!header.is_encrypted());
+        assert_eq!("DuckDB file, storage version: 64 (v0.9.0+), written by: v1.1.3 (19864453f7), compatibility: v0.10.0",
+            header.summary());
+    }
+
+    #[test]
+    fn parse_latest_file() {
+        let header = parse_header(&dump_bytes(&V1_4_1_LATEST_DUMP)).unwrap();
+        assert_eq!(67, header.storage_version);
+        assert_eq!("v1.4.1", header.library_version);
+        assert_eq!("b390a7c376", header.source_id);
+        assert_eq!(4, header.iteration);
+        assert_eq!(6, header.block_count);
+        assert_eq!(Some("v1.4.0"), header.compatibility_version());
+        assert_eq!("DuckDB file, storage version: 67 (v1.4.0+), written by: v1.4.1 (b390a7c376), compatibility: v1.4.0",
+            header.summary());
+    }
+
+    #[test]
+    fn serialization_versions() {
+        assert!(same_serialization((0, 10, 0), (0, 10, 2)));
+        assert!(!same_serialization((0, 10, 2), (0, 10, 3)));
+        assert!(same_serialization((0, 10, 3), (1, 0, 0)));
+        assert!(!same_serialization((1, 3, 2), (1, 4, 0)));
+    }
+
+    #[test]
+    fn parse_first_header_active() {
+        let fixture = Fixture {
+            iterations: (5, 2),
+            ..Fixture::current()
+        };
+        let header = parse_header(&fixture.bytes()).unwrap();
+        assert_eq!(5, header.iteration);
+        assert_eq!(6, header.serialization_compatibility);
+    }
+
+    #[test]
+    fn parse_older_storage() {
+        let fixture = Fixture {
+            storage_version: 39,
+            library_version: "v0.8.1",
+            serialization_compatibility: 0,
+            ..Fixture::current()
+        };
+        let header = parse_header(&fixture.bytes()).unwrap();
+        assert_eq!("39 (before v0.9.0)", header.storage_version_label());
+        assert_eq!(None, header.compatibility_version());
+        let path = fixture.write("older");
+        let inspection = inspect_file(&path, "", None).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(1, inspection.warnings.len());
+        assert!(inspection.warnings[0].contains("storage version 39"));
+    }
+
+    #[test]
+    fn parse_newer_than_driver() {
+        let path = Fixture::current().write("newer");
+        let inspection = inspect_file(&path, "v1.1.0", Some("1.2.1")).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(3, inspection.warnings.len());
+        assert!(inspection.warnings[0].contains("storage version 67"));
+        assert!(inspection.warnings[1].contains("compatibility version v1.4.0"));
+        assert!(inspection.warnings[2].contains("only applies to newly created files"));
+    }
+
+    #[test]
+    fn parse_truncated() {
+        let bytes = Fixture::current().bytes();
+        assert!(parse_header(&bytes[..FILE_HEADER_SIZE]).is_err());
+        let path = std::env::temp_dir().join(format!("db_header_test_{}_truncated.duckdb", std::process::id()));
+        fs::write(&path, &bytes[..FILE_HEADER_SIZE * 2]).unwrap();
+        let err = read_header(&path.to_string_lossy()).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert!(err.to_string().starts_with("Database file is too short"));
+    }
+
+    #[test]
+    fn parse_not_duckdb() {
+        let mut bytes = Fixture::current().bytes();
+        bytes[CHECKSUM_SIZE..CHECKSUM_SIZE + 4].copy_from_slice(b"SQLi");
+        let err = parse_header(&bytes).unwrap_err();
+        assert!(err.to_string().starts_with("Not a DuckDB database file"));
+    }
+
+    #[test]
+    fn parse_encrypted() {
+        let fixture = Fixture {
+            flags: ENCRYPTED_DATABASE_FLAG,
+            ..Fixture::current()
+        };
+        let header = parse_header(&fixture.bytes()).unwrap();
+        assert!(header.is_encrypted());
+        assert!(header.summary().ends_with(", encrypted"));
+    }
+}
+
@@ -0,0 +1,302 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+use std::fs;
+use std::path::Path;
+use std::process;
+
+pub const MEMORY_DB_PATH: &str = ":memory:";
+pub const ACCESS_MODE_SETTING_NAME: &str = "access_mode";
+pub const DB_FILE_EXTENSION: &str = "duckdb";
+pub const DEFAULT_BLOCK_SIZE_SETTING_NAME: &str = "default_block_size";
+
+const MOTHERDUCK_PREFIXES: [&str; 2] = ["md:", "motherduck:"];
+const READ_ONLY_PREFIXES: [&str; 9] = ["s3://", "s3a://", "gcs://", "gs://", "r2://", "az://", "azure://", "http://", "https://"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DbPathKind {
+    Memory,
+    NamedMemory(String),
+    LocalFile,
+    UncPath,
+    MotherDuck,
+    RemoteReadOnly,
+    Invalid,
+}
+
+impl DbPathKind {
+    pub fn label(&self) -> String {
+        match self {
+            DbPathKind::Memory => "in-memory database".to_string(),
+            DbPathKind::NamedMemory(name) => format!("named in-memory database '{}'", name),
+            DbPathKind::LocalFile => "local file".to_string(),
+            DbPathKind::UncPath => "network share (UNC) file".to_string(),
+            DbPathKind::MotherDuck => "MotherDuck database".to_string(),
+            DbPathKind::RemoteReadOnly => "remote read-only database".to_string(),
+            DbPathKind::Invalid => "invalid path".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DbPathCheck {
+    pub path: String,
+    pub kind: DbPathKind,
+    pub problems: Vec<String>,
+    pub warnings: Vec<String>,
+    pub absolute_path: Option<String>,
+}
+
+impl DbPathCheck {
+    pub fn relative_warning(&self) -> Option<String> {
+        self.absolute_path.as_ref().map(|abs| format!(
+            "Database path '{}' is relative, ODBC applications run with arbitrary working directories \
+            and will resolve it differently, from the current directory it resolves to: '{}'", self.path, abs))
+    }
+}
+
+pub fn is_read_only_access_mode(access_mode: &str) -> bool {
+    "READ_ONLY".eq_ignore_ascii_case(access_mode.trim().trim_matches('\''))
+}
+
+pub fn check_database_path(path: &str, read_only: bool) -> DbPathCheck {
+    let trimmed = path.trim();
+    let mut res = DbPathCheck {
+        path: trimmed.to_string(),
+        kind: DbPathKind::Invalid,
+        problems: vec!(),
+        warnings: vec!(),
+        absolute_path: None,
+    };
+    if trimmed.is_empty() {
+        res.problems.push("Database path is empty".to_string());
+        return res;
+    }
+    let lower = trimmed.to_lowercase();
+    if MEMORY_DB_PATH == lower {
+        res.kind = DbPathKind::Memory;
+        return res;
+    }
+    if lower.starts_with(MEMORY_DB_PATH) {
+        res.kind = DbPathKind::NamedMemory(trimmed[MEMORY_DB_PATH.len()..].to_string());
+        return res;
+    }
+    if MOTHERDUCK_PREFIXES.iter().any(|p| lower.starts_with(p)) {
+        res.kind = DbPathKind::MotherDuck;
+        if MOTHERDUCK_PREFIXES.iter().any(|p| *p == lower) {
+            res.warnings.push("No MotherDuck database name is specified, the default database will be used".to_string());
+        }
+        return res;
+    }
+    if READ_ONLY_PREFIXES.iter().any(|p| lower.starts_with(p)) {
+        res.kind = DbPathKind::RemoteReadOnly;
+        if !read_only {
+            res.warnings.push(format!(
+                "Remote databases can only be attached read-only, '{}' needs to be set to READ_ONLY",
+                ACCESS_MODE_SETTING_NAME));
+        }
+        return res;
+    }
+    if lower.starts_with(':') {
+        res.problems.push(format!(
+            "Unrecognised special database path: '{}', expected '{}' or '{}name'", trimmed, MEMORY_DB_PATH, MEMORY_DB_PATH));
+        return res;
+    }
+    if lower.contains("://") {
+        res.problems.push(format!("Unsupported URI scheme in database path: '{}'", trimmed));
+        return res;
+    }
+    let expanded = paths::expand_env_vars(trimmed);
+    let is_unc = (expanded.starts_with("\\\\") || expanded.starts_with("//")) && !expanded.starts_with("\\\\?\\");
+    res.kind = if is_unc {
+        res.warnings.push(
+            "Database is located on a network share, file locking there is not reliable for concurrent access".to_string());
+        DbPathKind::UncPath
+    } else {
+        DbPathKind::LocalFile
+    };
+    if paths::is_relative_path(&expanded) {
+        res.absolute_path = Some(paths::absolute_path(&expanded));
+    }
+    check_local_file(&expanded, read_only, &mut res);
+    res
+}
+
+fn check_local_file(expanded: &str, read_only: bool, res: &mut DbPathCheck) {
+    let fpath = Path::new(expanded);
+    if fpath.is_dir() {
+        res.problems.push(format!("Database path is a directory: '{}'", expanded));
+        return;
+    }
+    let dir = match fpath.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => Path::new(".").to_path_buf()
+    };
+    if !dir.is_dir() {
+        res.problems.push(format!("Database directory does not exist: '{}'", dir.to_string_lossy()));
+        return;
+    }
+    if fpath.exists() {
+        if let Ok(meta) = fs::metadata(fpath) {
+            if !read_only && meta.permissions().readonly() {
+                res.problems.push(format!(
+                    "Database file is read-only, '{}' needs to be set to READ_ONLY: '{}'", ACCESS_MODE_SETTING_NAME, expanded));
+            }
+        }
+    } else if read_only {
+        res.problems.push(format!("Database file does not exist and cannot be created in READ_ONLY mode: '{}'", expanded));
+        return;
+    } else {
+        res.warnings.push(format!("Database file does not exist, it will be created on first connect: '{}'", expanded));
+    }
+    // DuckDB writes the WAL file next to the database, so the directory
+    // itself needs to be writable, not only the database file.
+    if !read_only && !is_dir_writable(&dir) {
+        res.problems.push(format!("Database directory is not writable: '{}'", dir.to_string_lossy()));
+    }
+}
+
+fn is_dir_writable(dir: &Path) -> bool {
+    let probe = dir.join(format!(".duckdb_odbc_config_{}.tmp", process::id()));
+    match fs::OpenOptions::new().write(true).create_new(true).open(&probe) {
+        Ok(_) => {
+            let _ = fs::remove_file(&probe);
+            true
+        },
+        Err(_) => false
+    }
+}
+
+pub fn with_db_extension(path: &str) -> String {
+    let trimmed = path.trim();
+    match Path::new(trimmed).extension() {
+        Some(_) => trimmed.to_string(),
+        None => format!("{}.{}", trimmed, DB_FILE_EXTENSION)
+    }
+}
+
+// The file is created by the selected driver itself, so it gets the storage
+// format that the driver library writes for the specified settings. It is
+// created next to the target first, so an existing database and its WAL
+// are only replaced after the creation succeeded.
+pub fn create_database_file(driver: &DuckDbDriver, path: &str, storage_version: &str, block_size: &str) -> Result<(), ConfigError> {
+    odbc::check_bitness(&driver.bitness, &format!("Driver: '{}'", driver.name),
+        "choose a driver of the same bitness to create the database file")?;
+    let tmp_path = format!("{}.{}.tmp", path, process::id());
+    remove_db_files(&tmp_path);
+    let res = attach_new_database(driver, &tmp_path, storage_version, block_size)
+        .and_then(|_| replace_db_files(&tmp_path, path));
+    if res.is_err() {
+        remove_db_files(&tmp_path);
+    }
+    res
+}
+
+fn attach_new_database(driver: &DuckDbDriver, path: &str, storage_version: &str, block_size: &str) -> Result<(), ConfigError> {
+    let conn_str = format!("Driver={{{}}};{}={};", driver.name, registry::DATABASE_SETTING_NAME, MEMORY_DB_PATH);
+    let conn = odbc::OdbcConnection::connect(&conn_str)?;
+    if !storage_version.is_empty() {
+        conn.query(&format!("SET {} = {}", db_header::STORAGE_COMPATIBILITY_SETTING_NAME,
+            init_sql::quote_string(storage_version)))?;
+    }
+    if !block_size.is_empty() {
+        conn.query(&format!("SET {} = {}", DEFAULT_BLOCK_SIZE_SETTING_NAME, block_size))?;
+    }
+    conn.query(&format!("ATTACH {} AS new_database", init_sql::quote_string(path)))?;
+    conn.query("CHECKPOINT new_database")?;
+    conn.query("DETACH new_database")?;
+    Ok(())
+}
+
+// WAL of the replaced database must not be replayed on the new one, it is
+// moved aside first and is moved back if the database cannot be replaced.
+fn replace_db_files(src: &str, dest: &str) -> Result<(), ConfigError> {
+    let wal_path = format!("{}{}", dest, db_header::WAL_FILE_SUFFIX);
+    let wal_backup = format!("{}.{}.bak", wal_path, process::id());
+    let wal_moved = Path::new(&wal_path).exists();
+    if wal_moved {
+        fs::rename(&wal_path, &wal_backup).map_err(|e| ConfigError::from_string(format!(
+            "Cannot move existing WAL file: '{}', message: {}", wal_path, e)))?;
+    }
+    if let Err(e) = fs::rename(src, dest) {
+        if wal_moved {
+            let _ = fs::rename(&wal_backup, &wal_path);
+        }
+        return Err(ConfigError::from_string(format!(
+            "Cannot replace database file: '{}', message: {}", dest, e)));
+    }
+    if wal_moved {
+        let _ = fs::remove_file(&wal_backup);
+    }
+    Ok(())
+}
+
+fn remove_db_files(path: &str) {
+    let _ = fs::remove_file(path);
+    let _ = fs::remove_file(format!("{}{}", path, db_header::WAL_FILE_SUFFIX));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_kinds() {
+        assert_eq!(DbPathKind::Memory, check_database_path(" :memory: ", false).kind);
+        assert_eq!(DbPathKind::NamedMemory("shared".to_string()), check_database_path(":memory:shared", false).kind);
+        assert_eq!(DbPathKind::MotherDuck, check_database_path("md:my_db", false).kind);
+        assert_eq!(DbPathKind::LocalFile, check_database_path("Q:\\test.duckdb", false).kind);
+        assert_eq!(DbPathKind::UncPath, check_database_path("\\\\server\\share\\test.duckdb", false).kind);
+        assert_eq!(DbPathKind::RemoteReadOnly, check_database_path("HTTPS://example.com/test.duckdb", true).kind);
+    }
+
+    #[test]
+    fn path_problems() {
+        assert_eq!(vec!("Database path is empty"), check_database_path("", false).problems);
+        assert!(check_database_path(":temp:", false).problems[0].starts_with("Unrecognised special database path"));
+        assert!(check_database_path("ftp://example.com/test.duckdb", false).problems[0].starts_with("Unsupported URI scheme"));
+        assert_eq!(vec!("Database directory does not exist: 'Q:/missing'"),
+            check_database_path("Q:/missing/test.duckdb", false).problems);
+        assert!(check_database_path("md:", false).warnings[0].starts_with("No MotherDuck database name"));
+        let remote = check_database_path("s3://bucket/test.duckdb", false);
+        assert!(remote.problems.is_empty());
+        assert_eq!(1, remote.warnings.len());
+        assert!(check_database_path("s3://bucket/test.duckdb", true).warnings.is_empty());
+    }
+
+    #[test]
+    fn relative_path() {
+        let check = check_database_path("test.duckdb", false);
+        assert!(check.relative_warning().is_some());
+        assert!(check_database_path("Q:\\test.duckdb", false).relative_warning().is_none());
+    }
+
+    #[test]
+    fn access_mode() {
+        assert!(is_read_only_access_mode("READ_ONLY"));
+        assert!(is_read_only_access_mode(" 'read_only' "));
+        assert!(!is_read_only_access_mode("READ_WRITE"));
+        assert!(!is_read_only_access_mode(""));
+    }
+
+    #[test]
+    fn db_extension() {
+        assert_eq!("test.duckdb", with_db_extension(" test "));
+        assert_eq!("Q:\\data\\test.db", with_db_extension("Q:\\data\\test.db"));
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffKind {
+    Differs,
+    OnlyLeft,
+    OnlyRight,
+    Equal,
+}
+
+impl DiffKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiffKind::Differs => "differs",
+            DiffKind::OnlyLeft => "only left",
+            DiffKind::OnlyRight => "only right",
+            DiffKind::Equal => "equal",
+        }
+    }
+
+    fn order(&self) -> u8 {
+        match self {
+            DiffKind::Differs => 0,
+            DiffKind::OnlyLeft => 1,
+            DiffKind::OnlyRight => 2,
+            DiffKind::Equal => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DiffRow {
+    pub name: String,
+    pub left: Option<RegistrySetting>,
+    pub right: Option<RegistrySetting>,
+    pub default_value: Option<String>,
+    pub kind: DiffKind,
+}
+
+impl DiffRow {
+    pub fn left_value(&self) -> &str {
+        self.left.as_ref().map(|s| s.value.as_str()).unwrap_or("")
+    }
+
+    pub fn right_value(&self) -> &str {
+        self.right.as_ref().map(|s| s.value.as_str()).unwrap_or("")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DsnDiff {
+    pub left: String,
+    pub right: String,
+    pub rows: Vec<DiffRow>,
+}
+
+impl DsnDiff {
+    pub fn count(&self, kind: DiffKind) -> usize {
+        self.rows.iter().filter(|r| r.kind == kind).count()
+    }
+
+    pub fn summary(&self) -> String {
+        format!("Left: '{}', right: '{}', differ: {}, only left: {}, only right: {}, equal: {}",
+            self.left, self.right, self.count(DiffKind::Differs), self.count(DiffKind::OnlyLeft),
+            self.count(DiffKind::OnlyRight), self.count(DiffKind::Equal))
+    }
+
+    pub fn render_text(&self, include_equal: bool) -> String {
+        let mut lines = vec!(self.summary(), "".to_string());
+        for row in self.rows.iter().filter(|r| include_equal || DiffKind::Equal != r.kind) {
+            let default = match &row.default_value {
+                Some(dv) => format!(" (default: {})", dv),
+                None => "".to_string()
+            };
+            lines.push(format!("[{}] {}{}", row.kind.label(), row.name, default));
+            if row.left.is_some() {
+                lines.push(format!("  < {}", row.left_value()));
+            }
+            if row.right.is_some() {
+                lines.push(format!("  > {}", row.right_value()));
+            }
+        }
+        lines.join("\r\n")
+    }
+
+    pub fn render_json(&self, include_equal: bool) -> String {
+        let rows: Vec<String> = self.rows.iter()
+            .filter(|r| include_equal || DiffKind::Equal != r.kind)
+            .map(|r| format!("    {{\"name\": {}, \"kind\": {}, \"left\": {}, \"right\": {}, \"default\": {}}}",
+                json_string(&r.name), json_string(r.kind.label()),
+                json_opt(r.left.as_ref().map(|s| s.value.as_str())),
+                json_opt(r.right.as_ref().map(|s| s.value.as_str())),
+                json_opt(r.default_value.as_deref())))
+            .collect();
+        format!("{{\n  \"left\": {},\n  \"right\": {},\n  \"settings\": [\n{}\n  ]\n}}\n",
+            json_string(&self.left), json_string(&self.right), rows.join(",\n"))
+    }
+}
+
+pub fn diff_dsns(left: &RegistryDsn, right: &RegistryDsn, catalog: &[DuckDbSetting]) -> DsnDiff {
+    let mut names: Vec<&str> = left.settings.iter()
+        .chain(right.settings.iter())
+        .map(|s| s.name.as_str())
+        .collect();
+    names.sort_by_key(|n| n.to_lowercase());
+    names.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    let mut rows: Vec<DiffRow> = names.into_iter()
+        .map(|name| {
+            let left_st = left.settings.iter().find(|s| s.name.eq_ignore_ascii_case(name)).cloned();
+            let right_st = right.settings.iter().find(|s| s.name.eq_ignore_ascii_case(name)).cloned();
+            let kind = match (&left_st, &right_st) {
+                (Some(l), Some(r)) if l.value.trim() == r.value.trim() => DiffKind::Equal,
+                (Some(_), Some(_)) => DiffKind::Differs,
+                (Some(_), None) => DiffKind::OnlyLeft,
+                _ => DiffKind::OnlyRight,
+            };
+            let default_value = catalog.iter()
+                .find(|s| s.name.eq_ignore_ascii_case(name) && !s.input_type.is_empty())
+                .map(|s| s.default_value.clone());
+            DiffRow {
+                name: name.to_string(),
+                left: left_st,
+                right: right_st,
+                default_value,
+                kind,
+            }
+        })
+        .collect();
+    rows.sort_by_key(|r| r.kind.order());
+    DsnDiff {
+        left: left.display_name(),
+        right: right.display_name(),
+        rows,
+    }
+}
+
+pub fn json_string(value: &str) -> String {
+    let mut res = String::with_capacity(value.len() + 2);
+    res.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c)
+        }
+    }
+    res.push('"');
+    res
+}
+
+fn json_opt(value: Option<&str>) -> String {
+    match value {
+        Some(v) => json_string(v),
+        None => "null".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_rows() {
+        let left = RegistryDsn::with_settings("left",
+            &[("threads", "4"), ("Memory_Limit", "1GB"), ("access_mode", "READ_ONLY")]);
+        let right = RegistryDsn::with_settings("right",
+            &[("threads", " 4 "), ("memory_limit", "2GB"), ("temp_directory", "Q:\\tmp")]);
+        let catalog = vec!(DuckDbSetting::new("memory_limit", "80% of RAM", "VARCHAR", "GLOBAL", ""));
+        let diff = diff_dsns(&left, &right, &catalog);
+        let rows: Vec<(&str, DiffKind)> = diff.rows.iter().map(|r| (r.name.as_str(), r.kind.clone())).collect();
+        assert_eq!(vec!(("Memory_Limit", DiffKind::Differs), ("access_mode", DiffKind::OnlyLeft),
+            ("temp_directory", DiffKind::OnlyRight), ("threads", DiffKind::Equal)), rows);
+        assert_eq!(Some("80% of RAM".to_string()), diff.rows[0].default_value);
+        assert_eq!("2GB", diff.rows[0].right_value());
+        assert_eq!("", diff.rows[1].right_value());
+        assert_eq!("Left: 'left', right: 'right', differ: 1, only left: 1, only right: 1, equal: 1", diff.summary());
+        assert!(!diff.render_text(false).contains("threads"));
+        assert!(diff.render_text(true).contains("[equal] threads"));
+        assert!(diff.render_json(false).contains(
+            "{\"name\": \"access_mode\", \"kind\": \"only left\", \"left\": \"READ_ONLY\", \"right\": null, \"default\": null}"));
+    }
+
+    #[test]
+    fn json_strings() {
+        assert_eq!("\"plain\"", json_string("plain"));
+        assert_eq!("\"Q:\\\\a \\\"b\\\"\\r\\n\\u0001\"", json_string("Q:\\a \"b\"\r\n\u{1}"));
+    }
+}
@@ -0,0 +1,189 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+use registry::ExistingDsn;
+
+// SQL_MAX_DSN_LENGTH from sqlext.h
+pub const MAX_DSN_NAME_LENGTH: usize = 32;
+pub const INVALID_DSN_NAME_CHARS: [char; 14] = ['[', ']', '{', '}', '(', ')', ',', ';', '?', '*', '=', '!', '@', '\\'];
+
+#[derive(Default, Debug, Clone)]
+pub struct DsnNameCheck {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    pub suggestion: Option<String>,
+}
+
+impl DsnNameCheck {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn message(&self) -> String {
+        let mut res = match self.errors.first() {
+            Some(err) => err.clone(),
+            None => self.warnings.first().cloned().unwrap_or_default()
+        };
+        if let Some(suggestion) = &self.suggestion {
+            res.push_str(&format!(", suggested name: '{}'", suggestion));
+        }
+        res
+    }
+}
+
+pub fn check_dsn_name(name: &str, dsn_type: &DsnType, bitness: &Bitness, existing: &[ExistingDsn]) -> DsnNameCheck {
+    let mut res = DsnNameCheck::default();
+    if name.is_empty() {
+        res.errors.push("Name is required".to_string());
+        return res;
+    }
+    if name.trim() != name {
+        res.errors.push("Name must not start or end with spaces".to_string());
+    }
+    let invalid: String = name.chars()
+        .filter(|c| INVALID_DSN_NAME_CHARS.contains(c) || c.is_control())
+        .collect();
+    if !invalid.is_empty() {
+        res.errors.push(format!("Name contains characters not allowed by ODBC: {}", invalid));
+    }
+    if name.chars().count() > MAX_DSN_NAME_LENGTH {
+        res.errors.push(format!("Name is longer than {} characters", MAX_DSN_NAME_LENGTH));
+    }
+    for dsn in existing.iter().filter(|d| d.name.eq_ignore_ascii_case(name)) {
+        let desc = existing_description(dsn);
+        if is_same_scope(dsn, dsn_type, bitness) {
+            res.errors.push(format!("Name conflicts with {}", desc));
+        } else if dsn.dsn_type.label() == dsn_type.label() {
+            res.warnings.push(format!("Name is also used by {}", desc));
+        } else if let DsnType::USER = dsn_type {
+            res.warnings.push(format!("Name shadows {}, applications will use the new User DSN", desc));
+        } else {
+            res.warnings.push(format!("Name is shadowed by {}, applications will keep using it", desc));
+        }
+    }
+    if !res.errors.is_empty() {
+        res.suggestion = suggest_name(name, dsn_type, bitness, existing);
+    }
+    res
+}
+
+pub fn suggest_name(name: &str, dsn_type: &DsnType, bitness: &Bitness, existing: &[ExistingDsn]) -> Option<String> {
+    let sanitized: String = name.trim().chars()
+        .map(|c| if INVALID_DSN_NAME_CHARS.contains(&c) || c.is_control() { '_' } else { c })
+        .take(MAX_DSN_NAME_LENGTH)
+        .collect();
+    let base = sanitized.trim().to_string();
+    if base.is_empty() {
+        return None;
+    }
+    let taken = |candidate: &str| existing.iter()
+        .any(|d| d.name.eq_ignore_ascii_case(candidate) && is_same_scope(d, dsn_type, bitness));
+    if !taken(&base) {
+        return Some(base);
+    }
+    for idx in 2..100 {
+        let suffix = format!("_{}", idx);
+        let prefix: String = base.chars().take(MAX_DSN_NAME_LENGTH - suffix.len()).collect();
+        let candidate = format!("{}{}", prefix, suffix);
+        if !taken(&candidate) {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+// System DSNs are stored separately in 32-bit and 64-bit registry views,
+// User DSNs are shared between them.
+fn is_same_scope(dsn: &ExistingDsn, dsn_type: &DsnType, bitness: &Bitness) -> bool {
+    match (&dsn.dsn_type, dsn_type) {
+        (DsnType::USER, DsnType::USER) => true,
+        (DsnType::SYSTEM, DsnType::SYSTEM) => dsn.view.as_ref() == Some(bitness),
+        _ => false
+    }
+}
+
+fn existing_description(dsn: &ExistingDsn) -> String {
+    let view = match &dsn.view {
+        Some(bitness) => format!(" {}", bitness.label()),
+        None => "".to_string()
+    };
+    let driver = if dsn.driver.is_empty() {
+        "".to_string()
+    } else {
+        format!(", driver: '{}'", dsn.driver)
+    };
+    format!("existing {}{} DSN '{}'{}", dsn.dsn_type.label(), view, dsn.name, driver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing(name: &str, dsn_type: DsnType, view: Option<Bitness>) -> ExistingDsn {
+        ExistingDsn {
+            name: name.to_string(),
+            dsn_type,
+            view,
+            driver: "DuckDB Driver".to_string(),
+        }
+    }
+
+    #[test]
+    fn invalid_names() {
+        assert_eq!("Name is required", check_dsn_name("", &DsnType::USER, &Bitness::X64, &[]).message());
+        assert!(check_dsn_name("DuckDB", &DsnType::USER, &Bitness::X64, &[]).is_valid());
+        let check = check_dsn_name(" my{dsn}", &DsnType::USER, &Bitness::X64, &[]);
+        assert_eq!(2, check.errors.len());
+        assert_eq!("Name contains characters not allowed by ODBC: {}", check.errors[1]);
+        assert_eq!(Some("my_dsn_".to_string()), check.suggestion);
+        let long = "a".repeat(MAX_DSN_NAME_LENGTH + 1);
+        let check = check_dsn_name(&long, &DsnType::USER, &Bitness::X64, &[]);
+        assert!(!check.is_valid());
+        assert_eq!(Some("a".repeat(MAX_DSN_NAME_LENGTH)), check.suggestion);
+    }
+
+    #[test]
+    fn name_scopes() {
+        let dsns = vec!(
+            existing("DuckDB", DsnType::USER, None),
+            existing("Sys", DsnType::SYSTEM, Some(Bitness::X86)),
+        );
+        let check = check_dsn_name("duckdb", &DsnType::USER, &Bitness::X86, &dsns);
+        assert!(!check.is_valid());
+        assert!(check.message().ends_with(", suggested name: 'duckdb_2'"));
+        let check = check_dsn_name("DuckDB", &DsnType::SYSTEM, &Bitness::X64, &dsns);
+        assert!(check.is_valid());
+        assert!(check.message().starts_with("Name is shadowed by existing User DSN 'DuckDB'"));
+        assert!(!check_dsn_name("Sys", &DsnType::SYSTEM, &Bitness::X86, &dsns).is_valid());
+        let check = check_dsn_name("Sys", &DsnType::SYSTEM, &Bitness::X64, &dsns);
+        assert!(check.is_valid());
+        assert!(check.message().starts_with("Name is also used by existing System"));
+        let check = check_dsn_name("Sys", &DsnType::USER, &Bitness::X64, &dsns);
+        assert!(check.message().starts_with("Name shadows existing System"));
+    }
+
+    #[test]
+    fn suggestions() {
+        let dsns = vec!(
+            existing("test", DsnType::USER, None),
+            existing("test_2", DsnType::USER, None),
+        );
+        assert_eq!(Some("Test_3".to_string()), suggest_name("Test", &DsnType::USER, &Bitness::X64, &dsns));
+        assert_eq!(Some("Test".to_string()), suggest_name("Test", &DsnType::SYSTEM, &Bitness::X64, &dsns));
+        assert_eq!(Some("__".to_string()), suggest_name(" [] ", &DsnType::USER, &Bitness::X64, &dsns));
+        assert_eq!(None, suggest_name("  ", &DsnType::USER, &Bitness::X64, &dsns));
+    }
+}
@@ -0,0 +1,158 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use regex::Regex;
+use regex::RegexBuilder;
+use wildmatch::WildMatch;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchMode {
+    Wildcard,
+    Regex,
+}
+
+impl SearchMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchMode::Wildcard => "Wildcard",
+            SearchMode::Regex => "Regular expression",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub dsn: RegistryDsn,
+    pub setting: RegistrySetting,
+}
+
+impl SearchHit {
+    pub fn report_row(&self) -> Vec<String> {
+        vec!(self.dsn.display_name(), self.dsn.dsn_type.label().to_string(),
+            self.setting.name.clone(), self.setting.value.clone())
+    }
+}
+
+enum Matcher {
+    Any,
+    Wildcard(WildMatch),
+    Regex(Regex),
+}
+
+impl Matcher {
+    // Wildcard patterns without '*' or '?' match as a substring, all matching
+    // is case-insensitive as both registry names and Windows paths are.
+    fn new(pattern: &str, mode: &SearchMode) -> Result<Self, ConfigError> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Ok(Matcher::Any);
+        }
+        match mode {
+            SearchMode::Wildcard => {
+                let lower = pattern.to_lowercase();
+                let wm = if lower.contains(['*', '?']) {
+                    WildMatch::new(&lower)
+                } else {
+                    WildMatch::new(&format!("*{}*", lower))
+                };
+                Ok(Matcher::Wildcard(wm))
+            },
+            SearchMode::Regex => match RegexBuilder::new(pattern).case_insensitive(true).build() {
+                Ok(re) => Ok(Matcher::Regex(re)),
+                Err(e) => Err(ConfigError::from_string(format!(
+                    "Invalid regular expression: '{}', message: {}", pattern, e)))
+            }
+        }
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Matcher::Any => true,
+            Matcher::Wildcard(wm) => wm.matches(&text.to_lowercase()),
+            Matcher::Regex(re) => re.is_match(text),
+        }
+    }
+}
+
+// Values are matched both as stored and with environment variables expanded,
+// so a search for a directory finds values that reference it through %VAR%.
+pub fn search_dsns(dsns: &[RegistryDsn], name_pattern: &str, value_pattern: &str,
+                   mode: &SearchMode) -> Result<Vec<SearchHit>, ConfigError> {
+    let name_matcher = Matcher::new(name_pattern, mode)?;
+    let value_matcher = Matcher::new(value_pattern, mode)?;
+    let mut res = vec!();
+    for dsn in dsns {
+        for st in &dsn.settings {
+            if !name_matcher.matches(&st.name) {
+                continue;
+            }
+            let value_matches = value_matcher.matches(&st.value) ||
+                (st.value.contains('%') && value_matcher.matches(&paths::expand_env_vars(&st.value)));
+            if value_matches {
+                res.push(SearchHit {
+                    dsn: dsn.clone(),
+                    setting: st.clone(),
+                });
+            }
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn hits(dsns: &[RegistryDsn], name_pattern: &str, value_pattern: &str, mode: &SearchMode) -> Vec<String> {
+        search_dsns(dsns, name_pattern, value_pattern, mode).unwrap().iter()
+            .map(|h| format!("{}.{}", h.dsn.name, h.setting.name))
+            .collect()
+    }
+
+    #[test]
+    fn wildcard_search() {
+        let dsns = vec!(
+            RegistryDsn::with_settings("a", &[("database", "Q:\\Data\\a.duckdb"), ("threads", "4")]),
+            RegistryDsn::with_settings("b", &[("database", "Q:\\Other\\b.duckdb"), ("temp_directory", "Q:\\Data\\tmp")]),
+        );
+        let mode = SearchMode::Wildcard;
+        assert_eq!(4, hits(&dsns, "", "", &mode).len());
+        assert_eq!(vec!("a.database", "b.temp_directory"), hits(&dsns, "", "\\data\\", &mode));
+        assert_eq!(vec!("a.database", "b.database"), hits(&dsns, "DATA*", "*.duckdb", &mode));
+        assert_eq!(vec!("a.threads"), hits(&dsns, "threads", "?", &mode));
+    }
+
+    #[test]
+    fn env_var_search() {
+        env::set_var("DSN_SEARCH_TEST_DIR", "Q:\\Data");
+        let dsns = vec!(RegistryDsn::with_settings("a", &[("database", "%DSN_SEARCH_TEST_DIR%\\a.duckdb")]));
+        assert_eq!(vec!("a.database"), hits(&dsns, "", "q:\\data", &SearchMode::Wildcard));
+        assert_eq!(vec!("a.database"), hits(&dsns, "", "%dsn_search", &SearchMode::Wildcard));
+    }
+
+    #[test]
+    fn regex_search() {
+        let dsns = vec!(RegistryDsn::with_settings("a", &[("threads", "4"), ("worker_threads", "16")]));
+        let mode = SearchMode::Regex;
+        assert_eq!(vec!("a.worker_threads"), hits(&dsns, "^WORKER", "", &mode));
+        assert_eq!(vec!("a.threads"), hits(&dsns, "threads$", "^\\d$", &mode));
+        let err = search_dsns(&dsns, "(", "", &mode).err().unwrap();
+        assert!(err.to_string().starts_with("Invalid regular expression: '('"));
+    }
+}
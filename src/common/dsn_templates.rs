@@ -0,0 +1,38 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct DsnTemplate {
+    pub name: String,
+    pub settings: Vec<RegistrySetting>,
+}
+
+// Bundled presets are the single source of the template values.
+pub fn builtin_templates() -> Vec<DsnTemplate> {
+    presets::bundled_presets().into_iter()
+        .map(|pr| DsnTemplate {
+            name: pr.name,
+            settings: pr.settings.into_iter()
+                .map(|(name, value)| RegistrySetting {
+                    name,
+                    value,
+                    value_type: RegistryValueType::SZ,
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -14,14 +14,20 @@
  * limitations under the License.
  */
 
+use super::*;
+
 #[derive(Default, Debug, Clone)]
 pub struct DuckDbSetting {
     pub name: String,
     pub dsn_value: String,
+    pub dsn_value_type: Option<RegistryValueType>,
     pub default_value: String,
     pub description: String,
     pub input_type: String,
     pub scope: String,
+    pub effective_value: Option<String>,
+    pub live_only: bool,
+    pub risk_level: Option<security::RiskLevel>,
 }
 
 impl DuckDbSetting {
@@ -29,10 +35,132 @@ impl DuckDbSetting {
         Self {
             name: name.to_string(),
             dsn_value: "".to_string(),
+            dsn_value_type: None,
             default_value: value.to_string(),
             description: description.to_string(),
             input_type: input_type.to_string(),
             scope: scope.to_string(),
+            effective_value: None,
+            live_only: false,
+            risk_level: security::risk_level(name),
+        }
+    }
+
+    pub fn effective_differs(&self) -> bool {
+        match &self.effective_value {
+            Some(effective) => !self.dsn_value.is_empty() && !values_match(&self.dsn_value, effective),
+            None => false
         }
     }
-}
\ No newline at end of file
+}
+
+pub(super) fn values_match(dsn_value: &str, effective: &str) -> bool {
+    let dv = unquote(dsn_value.trim());
+    let ev = unquote(effective.trim());
+    if dv.eq_ignore_ascii_case(ev) {
+        return true;
+    }
+    if let (Some(db), Some(eb)) = (parse_bool(dv), parse_bool(ev)) {
+        return db == eb;
+    }
+    // engine reports sizes rounded to one decimal place, like "3.7 GiB" for "4GB"
+    if let (Some(dbytes), Some(ebytes)) = (parse_bytes(dv), parse_bytes(ev)) {
+        let max = dbytes.max(ebytes);
+        return 0.0 == max || (dbytes - ebytes).abs() / max < 0.05;
+    }
+    if let (Ok(dnum), Ok(enum_)) = (dv.parse::<f64>(), ev.parse::<f64>()) {
+        return dnum == enum_;
+    }
+    false
+}
+
+pub(super) fn unquote(value: &str) -> &str {
+    if value.len() >= 2 && ((value.starts_with('\'') && value.ends_with('\'')) ||
+        (value.starts_with('"') && value.ends_with('"'))) {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+pub(super) fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "1" | "on" | "yes" | "t" => Some(true),
+        "false" | "0" | "off" | "no" | "f" => Some(false),
+        _ => None
+    }
+}
+
+pub(super) fn parse_bytes(value: &str) -> Option<f64> {
+    let split_idx = value.find(|c: char| !(c.is_ascii_digit() || '.' == c))?;
+    let num: f64 = value[..split_idx].trim().parse().ok()?;
+    let multiplier = match value[split_idx..].trim().to_lowercase().as_str() {
+        "b" | "byte" | "bytes" => 1f64,
+        "kb" | "kilobyte" | "kilobytes" => 1e3,
+        "mb" | "megabyte" | "megabytes" => 1e6,
+        "gb" | "gigabyte" | "gigabytes" => 1e9,
+        "tb" | "terabyte" | "terabytes" => 1e12,
+        "kib" => 1024f64,
+        "mib" => 1024f64.powi(2),
+        "gib" => 1024f64.powi(3),
+        "tib" => 1024f64.powi(4),
+        _ => return None
+    };
+    Some(num * multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(Some(4e9), parse_bytes("4GB"));
+        assert_eq!(Some(4e9), parse_bytes("4 gb"));
+        assert_eq!(Some(1.5e3), parse_bytes("1.5KB"));
+        assert_eq!(Some(2048f64), parse_bytes("2 KiB"));
+        assert_eq!(Some(3.7 * 1024f64.powi(3)), parse_bytes("3.7 GiB"));
+        assert_eq!(Some(10f64), parse_bytes("10 bytes"));
+        assert_eq!(None, parse_bytes("1024"));
+        assert_eq!(None, parse_bytes("GB"));
+        assert_eq!(None, parse_bytes("80%"));
+        assert_eq!(None, parse_bytes("4 XB"));
+    }
+
+    #[test]
+    fn values() {
+        assert!(values_match("'4GB'", "3.7 GiB"));
+        assert!(values_match("1GiB", "1.0 GiB"));
+        assert!(!values_match("4GB", "2.0 GiB"));
+        assert!(values_match("80% of RAM", "80% of ram"));
+        assert!(!values_match("80%", "90%"));
+        assert!(values_match("'AUTOMATIC'", "automatic"));
+        assert!(values_match("\"Q:\\Data\"", "q:\\data"));
+        assert!(values_match("on", "true"));
+        assert!(values_match("'0'", "false"));
+        assert!(!values_match("yes", "false"));
+        assert!(values_match(" 4 ", "4.0"));
+        assert!(!values_match("4", "8"));
+        assert!(!values_match("'a'", "'b'"));
+    }
+
+    #[test]
+    fn unquote_values() {
+        assert_eq!("a", unquote("'a'"));
+        assert_eq!("a", unquote("\"a\""));
+        assert_eq!("'a\"", unquote("'a\""));
+        assert_eq!("'", unquote("'"));
+        assert_eq!("", unquote("''"));
+    }
+
+    #[test]
+    fn effective_value() {
+        let mut setting = DuckDbSetting::new("memory_limit", "80% of RAM", "VARCHAR", "GLOBAL", "");
+        setting.effective_value = Some("3.7 GiB".to_string());
+        assert!(!setting.effective_differs());
+        setting.dsn_value = "4GB".to_string();
+        assert!(!setting.effective_differs());
+        setting.dsn_value = "8GB".to_string();
+        assert!(setting.effective_differs());
+    }
+}
@@ -0,0 +1,548 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+use std::fs;
+use std::path::Path;
+
+use list_value::ListFormat;
+
+const KNOWN_EXTENSION_REPOSITORIES: [&str; 3] = ["core", "core_nightly", "community"];
+const STARTUP_ONLY_SETTINGS: [&str; 6] = [
+    registry::DATABASE_SETTING_NAME,
+    registry::SESSION_INIT_SQL_FILE_SETTING_NAME,
+    db_path::ACCESS_MODE_SETTING_NAME,
+    db_path::DEFAULT_BLOCK_SIZE_SETTING_NAME,
+    "allow_unsigned_extensions",
+    "allow_community_extensions",
+];
+const EXTENSION_FILE_SUFFIX: &str = ".duckdb_extension";
+const REMOTE_PREFIXES: [&str; 10] = ["http://", "https://", "s3://", "s3a://", "gcs://", "gs://", "r2://", "az://", "azure://", "md:"];
+
+#[derive(Debug, Clone)]
+pub struct SqlStatement {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct InitSqlImport {
+    pub settings: Vec<(String, String)>,
+    pub untranslated: Vec<String>,
+    pub remaining_script: String,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct InitSqlInspection {
+    pub path: String,
+    pub problems: Vec<String>,
+    pub warnings: Vec<String>,
+    pub statements: Vec<(SqlStatement, Vec<String>)>,
+}
+
+impl InitSqlInspection {
+    pub fn summary(&self) -> String {
+        let risky_count = self.statements.iter()
+            .filter(|(_, warnings)| !warnings.is_empty())
+            .count();
+        format!("File: '{}', statements: {}, with warnings: {}, file problems: {}, file warnings: {}",
+            self.path, self.statements.len(), risky_count, self.problems.len(), self.warnings.len())
+    }
+
+    pub fn report_rows(&self) -> Vec<Vec<String>> {
+        let mut res = vec!();
+        for problem in &self.problems {
+            res.push(vec!("".to_string(), "(file)".to_string(), format!("Error: {}", problem)));
+        }
+        for warning in &self.warnings {
+            res.push(vec!("".to_string(), "(file)".to_string(), warning.clone()));
+        }
+        for (stmt, warnings) in &self.statements {
+            let text = stmt.text.split_whitespace().collect::<Vec<&str>>().join(" ");
+            res.push(vec!(stmt.line.to_string(), text, warnings.join("; ")));
+        }
+        res
+    }
+}
+
+pub fn inspect_file(path: &str) -> InitSqlInspection {
+    let mut res = InitSqlInspection {
+        path: path.to_string(),
+        ..Default::default()
+    };
+    let expanded = paths::expand_env_vars(path.trim());
+    if paths::is_relative_path(&expanded) {
+        res.warnings.push(format!(
+            "Path '{}' is relative, it is resolved against the current directory of each application that uses the DSN", expanded));
+    }
+    let fpath = Path::new(&expanded);
+    if !fpath.exists() {
+        res.problems.push(format!("File not found: '{}'", expanded));
+        return res;
+    }
+    if fpath.is_dir() {
+        res.problems.push(format!("Path is a directory: '{}'", expanded));
+        return res;
+    }
+    let bytes = match fs::read(fpath) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            res.problems.push(format!("File cannot be read: '{}', message: {}", expanded, e));
+            return res;
+        }
+    };
+    if bytes.starts_with(&[0xff, 0xfe]) || bytes.starts_with(&[0xfe, 0xff]) {
+        res.problems.push("File is encoded as UTF-16, the driver expects UTF-8".to_string());
+        return res;
+    }
+    let body = if bytes.starts_with(&[0xef, 0xbb, 0xbf]) {
+        res.warnings.push("File starts with a UTF-8 byte order mark that can break the first statement".to_string());
+        &bytes[3..]
+    } else {
+        &bytes[..]
+    };
+    let script = match std::str::from_utf8(body) {
+        Ok(script) => script,
+        Err(e) => {
+            res.problems.push(format!(
+                "File is not valid UTF-8 (invalid byte at offset {}), it needs to be saved as UTF-8", e.valid_up_to()));
+            return res;
+        }
+    };
+    res.statements = split_statements(script).into_iter()
+        .filter(|stmt| !stmt.text.is_empty())
+        .map(|stmt| {
+            let warnings = statement_warnings(&stmt.text);
+            (stmt, warnings)
+        })
+        .collect();
+    res
+}
+
+pub fn statement_warnings(statement: &str) -> Vec<String> {
+    let mut res = vec!();
+    let words: Vec<&str> = statement.split_whitespace().collect();
+    let lower_words: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
+    let first = lower_words.first().map(|w| w.as_str()).unwrap_or("");
+    let is_install = "install" == first || ("force" == first && lower_words.get(1).map(|w| w.as_str()) == Some("install"));
+    if is_install {
+        if let Some(idx) = lower_words.iter().position(|w| "from" == w) {
+            let repo = words.get(idx + 1).map(|w| w.trim_matches(|c| '\'' == c || '"' == c || ';' == c)).unwrap_or("");
+            if !KNOWN_EXTENSION_REPOSITORIES.iter().any(|kr| kr.eq_ignore_ascii_case(repo)) {
+                res.push(format!("INSTALL from a custom repository: '{}'", repo));
+            }
+        }
+    }
+    if let Some((name, value)) = parse_setting_statement(statement) {
+        let enabled = !["false", "0", "off", "no"].iter().any(|v| v.eq_ignore_ascii_case(&value));
+        if "allow_unsigned_extensions".eq_ignore_ascii_case(&name) && enabled {
+            res.push("Allows loading unsigned extensions".to_string());
+        }
+    }
+    let takes_path = ["attach", "load", "import", ".read"].contains(&first);
+    if takes_path {
+        let arg = if ".read" == first {
+            words.get(1).map(|w| w.to_string())
+        } else {
+            first_string_literal(statement)
+        };
+        if let Some(arg) = arg {
+            // LOAD takes either an extension name or a path to the extension file
+            let is_file = "load" != first || arg.contains(['/', '\\']) ||
+                arg.to_lowercase().ends_with(EXTENSION_FILE_SUFFIX);
+            if REMOTE_PREFIXES.iter().any(|rp| arg.to_lowercase().starts_with(rp)) {
+                if "attach" == first {
+                    res.push(format!("ATTACH to a remote URL: '{}'", arg));
+                }
+            } else if is_file && paths::is_relative_path(&arg) && ":memory:" != arg {
+                res.push(format!("Relative path '{}' is resolved differently by each application", arg));
+            }
+        }
+    }
+    res
+}
+
+// Settings applied only when the database is opened are kept
+// as comments, SET fails for them when the script is replayed.
+pub fn render_set_statements(dsn_name: &str, settings: &[DuckDbSetting]) -> String {
+    let mut res = format!("-- Settings exported from DSN: {}\r\n", dsn_name);
+    for st in settings {
+        if st.dsn_value.is_empty() {
+            continue;
+        }
+        if STARTUP_ONLY_SETTINGS.iter().any(|name| name.eq_ignore_ascii_case(&st.name)) {
+            res.push_str(&format!("-- {} = {} (can only be set when the database is opened)\r\n",
+                st.name, sql_literal(&st.dsn_value, &st.input_type)));
+            continue;
+        }
+        if st.input_type.is_empty() {
+            continue;
+        }
+        res.push_str(&format!("SET {} = {};\r\n", st.name, sql_literal(&st.dsn_value, &st.input_type)));
+    }
+    res
+}
+
+pub fn sql_literal(value: &str, input_type: &str) -> String {
+    let trimmed = value.trim();
+    match input_type.to_uppercase().as_str() {
+        "BOOLEAN" => match trimmed.to_lowercase().as_str() {
+            "true" | "1" | "on" | "yes" => "true".to_string(),
+            "false" | "0" | "off" | "no" => "false".to_string(),
+            _ => quote_string(trimmed)
+        },
+        "TINYINT" | "SMALLINT" | "INTEGER" | "BIGINT" | "HUGEINT" |
+        "UTINYINT" | "USMALLINT" | "UINTEGER" | "UBIGINT" | "UHUGEINT" => {
+            if trimmed.parse::<i128>().is_ok() || trimmed.parse::<u128>().is_ok() {
+                trimmed.to_string()
+            } else {
+                quote_string(trimmed)
+            }
+        },
+        "FLOAT" | "DOUBLE" => {
+            if trimmed.parse::<f64>().is_ok() {
+                trimmed.to_string()
+            } else {
+                quote_string(trimmed)
+            }
+        },
+        "VARCHAR[]" => match list_value::parse_list(trimmed, ListFormat::Literal) {
+            Ok(items) => list_value::render_list(&items, ListFormat::Literal),
+            Err(_) => quote_string(trimmed)
+        },
+        _ => quote_string(value)
+    }
+}
+
+pub fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+pub fn split_statements(script: &str) -> Vec<SqlStatement> {
+    let chars: Vec<(usize, char)> = script.char_indices().collect();
+    let mut res = vec!();
+    let mut start: Option<usize> = None;
+    let mut line = 1;
+    let mut start_line = 1;
+    let mut i = 0;
+    while i < chars.len() {
+        let (pos, ch) = chars[i];
+        let next = chars.get(i + 1).map(|(_, c)| *c);
+        if '-' == ch && Some('-') == next {
+            // line comment
+            while i < chars.len() && '\n' != chars[i].1 {
+                i += 1;
+            }
+            continue;
+        }
+        if '/' == ch && Some('*') == next {
+            i += 2;
+            while i < chars.len() && !('*' == chars[i].1 && chars.get(i + 1).map(|(_, c)| *c) == Some('/')) {
+                if '\n' == chars[i].1 {
+                    line += 1;
+                }
+                i += 1;
+            }
+            i += 2;
+            continue;
+        }
+        if '\n' == ch {
+            line += 1;
+        }
+        if '.' == ch && start.is_none() {
+            // .duckdbrc dot command, terminated by the end of line
+            let cmd_start = pos;
+            while i < chars.len() && '\n' != chars[i].1 {
+                i += 1;
+            }
+            let cmd_end = chars.get(i).map(|(p, _)| *p).unwrap_or(script.len());
+            res.push(SqlStatement {
+                text: script[cmd_start..cmd_end].trim().to_string(),
+                start: cmd_start,
+                end: cmd_end,
+                line,
+            });
+            continue;
+        }
+        if ch.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if start.is_none() {
+            start = Some(pos);
+            start_line = line;
+        }
+        if '\'' == ch || '"' == ch {
+            i += 1;
+            while i < chars.len() && ch != chars[i].1 {
+                if '\n' == chars[i].1 {
+                    line += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+        if '$' == ch {
+            // dollar-quoted string: $$ ... $$ or $tag$ ... $tag$
+            let mut j = i + 1;
+            while j < chars.len() && (chars[j].1.is_alphanumeric() || '_' == chars[j].1) {
+                j += 1;
+            }
+            if j < chars.len() && '$' == chars[j].1 {
+                let body_start = chars[j].0 + 1;
+                let tag = &script[pos..body_start];
+                let close_pos = match script[body_start..].find(tag) {
+                    Some(idx) => body_start + idx + tag.len(),
+                    None => script.len()
+                };
+                line += script[body_start..close_pos].matches('\n').count();
+                i = chars.iter().position(|(p, _)| *p >= close_pos).unwrap_or(chars.len());
+                continue;
+            }
+        }
+        if ';' == ch {
+            let st = start.take().unwrap_or(pos);
+            let end = pos + 1;
+            res.push(SqlStatement {
+                text: script[st..pos].trim().to_string(),
+                start: st,
+                end,
+                line: start_line,
+            });
+        }
+        i += 1;
+    }
+    if let Some(st) = start {
+        let text = script[st..].trim().to_string();
+        if !text.is_empty() {
+            res.push(SqlStatement {
+                text,
+                start: st,
+                end: script.len(),
+                line: start_line,
+            });
+        }
+    }
+    res
+}
+
+pub fn parse_setting_statement(statement: &str) -> Option<(String, String)> {
+    let stmt = statement.trim().trim_end_matches(';').trim();
+    let rest = if let Some(rest) = strip_prefix_ci(stmt, "set ") {
+        let mut rest = rest.trim_start();
+        for scope in ["global ", "session ", "local "].iter() {
+            if let Some(stripped) = strip_prefix_ci(rest, scope) {
+                rest = stripped.trim_start();
+            }
+        }
+        rest
+    } else if let Some(rest) = strip_prefix_ci(stmt, "pragma ") {
+        rest.trim_start()
+    } else {
+        return None;
+    };
+    let name_end = rest.find(|c: char| !(c.is_alphanumeric() || '_' == c))
+        .unwrap_or(rest.len());
+    let name = &rest[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+    let tail = rest[name_end..].trim();
+    let value = if let Some(val) = tail.strip_prefix('=') {
+        val.trim()
+    } else if let Some(val) = strip_prefix_ci(tail, "to ") {
+        val.trim()
+    } else if tail.starts_with('(') && tail.ends_with(')') {
+        tail[1..tail.len() - 1].trim()
+    } else {
+        return None;
+    };
+    let parsed = parse_literal(value)?;
+    Some((name.to_string(), parsed))
+}
+
+pub fn import_script(script: &str, known_names: &[String]) -> InitSqlImport {
+    let mut res = InitSqlImport::default();
+    let mut removed: Vec<(usize, usize)> = vec!();
+    for stmt in split_statements(script) {
+        if stmt.text.is_empty() {
+            continue;
+        }
+        let parsed = parse_setting_statement(&stmt.text);
+        let known = parsed.as_ref().and_then(|(name, _)| known_names.iter()
+            .find(|kn| kn.eq_ignore_ascii_case(name)));
+        match (parsed.clone(), known) {
+            (Some((_, value)), Some(known_name)) => {
+                res.settings.push((known_name.clone(), value));
+                removed.push((stmt.start, stmt.end));
+            },
+            (Some((name, _)), None) => res.untranslated.push(format!(
+                "line {}: unknown setting '{}': {}", stmt.line, name, stmt.text)),
+            _ => res.untranslated.push(format!(
+                "line {}: {}", stmt.line, stmt.text)),
+        }
+    }
+    let mut remaining = String::new();
+    let mut pos = 0;
+    for (start, end) in removed {
+        remaining.push_str(&script[pos..start]);
+        pos = end;
+        // drop the rest of the line if it is empty
+        let line_rest = &script[pos..];
+        let eol = line_rest.find('\n').map(|idx| idx + 1).unwrap_or(line_rest.len());
+        if line_rest[..eol].trim().is_empty() {
+            pos += eol;
+        } else {
+            pos += line_rest.len() - line_rest.trim_start_matches([' ', '\t']).len();
+        }
+    }
+    remaining.push_str(&script[pos..]);
+    res.remaining_script = remaining;
+    res
+}
+
+fn first_string_literal(statement: &str) -> Option<String> {
+    let start = statement.find('\'')?;
+    let rest = &statement[start + 1..];
+    let end = rest.find('\'')?;
+    Some(rest[..end].to_string())
+}
+
+fn strip_prefix_ci<'a>(st: &'a str, prefix: &str) -> Option<&'a str> {
+    match st.get(..prefix.len()) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => Some(&st[prefix.len()..]),
+        _ => None
+    }
+}
+
+fn parse_literal(value: &str) -> Option<String> {
+    let val = value.trim();
+    if val.is_empty() {
+        return None;
+    }
+    if val.len() >= 2 && val.starts_with('\'') && val.ends_with('\'') {
+        return Some(val[1..val.len() - 1].replace("''", "'"));
+    }
+    if val.starts_with('[') && val.ends_with(']') {
+        let items = list_value::parse_list(val, ListFormat::Literal).ok()?;
+        return Some(list_value::render_list(&items, ListFormat::Literal));
+    }
+    if val.chars().all(|c| c.is_alphanumeric() || '.' == c || '_' == c || '-' == c) {
+        return Some(val.to_string());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(script: &str) -> Vec<String> {
+        split_statements(script).into_iter().map(|st| st.text).collect()
+    }
+
+    #[test]
+    fn split_plain() {
+        let stmts = split_statements("SET threads = 4;\nSET memory_limit = '1GB';\n\nSELECT 42");
+        assert_eq!(3, stmts.len());
+        assert_eq!("SET threads = 4", stmts[0].text);
+        assert_eq!((1, 2, 4), (stmts[0].line, stmts[1].line, stmts[2].line));
+        assert_eq!("SET threads = 4;", &"SET threads = 4;\n"[stmts[0].start..stmts[0].end]);
+    }
+
+    #[test]
+    fn split_quoted_and_comments() {
+        assert_eq!(vec!("SELECT 'a;b', \"c;d\"", "SELECT 1"), texts("SELECT 'a;b', \"c;d\"; SELECT 1;"));
+        assert_eq!(vec!("SELECT 1", "SELECT 2"), texts("-- comment;\nSELECT 1; /* multi;\nline */ SELECT 2;"));
+        assert_eq!(vec!("SELECT $$a;b$$", "SELECT $x$c;$$;d$x$"), texts("SELECT $$a;b$$; SELECT $x$c;$$;d$x$;"));
+    }
+
+    #[test]
+    fn split_dot_commands() {
+        let stmts = split_statements(".timer on\nSET threads = 2;");
+        assert_eq!(vec!(".timer on", "SET threads = 2"), stmts.iter().map(|st| st.text.as_str()).collect::<Vec<_>>());
+        assert_eq!(2, stmts[1].line);
+    }
+
+    #[test]
+    fn parse_settings() {
+        let parsed = |st: &str| parse_setting_statement(st);
+        assert_eq!(Some(("threads".to_string(), "4".to_string())), parsed("SET threads = 4;"));
+        assert_eq!(Some(("memory_limit".to_string(), "1GB".to_string())), parsed("set global memory_limit to '1GB'"));
+        assert_eq!(Some(("enable_progress_bar".to_string(), "true".to_string())), parsed("PRAGMA enable_progress_bar(true)"));
+        assert_eq!(Some(("search_path".to_string(), "it's".to_string())), parsed("SET search_path = 'it''s'"));
+        assert_eq!(None, parsed("SELECT 1"));
+        assert_eq!(None, parsed("SET threads = getenv('X')"));
+    }
+
+    #[test]
+    fn import_known_settings() {
+        let known = vec!("threads".to_string(), "memory_limit".to_string());
+        let imp = import_script("SET THREADS = 4;\nINSTALL httpfs;\nSET foo = 1;\n", &known);
+        assert_eq!(vec!(("threads".to_string(), "4".to_string())), imp.settings);
+        assert_eq!(2, imp.untranslated.len());
+        assert!(imp.untranslated[1].contains("unknown setting 'foo'"));
+        assert_eq!("INSTALL httpfs;\nSET foo = 1;\n", imp.remaining_script);
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!("true", sql_literal("on", "BOOLEAN"));
+        assert_eq!("4", sql_literal(" 4 ", "BIGINT"));
+        assert_eq!("'four'", sql_literal("four", "BIGINT"));
+        assert_eq!("'it''s'", sql_literal("it's", "VARCHAR"));
+        assert_eq!("['a', 'b,c']", sql_literal("['a', 'b,c']", "VARCHAR[]"));
+        assert_eq!("['a', 'b']", sql_literal("a, b", "VARCHAR[]"));
+        assert_eq!("'[a'", sql_literal("[a", "VARCHAR[]"));
+    }
+
+    #[test]
+    fn load_warnings() {
+        assert!(statement_warnings("LOAD 'httpfs'").is_empty());
+        assert!(statement_warnings("LOAD httpfs").is_empty());
+        assert!(statement_warnings("LOAD 'Q:\\ext\\my.duckdb_extension'").is_empty());
+        let relative = "Relative path 'my.duckdb_extension' is resolved differently by each application";
+        assert_eq!(vec!(relative), statement_warnings("LOAD 'my.duckdb_extension'"));
+        assert_eq!(1, statement_warnings("LOAD 'ext/my_ext'").len());
+        assert_eq!(1, statement_warnings("ATTACH 'test.duckdb' AS test").len());
+        assert!(statement_warnings("ATTACH ':memory:' AS test").is_empty());
+    }
+
+    #[test]
+    fn render_statements() {
+        let mut threads = DuckDbSetting::new("threads", "8", "BIGINT", "GLOBAL", "");
+        threads.dsn_value = "4".to_string();
+        let mut access_mode = DuckDbSetting::new("access_mode", "automatic", "VARCHAR", "GLOBAL", "");
+        access_mode.dsn_value = "READ_ONLY".to_string();
+        let database = DuckDbSetting {
+            name: registry::DATABASE_SETTING_NAME.to_string(),
+            dsn_value: "Q:\\test.duckdb".to_string(),
+            ..DuckDbSetting::default()
+        };
+        let unset = DuckDbSetting::new("memory_limit", "80% of RAM", "VARCHAR", "GLOBAL", "");
+        let sql = render_set_statements("test", &[threads, access_mode, database, unset]);
+        assert_eq!("-- Settings exported from DSN: test\r\n\
+            SET threads = 4;\r\n\
+            -- access_mode = 'READ_ONLY' (can only be set when the database is opened)\r\n\
+            -- database = 'Q:\\test.duckdb' (can only be set when the database is opened)\r\n", sql);
+        let imp = import_script(&sql, &["threads".to_string(), "access_mode".to_string()]);
+        assert_eq!(vec!(("threads".to_string(), "4".to_string())), imp.settings);
+    }
+}
@@ -0,0 +1,316 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use std::path::Path;
+
+use db_conflicts::DbFileGroup;
+use duckdb_setting::parse_bool;
+use duckdb_setting::parse_bytes;
+use duckdb_setting::unquote;
+
+const DEBUG_SETTING_PREFIX: &str = "DEBUG SETTING";
+const THREADS_SETTING_NAMES: [&str; 2] = ["threads", "worker_threads"];
+const MEMORY_SETTING_NAMES: [&str; 2] = ["memory_limit", "max_memory"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LintFinding {
+    pub setting: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl LintFinding {
+    fn new(setting: &str, severity: Severity, message: String) -> Self {
+        Self {
+            setting: setting.to_string(),
+            severity,
+            message,
+        }
+    }
+
+    pub fn report_line(&self) -> String {
+        format!("[{}] {}: {}", self.severity.label(), self.setting, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LintReport {
+    pub dsn: String,
+    pub findings: Vec<LintFinding>,
+}
+
+impl LintReport {
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings.iter().filter(|f| f.severity == severity).count()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.count(Severity::Error) > 0
+    }
+
+    pub fn summary(&self) -> String {
+        if self.findings.is_empty() {
+            return "no problems found".to_string();
+        }
+        format!("{} errors, {} warnings", self.count(Severity::Error), self.count(Severity::Warning))
+    }
+
+    // findings are sorted by severity, so the first one is the worst
+    pub fn finding_for(&self, setting: &str) -> Option<&LintFinding> {
+        self.findings.iter().find(|f| f.setting.eq_ignore_ascii_case(setting))
+    }
+
+    pub fn render_text(&self) -> String {
+        let mut lines = vec!(format!("{}: {}", self.dsn, self.summary()));
+        for finding in &self.findings {
+            lines.push(format!("  {}", finding.report_line()));
+        }
+        lines.join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SystemLimits {
+    pub cpu_count: Option<usize>,
+    pub physical_memory: Option<u64>,
+}
+
+impl SystemLimits {
+    pub fn current() -> Self {
+        Self {
+            cpu_count: system_info::cpu_count(),
+            physical_memory: system_info::physical_memory(),
+        }
+    }
+}
+
+pub fn lint_dsn(dsn: &RegistryDsn, catalog: &[DuckDbSetting], limits: &SystemLimits,
+                conflicts: &[DbFileGroup]) -> LintReport {
+    let known = all_settings();
+    let read_only = dsn.settings.iter()
+        .find(|s| db_path::ACCESS_MODE_SETTING_NAME == s.name)
+        .map(|s| db_path::is_read_only_access_mode(&s.value))
+        .unwrap_or(false);
+    let mut findings = vec!();
+    for st in &dsn.settings {
+        let value = st.value.trim();
+        if registry::DRIVER_SETTING_NAME == st.name {
+            if dsn.is_orphaned() {
+                findings.push(LintFinding::new(&st.name, Severity::Error, format!(
+                    "Driver is not registered: '{}'", dsn.driver_path().unwrap_or(""))));
+            }
+            continue;
+        }
+        if registry::DATABASE_SETTING_NAME == st.name {
+            lint_database_path(&st.name, value, read_only, &mut findings);
+            continue;
+        }
+        if registry::SESSION_INIT_SQL_FILE_SETTING_NAME == st.name {
+            let expanded = paths::expand_env_vars(value);
+            if !value.is_empty() && !Path::new(&expanded).is_file() {
+                findings.push(LintFinding::new(&st.name, Severity::Error, format!(
+                    "Session init SQL file does not exist: '{}'", expanded)));
+            }
+            continue;
+        }
+        let setting = match catalog.iter().find(|s| s.name.eq_ignore_ascii_case(&st.name)) {
+            Some(setting) => setting,
+            None => {
+                if known.iter().any(|s| s.name.eq_ignore_ascii_case(&st.name)) {
+                    findings.push(LintFinding::new(&st.name, Severity::Warning,
+                        "Setting is not supported by the installed driver version".to_string()));
+                } else {
+                    let suggestion = match suggest_setting_name(&st.name, catalog.iter().chain(known.iter())) {
+                        Some(name) => format!(", did you mean: '{}'?", name),
+                        None => "".to_string()
+                    };
+                    findings.push(LintFinding::new(&st.name, Severity::Error, format!("Unknown setting{}", suggestion)));
+                }
+                continue;
+            }
+        };
+        if !value_matches_type(value, &setting.input_type) {
+            findings.push(LintFinding::new(&st.name, Severity::Error, format!(
+                "Value: '{}' is not a valid {}", value, setting.input_type)));
+            continue;
+        }
+        if setting.description.starts_with(DEBUG_SETTING_PREFIX) &&
+            !unquote(value).eq_ignore_ascii_case(setting.default_value.trim()) {
+            findings.push(LintFinding::new(&st.name, Severity::Warning,
+                "Debug setting is changed from its default, it is intended for testing only".to_string()));
+        }
+        if THREADS_SETTING_NAMES.contains(&setting.name.as_str()) {
+            if let (Ok(threads), Some(cores)) = (unquote(value).parse::<usize>(), limits.cpu_count) {
+                if threads > cores {
+                    findings.push(LintFinding::new(&st.name, Severity::Warning, format!(
+                        "Number of threads: {} is above the number of CPU cores: {}", threads, cores)));
+                }
+            }
+        }
+        if MEMORY_SETTING_NAMES.contains(&setting.name.as_str()) {
+            if let (Some(bytes), Some(ram)) = (parse_bytes(unquote(value)), limits.physical_memory) {
+                if bytes > ram as f64 {
+                    findings.push(LintFinding::new(&st.name, Severity::Warning, format!(
+                        "Memory limit: {} is above the physical memory: {:.1} GiB", value, ram as f64 / 1024f64.powi(3))));
+                }
+            }
+        }
+    }
+    if let Some(group) = db_conflicts::conflict_for(conflicts, dsn) {
+        findings.push(LintFinding::new(registry::DATABASE_SETTING_NAME, Severity::Warning, format!(
+            "Database file is also used by: {}, {}", group.users_listing(&dsn.display_name()), group.problem())));
+    }
+    for rule in consistency::check_values(&consistency::SettingValues::from_dsn(dsn)) {
+        findings.push(LintFinding::new(rule.settings[0], rule.severity, rule.message.to_string()));
+    }
+    findings.sort_by_key(|f| f.severity);
+    LintReport {
+        dsn: dsn.display_name(),
+        findings,
+    }
+}
+
+fn lint_database_path(name: &str, value: &str, read_only: bool, findings: &mut Vec<LintFinding>) {
+    let check = db_path::check_database_path(value, read_only);
+    for problem in &check.problems {
+        findings.push(LintFinding::new(name, Severity::Error, problem.clone()));
+    }
+    if let Some(warning) = check.relative_warning() {
+        findings.push(LintFinding::new(name, Severity::Warning, warning));
+    }
+    for warning in &check.warnings {
+        findings.push(LintFinding::new(name, Severity::Warning, warning.clone()));
+    }
+}
+
+fn value_matches_type(value: &str, input_type: &str) -> bool {
+    let value = unquote(value);
+    match input_type {
+        "BOOLEAN" => parse_bool(value).is_some(),
+        "BIGINT" => value.parse::<i64>().is_ok(),
+        "UBIGINT" => value.parse::<u64>().is_ok(),
+        "DOUBLE" => value.parse::<f64>().is_ok(),
+        _ => true
+    }
+}
+
+fn suggest_setting_name<'a, I>(name: &str, candidates: I) -> Option<String>
+    where I: Iterator<Item = &'a DuckDbSetting> {
+    let lower = name.to_lowercase();
+    let max_distance = (lower.chars().count() / 3).max(2);
+    candidates
+        .map(|s| (edit_distance(&lower, &s.name.to_lowercase()), &s.name))
+        .filter(|(dist, _)| *dist <= max_distance)
+        .min_by_key(|(dist, _)| *dist)
+        .map(|(_, name)| name.clone())
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, ac) in a.chars().enumerate() {
+        let mut cur = vec!(i + 1);
+        for (j, bc) in b_chars.iter().enumerate() {
+            let cost = if ac == *bc { 0 } else { 1 };
+            cur.push((prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1));
+        }
+        prev = cur;
+    }
+    prev[b_chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog() -> Vec<DuckDbSetting> {
+        vec!(
+            DuckDbSetting::new("threads", "8", "BIGINT", "GLOBAL", "The number of total threads used by the system."),
+            DuckDbSetting::new("memory_limit", "80% of RAM", "VARCHAR", "GLOBAL", "The maximum memory of the system"),
+            DuckDbSetting::new("enable_progress_bar", "true", "BOOLEAN", "LOCAL", "Enables the progress bar"),
+        )
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(0, edit_distance("threads", "threads"));
+        assert_eq!(1, edit_distance("threds", "threads"));
+        assert_eq!(2, edit_distance("memroy_limit", "memory_limit"));
+        assert_eq!(3, edit_distance("", "abc"));
+        assert_eq!(3, edit_distance("abc", ""));
+    }
+
+    #[test]
+    fn setting_name_suggestions() {
+        let catalog = catalog();
+        assert_eq!(Some("threads".to_string()), suggest_setting_name("Threds", catalog.iter()));
+        assert_eq!(Some("memory_limit".to_string()), suggest_setting_name("memroy_limit", catalog.iter()));
+        assert_eq!(None, suggest_setting_name("unrelated", catalog.iter()));
+    }
+
+    #[test]
+    fn lint_findings() {
+        let dsn = RegistryDsn::with_settings("test", &[
+            ("threads", "16"),
+            ("memory_limit", "'32GB'"),
+            ("memroy_limit", "1GB"),
+            ("enable_progress_bar", "maybe"),
+        ]);
+        let limits = SystemLimits {
+            cpu_count: Some(4),
+            physical_memory: Some(16 << 30),
+        };
+        let report = lint_dsn(&dsn, &catalog(), &limits, &[]);
+        assert_eq!(2, report.count(Severity::Error));
+        assert_eq!(2, report.count(Severity::Warning));
+        assert_eq!(Severity::Error, report.findings[0].severity);
+        assert_eq!("Unknown setting, did you mean: 'memory_limit'?",
+            report.finding_for("memroy_limit").unwrap().message);
+        assert_eq!("Value: 'maybe' is not a valid BOOLEAN",
+            report.finding_for("enable_progress_bar").unwrap().message);
+        assert_eq!("Number of threads: 16 is above the number of CPU cores: 4",
+            report.finding_for("threads").unwrap().message);
+        assert!(report.finding_for("memory_limit").unwrap().message.starts_with("Memory limit: '32GB'"));
+    }
+
+    #[test]
+    fn lint_orphaned() {
+        let dsn = RegistryDsn {
+            driver: None,
+            ..RegistryDsn::with_settings("test", &[(registry::DRIVER_SETTING_NAME, "Q:\\duckdb_odbc.dll")])
+        };
+        let report = lint_dsn(&dsn, &catalog(), &SystemLimits::default(), &[]);
+        assert!(report.has_errors());
+        assert_eq!("test (orphaned)", report.dsn);
+        assert_eq!("Driver is not registered: 'Q:\\duckdb_odbc.dll'", report.findings[0].message);
+    }
+}
@@ -0,0 +1,247 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListFormat {
+    // ['a', 'b'] as used by VARCHAR[] settings
+    Literal,
+    // a,b as used by VARCHAR settings that take comma separated names
+    Comma,
+    // one item per line as REG_MULTI_SZ values are shown
+    Lines,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListItemKind {
+    Text,
+    Directory,
+    File,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListSetting {
+    pub format: ListFormat,
+    pub item_kind: ListItemKind,
+    pub known_values: &'static [&'static str],
+}
+
+const DISABLED_OPTIMIZERS: &[&str] = &[
+    "expression_rewriter", "filter_pullup", "filter_pushdown", "empty_result_pullup", "cte_filter_pusher",
+    "regex_range", "in_clause", "join_order", "deliminator", "unnest_rewriter", "unused_columns",
+    "statistics_propagation", "common_subexpressions", "common_aggregate", "column_lifetime",
+    "build_side_probe_side", "limit_pushdown", "top_n", "compressed_materialization", "duplicate_groups",
+    "reorder_filter", "sampling_pushdown", "join_filter_pushdown", "extension", "materialized_cte",
+    "sum_no_overflow", "late_materialization",
+];
+
+const DISABLED_COMPRESSION_METHODS: &[&str] = &[
+    "uncompressed", "constant", "rle", "dictionary", "pfor_delta", "bitpacking", "fsst", "chimp", "patas",
+    "alp", "alprd", "zstd", "roaring", "dict_fsst",
+];
+
+const DISABLED_FILESYSTEMS: &[&str] = &[
+    "LocalFileSystem", "HTTPFileSystem", "S3FileSystem", "HuggingFaceFileSystem",
+    "AzureBlobStorageFileSystem", "AzureDfsStorageFileSystem",
+];
+
+pub fn list_setting(name: &str, input_type: &str) -> Option<ListSetting> {
+    let (format, item_kind, known_values) = match name {
+        "allowed_directories" => (ListFormat::Literal, ListItemKind::Directory, &[][..]),
+        "allowed_paths" => (ListFormat::Literal, ListItemKind::File, &[][..]),
+        "disabled_optimizers" => (ListFormat::Comma, ListItemKind::Text, DISABLED_OPTIMIZERS),
+        "disabled_compression_methods" => (ListFormat::Comma, ListItemKind::Text, DISABLED_COMPRESSION_METHODS),
+        "disabled_filesystems" => (ListFormat::Comma, ListItemKind::Text, DISABLED_FILESYSTEMS),
+        "enabled_log_types" => (ListFormat::Comma, ListItemKind::Text, &[][..]),
+        _ if input_type.ends_with("[]") => (ListFormat::Literal, ListItemKind::Text, &[][..]),
+        _ => return None
+    };
+    Some(ListSetting {
+        format,
+        item_kind,
+        known_values,
+    })
+}
+
+// Bare comma separated items are accepted for list literals too,
+// the same way as when exporting them to SQL.
+pub fn parse_list(value: &str, format: ListFormat) -> Result<Vec<String>, ConfigError> {
+    let trimmed = value.trim();
+    if ListFormat::Literal == format && trimmed.starts_with('[') {
+        if !trimmed.ends_with(']') || trimmed.len() < 2 {
+            return Err(ConfigError::from_string(format!("List value is not closed with ']': {}", trimmed)));
+        }
+        return parse_literal_items(&trimmed[1..trimmed.len() - 1]);
+    }
+    if ListFormat::Lines == format {
+        return Ok(registry::split_multi_sz(value));
+    }
+    Ok(trimmed.split(',')
+        .map(|s| unquote_item(s.trim()))
+        .filter(|s| !s.is_empty())
+        .collect())
+}
+
+pub fn render_list(items: &[String], format: ListFormat) -> String {
+    match format {
+        ListFormat::Literal => {
+            let quoted: Vec<String> = items.iter().map(|s| init_sql::quote_string(s)).collect();
+            format!("[{}]", quoted.join(", "))
+        },
+        ListFormat::Comma => items.join(","),
+        ListFormat::Lines => registry::join_multi_sz(items)
+    }
+}
+
+pub fn check_item(item: &str, format: ListFormat) -> Result<(), ConfigError> {
+    if item.trim().is_empty() {
+        return Err(ConfigError::from("List item cannot be empty"));
+    }
+    if ListFormat::Comma == format && item.contains(',') {
+        return Err(ConfigError::from_string(format!(
+            "List item cannot contain commas in a comma separated list: '{}'", item)));
+    }
+    if ListFormat::Lines == format && item.contains(['\r', '\n']) {
+        return Err(ConfigError::from_string(format!(
+            "List item cannot contain line breaks: '{}'", item)));
+    }
+    Ok(())
+}
+
+fn unquote_item(item: &str) -> String {
+    let unquoted = duckdb_setting::unquote(item);
+    if unquoted.len() == item.len() {
+        return item.to_string();
+    }
+    let quote = &item[..1];
+    unquoted.replace(&format!("{}{}", quote, quote), quote)
+}
+
+fn parse_literal_items(inner: &str) -> Result<Vec<String>, ConfigError> {
+    let mut res = vec!();
+    let mut chars = inner.chars().peekable();
+    loop {
+        while chars.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+            chars.next();
+        }
+        let first = match chars.peek() {
+            Some(c) => *c,
+            None => break
+        };
+        let mut item = String::new();
+        if '\'' == first || '"' == first {
+            chars.next();
+            let mut closed = false;
+            while let Some(c) = chars.next() {
+                if c == first {
+                    if Some(&first) == chars.peek() {
+                        item.push(first);
+                        chars.next();
+                    } else {
+                        closed = true;
+                        break;
+                    }
+                } else {
+                    item.push(c);
+                }
+            }
+            if !closed {
+                return Err(ConfigError::from_string(format!("Unterminated quoted list item: {}{}", first, item)));
+            }
+            while chars.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+                chars.next();
+            }
+            match chars.next() {
+                Some(',') | None => {},
+                Some(c) => return Err(ConfigError::from_string(format!(
+                    "Unexpected character: '{}' after list item: '{}', expected ','", c, item)))
+            }
+        } else {
+            for c in chars.by_ref() {
+                if ',' == c {
+                    break;
+                }
+                item.push(c);
+            }
+            item = item.trim().to_string();
+            if item.is_empty() {
+                continue;
+            }
+        }
+        res.push(item);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn literal_round_trip() {
+        for list in [items(&[]), items(&["a"]), items(&["C:\\data", "it's", "a,b", " padded ", "\"q\""])] {
+            let rendered = render_list(&list, ListFormat::Literal);
+            assert_eq!(list, parse_list(&rendered, ListFormat::Literal).unwrap());
+        }
+    }
+
+    #[test]
+    fn comma_round_trip() {
+        let list = items(&["top_n", "join_order"]);
+        assert_eq!("top_n,join_order", render_list(&list, ListFormat::Comma));
+        assert_eq!(list, parse_list("top_n, join_order,", ListFormat::Comma).unwrap());
+    }
+
+    #[test]
+    fn lines_round_trip() {
+        let list = items(&["Q:\\Data", "Q:\\a,b", "'quoted'"]);
+        let rendered = render_list(&list, ListFormat::Lines);
+        assert_eq!("Q:\\Data\nQ:\\a,b\n'quoted'", rendered);
+        assert_eq!(list, parse_list(&rendered, ListFormat::Lines).unwrap());
+        assert_eq!(list, parse_list("Q:\\Data\r\n\r\nQ:\\a,b\r\n'quoted'\r\n", ListFormat::Lines).unwrap());
+    }
+
+    #[test]
+    fn parse_literal_forms() {
+        assert_eq!(items(&["a", "b", "c"]), parse_list("[a, b ,, c]", ListFormat::Literal).unwrap());
+        assert_eq!(items(&["a", "b"]), parse_list("a, 'b'", ListFormat::Literal).unwrap());
+        assert_eq!(items(&["q\"x"]), parse_list("[\"q\"\"x\"]", ListFormat::Literal).unwrap());
+        assert!(parse_list("['x' y]", ListFormat::Literal).is_err());
+        assert!(parse_list("['x", ListFormat::Literal).is_err());
+        assert!(parse_list("[a", ListFormat::Literal).is_err());
+    }
+
+    #[test]
+    fn items_check() {
+        assert!(check_item("a,b", ListFormat::Literal).is_ok());
+        assert!(check_item("a,b", ListFormat::Comma).is_err());
+        assert!(check_item("  ", ListFormat::Literal).is_err());
+        assert!(check_item("a,b", ListFormat::Lines).is_ok());
+        assert!(check_item("a\r\nb", ListFormat::Lines).is_err());
+    }
+
+    #[test]
+    fn list_settings() {
+        assert_eq!(ListFormat::Comma, list_setting("disabled_optimizers", "VARCHAR").unwrap().format);
+        assert_eq!(ListItemKind::Directory, list_setting("allowed_directories", "VARCHAR[]").unwrap().item_kind);
+        assert_eq!(ListFormat::Literal, list_setting("other", "VARCHAR[]").unwrap().format);
+        assert!(list_setting("threads", "BIGINT").is_none());
+    }
+}
@@ -15,14 +15,39 @@
  */
 
 pub mod all_settings;
+pub mod bulk_apply;
+pub mod consistency;
+pub mod db_conflicts;
+pub mod db_header;
+pub mod db_path;
+pub mod dsn_diff;
+pub mod dsn_name;
+pub mod dsn_search;
+pub mod dsn_templates;
+pub mod init_sql;
 pub mod labels;
+pub mod lint;
+pub mod list_value;
+pub mod odbc;
+pub mod paths;
+pub mod pe_file;
+pub mod policy;
+pub mod presets;
 mod duckdb_setting;
 mod config_error;
 pub mod registry;
+pub mod security;
+pub mod settings_matrix;
+pub mod system_info;
+pub mod settings_catalog;
 
 pub use all_settings::all_settings;
 pub use config_error::ConfigError;
+pub use registry::Bitness;
 pub use registry::DsnType;
+pub use registry::DuckDbDriver;
 pub use registry::RegistryDsn;
 pub use registry::RegistrySetting;
+pub use registry::RegistryValueType;
 pub use duckdb_setting::DuckDbSetting;
+pub use settings_catalog::settings_catalog;
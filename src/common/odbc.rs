@@ -0,0 +1,243 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+use std::os::raw::c_void;
+use std::ptr;
+
+type SqlHandle = *mut c_void;
+type SqlReturn = i16;
+type SqlSmallInt = i16;
+type SqlUSmallInt = u16;
+type SqlInteger = i32;
+type SqlLen = isize;
+type SqlWChar = u16;
+
+const SQL_SUCCESS: SqlReturn = 0;
+const SQL_SUCCESS_WITH_INFO: SqlReturn = 1;
+const SQL_NO_DATA: SqlReturn = 100;
+const SQL_HANDLE_ENV: SqlSmallInt = 1;
+const SQL_HANDLE_DBC: SqlSmallInt = 2;
+const SQL_HANDLE_STMT: SqlSmallInt = 3;
+const SQL_ATTR_ODBC_VERSION: SqlInteger = 200;
+const SQL_OV_ODBC3: usize = 3;
+const SQL_DRIVER_NOPROMPT: SqlUSmallInt = 0;
+const SQL_NTS: SqlSmallInt = -3;
+const SQL_C_WCHAR: SqlSmallInt = -8;
+const SQL_NULL_DATA: SqlLen = -1;
+const SQL_NO_TOTAL: SqlLen = -4;
+
+#[link(name = "odbc32")]
+extern "system" {
+    fn SQLAllocHandle(handle_type: SqlSmallInt, input_handle: SqlHandle, output_handle: *mut SqlHandle) -> SqlReturn;
+    fn SQLFreeHandle(handle_type: SqlSmallInt, handle: SqlHandle) -> SqlReturn;
+    fn SQLSetEnvAttr(env: SqlHandle, attribute: SqlInteger, value: *mut c_void, string_length: SqlInteger) -> SqlReturn;
+    fn SQLDriverConnectW(dbc: SqlHandle, hwnd: *mut c_void, in_conn_str: *const SqlWChar, in_conn_str_len: SqlSmallInt,
+                         out_conn_str: *mut SqlWChar, out_conn_str_max: SqlSmallInt, out_conn_str_len: *mut SqlSmallInt,
+                         driver_completion: SqlUSmallInt) -> SqlReturn;
+    fn SQLDisconnect(dbc: SqlHandle) -> SqlReturn;
+    fn SQLExecDirectW(stmt: SqlHandle, text: *const SqlWChar, text_len: SqlInteger) -> SqlReturn;
+    fn SQLNumResultCols(stmt: SqlHandle, column_count: *mut SqlSmallInt) -> SqlReturn;
+    fn SQLFetch(stmt: SqlHandle) -> SqlReturn;
+    fn SQLGetData(stmt: SqlHandle, column: SqlUSmallInt, target_type: SqlSmallInt, target: *mut c_void,
+                  buffer_len: SqlLen, indicator: *mut SqlLen) -> SqlReturn;
+    fn SQLGetDiagRecW(handle_type: SqlSmallInt, handle: SqlHandle, rec_number: SqlSmallInt, state: *mut SqlWChar,
+                      native_error: *mut SqlInteger, message: *mut SqlWChar, buffer_len: SqlSmallInt,
+                      text_len: *mut SqlSmallInt) -> SqlReturn;
+}
+
+// Driver manager loads drivers into this process and only sees
+// the registry view of the same bitness, 32-bit DSNs with the same
+// name as 64-bit ones would silently resolve to the latter.
+pub fn process_bitness() -> Bitness {
+    if cfg!(target_pointer_width = "64") {
+        Bitness::X64
+    } else {
+        Bitness::X86
+    }
+}
+
+// Hint tells how to do the action with the other bitness
+pub fn check_bitness(bitness: &Bitness, subject: &str, hint: &str) -> Result<(), ConfigError> {
+    let process = process_bitness();
+    if *bitness != process {
+        return Err(ConfigError::from_string(format!(
+            "{} is {}, it cannot be loaded by this {} application, {}",
+            subject, bitness.label(), process.label(), hint)));
+    }
+    Ok(())
+}
+
+pub struct OdbcConnection {
+    env: SqlHandle,
+    dbc: SqlHandle,
+}
+
+impl OdbcConnection {
+    pub fn connect(conn_str: &str) -> Result<Self, ConfigError> {
+        let mut env: SqlHandle = ptr::null_mut();
+        let ret = unsafe { SQLAllocHandle(SQL_HANDLE_ENV, ptr::null_mut(), &mut env) };
+        if !succeeded(ret) {
+            return Err(ConfigError::from("Error allocating ODBC environment handle"));
+        }
+        let ret = unsafe { SQLSetEnvAttr(env, SQL_ATTR_ODBC_VERSION, SQL_OV_ODBC3 as *mut c_void, 0) };
+        if !succeeded(ret) {
+            let err = diagnostics_error("Error setting ODBC version", SQL_HANDLE_ENV, env);
+            unsafe { SQLFreeHandle(SQL_HANDLE_ENV, env) };
+            return Err(err);
+        }
+        let mut dbc: SqlHandle = ptr::null_mut();
+        let ret = unsafe { SQLAllocHandle(SQL_HANDLE_DBC, env, &mut dbc) };
+        if !succeeded(ret) {
+            let err = diagnostics_error("Error allocating ODBC connection handle", SQL_HANDLE_ENV, env);
+            unsafe { SQLFreeHandle(SQL_HANDLE_ENV, env) };
+            return Err(err);
+        }
+        let wconn_str = to_wide(conn_str);
+        let ret = unsafe { SQLDriverConnectW(dbc, ptr::null_mut(), wconn_str.as_ptr(), SQL_NTS,
+            ptr::null_mut(), 0, ptr::null_mut(), SQL_DRIVER_NOPROMPT) };
+        if !succeeded(ret) {
+            let err = diagnostics_error(&format!("Error connecting, connection string: '{}'", conn_str), SQL_HANDLE_DBC, dbc);
+            unsafe {
+                SQLFreeHandle(SQL_HANDLE_DBC, dbc);
+                SQLFreeHandle(SQL_HANDLE_ENV, env);
+            }
+            return Err(err);
+        }
+        Ok(Self { env, dbc })
+    }
+
+    pub fn query(&self, sql: &str) -> Result<Vec<Vec<Option<String>>>, ConfigError> {
+        let mut stmt: SqlHandle = ptr::null_mut();
+        let ret = unsafe { SQLAllocHandle(SQL_HANDLE_STMT, self.dbc, &mut stmt) };
+        if !succeeded(ret) {
+            return Err(diagnostics_error("Error allocating ODBC statement handle", SQL_HANDLE_DBC, self.dbc));
+        }
+        let res = Self::fetch_all(stmt, sql);
+        unsafe { SQLFreeHandle(SQL_HANDLE_STMT, stmt) };
+        res
+    }
+
+    fn fetch_all(stmt: SqlHandle, sql: &str) -> Result<Vec<Vec<Option<String>>>, ConfigError> {
+        let wsql = to_wide(sql);
+        let ret = unsafe { SQLExecDirectW(stmt, wsql.as_ptr(), SQL_NTS as SqlInteger) };
+        if !succeeded(ret) && SQL_NO_DATA != ret {
+            return Err(diagnostics_error(&format!("Error executing query: '{}'", sql), SQL_HANDLE_STMT, stmt));
+        }
+        let mut cols_count: SqlSmallInt = 0;
+        let ret = unsafe { SQLNumResultCols(stmt, &mut cols_count) };
+        if !succeeded(ret) {
+            return Err(diagnostics_error("Error reading the number of result columns", SQL_HANDLE_STMT, stmt));
+        }
+        let mut rows = vec!();
+        if 0 == cols_count {
+            return Ok(rows);
+        }
+        loop {
+            let ret = unsafe { SQLFetch(stmt) };
+            if SQL_NO_DATA == ret {
+                break;
+            }
+            if !succeeded(ret) {
+                return Err(diagnostics_error("Error fetching query results", SQL_HANDLE_STMT, stmt));
+            }
+            let mut row = vec!();
+            for col in 1..=(cols_count as SqlUSmallInt) {
+                row.push(Self::get_string(stmt, col)?);
+            }
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    fn get_string(stmt: SqlHandle, col: SqlUSmallInt) -> Result<Option<String>, ConfigError> {
+        let mut buf: Vec<SqlWChar> = vec![0; 1024];
+        let buf_bytes = (buf.len() * std::mem::size_of::<SqlWChar>()) as SqlLen;
+        let mut wide: Vec<SqlWChar> = vec!();
+        loop {
+            let mut indicator: SqlLen = 0;
+            let ret = unsafe { SQLGetData(stmt, col, SQL_C_WCHAR, buf.as_mut_ptr() as *mut c_void, buf_bytes, &mut indicator) };
+            if SQL_NO_DATA == ret {
+                break;
+            }
+            if !succeeded(ret) {
+                return Err(diagnostics_error(&format!("Error reading column: {}", col), SQL_HANDLE_STMT, stmt));
+            }
+            if SQL_NULL_DATA == indicator {
+                return Ok(None);
+            }
+            // truncated data, buffer is filled up to the null terminator
+            if SQL_NO_TOTAL == indicator || indicator >= buf_bytes {
+                wide.extend_from_slice(&buf[..buf.len() - 1]);
+                continue;
+            }
+            let chars_count = indicator as usize / std::mem::size_of::<SqlWChar>();
+            wide.extend_from_slice(&buf[..chars_count]);
+            break;
+        }
+        Ok(Some(String::from_utf16_lossy(&wide)))
+    }
+}
+
+impl Drop for OdbcConnection {
+    fn drop(&mut self) {
+        unsafe {
+            SQLDisconnect(self.dbc);
+            SQLFreeHandle(SQL_HANDLE_DBC, self.dbc);
+            SQLFreeHandle(SQL_HANDLE_ENV, self.env);
+        }
+    }
+}
+
+fn succeeded(ret: SqlReturn) -> bool {
+    SQL_SUCCESS == ret || SQL_SUCCESS_WITH_INFO == ret
+}
+
+fn to_wide(st: &str) -> Vec<SqlWChar> {
+    st.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+fn diagnostics_error(message: &str, handle_type: SqlSmallInt, handle: SqlHandle) -> ConfigError {
+    let records = diagnostics(handle_type, handle);
+    if records.is_empty() {
+        ConfigError::from_string(message.to_string())
+    } else {
+        ConfigError::from_string(format!("{}\r\n{}", message, records.join("\r\n")))
+    }
+}
+
+fn diagnostics(handle_type: SqlSmallInt, handle: SqlHandle) -> Vec<String> {
+    let mut res = vec!();
+    let mut rec_number: SqlSmallInt = 1;
+    loop {
+        let mut state: Vec<SqlWChar> = vec![0; 6];
+        let mut native_error: SqlInteger = 0;
+        let mut message: Vec<SqlWChar> = vec![0; 4096];
+        let mut text_len: SqlSmallInt = 0;
+        let ret = unsafe { SQLGetDiagRecW(handle_type, handle, rec_number, state.as_mut_ptr(), &mut native_error,
+            message.as_mut_ptr(), message.len() as SqlSmallInt, &mut text_len) };
+        if !succeeded(ret) {
+            break;
+        }
+        let state_st = String::from_utf16_lossy(&state[..5]);
+        let message_len = (text_len.max(0) as usize).min(message.len() - 1);
+        let message_st = String::from_utf16_lossy(&message[..message_len]);
+        res.push(format!("[{}] (native error: {}) {}", state_st, native_error, message_st));
+        rec_number += 1;
+    }
+    res
+}
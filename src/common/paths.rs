@@ -0,0 +1,172 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+pub fn has_env_vars(value: &str) -> bool {
+    match value.find('%') {
+        Some(start) => matches!(value[start + 1..].find('%'), Some(len) if len > 0),
+        None => false
+    }
+}
+
+// Follows ExpandEnvironmentStrings semantics: references to undefined
+// variables are left in place as is.
+pub fn expand_env_vars(value: &str) -> String {
+    let mut res = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('%') {
+        res.push_str(&rest[..start]);
+        let tail = &rest[start + 1..];
+        match tail.find('%') {
+            Some(end) if end > 0 => {
+                let name = &tail[..end];
+                match env::var(name) {
+                    Ok(val) => res.push_str(&val),
+                    Err(_) => {
+                        res.push('%');
+                        res.push_str(name);
+                        res.push('%');
+                    }
+                }
+                rest = &tail[end + 1..];
+            },
+            Some(_) => {
+                res.push('%');
+                rest = tail;
+            },
+            None => {
+                res.push('%');
+                rest = tail;
+                break;
+            }
+        }
+    }
+    res.push_str(rest);
+    res
+}
+
+// Rooted paths without a drive letter ("\\dir") and drive-relative
+// paths ("C:dir") are relative too, they depend on the current drive
+// or directory of the process.
+pub fn is_relative_path(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    let has_drive = bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && b':' == bytes[1] &&
+        (b'\\' == bytes[2] || b'/' == bytes[2]);
+    let is_unc = path.starts_with("\\\\") || path.starts_with("//");
+    !(path.is_empty() || has_drive || is_unc || path.starts_with('~'))
+}
+
+pub fn absolute_path(path: &str) -> String {
+    match env::current_dir() {
+        Ok(dir) => dir.join(Path::new(path)).to_string_lossy().to_string(),
+        Err(_) => path.to_string()
+    }
+}
+
+// Comparable form of a file path: variables expanded, separators unified,
+// '.' and '..' resolved and lowercased as Windows file names are
+// case-insensitive. Existing files are canonicalized to see through short
+// names and links. Relative paths are resolved by the driver against the
+// working directory of the client application, so they are kept relative
+// and only compared by their text.
+pub fn normalize_path(path: &str) -> String {
+    let expanded = strip_verbatim_prefix(&expand_env_vars(path.trim()).replace('/', "\\"));
+    let relative = is_relative_path(&expanded);
+    if !relative {
+        if let Ok(canonical) = fs::canonicalize(&expanded) {
+            return strip_verbatim_prefix(&canonical.to_string_lossy()).to_lowercase();
+        }
+    }
+    let (prefix, rest) = match expanded.strip_prefix("\\\\") {
+        Some(rest) => ("\\\\", rest),
+        None => ("", expanded.as_str())
+    };
+    // server and share names of UNC paths cannot be left with '..'
+    let root_len = if prefix.is_empty() { 1 } else { 2 };
+    let mut parts: Vec<&str> = vec!();
+    for part in rest.split('\\') {
+        match part {
+            "" | "." => {},
+            ".." => {
+                if relative && matches!(parts.last(), None | Some(&"..")) {
+                    parts.push(part);
+                } else if relative || parts.len() > root_len {
+                    parts.pop();
+                }
+            },
+            _ => parts.push(part)
+        }
+    }
+    let rooted = if relative && expanded.starts_with('\\') { "\\" } else { "" };
+    format!("{}{}{}", prefix, rooted, parts.join("\\")).to_lowercase()
+}
+
+fn strip_verbatim_prefix(path: &str) -> String {
+    if let Some(rest) = path.strip_prefix("\\\\?\\UNC\\") {
+        format!("\\\\{}", rest)
+    } else {
+        path.strip_prefix("\\\\?\\").unwrap_or(path).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_vars() {
+        env::set_var("PATHS_TEST_DIR", "Q:\\Data");
+        assert!(has_env_vars("%PATHS_TEST_DIR%\\a"));
+        assert!(!has_env_vars("100%"));
+        assert!(!has_env_vars("%%"));
+        assert_eq!("Q:\\Data\\a", expand_env_vars("%PATHS_TEST_DIR%\\a"));
+        assert_eq!("%PATHS_TEST_UNDEFINED%\\a", expand_env_vars("%PATHS_TEST_UNDEFINED%\\a"));
+        assert_eq!("100%", expand_env_vars("100%"));
+    }
+
+    #[test]
+    fn relative_paths() {
+        assert!(is_relative_path("a.duckdb"));
+        assert!(is_relative_path("..\\a.duckdb"));
+        assert!(is_relative_path("\\dir\\a.duckdb"));
+        assert!(is_relative_path("C:a.duckdb"));
+        assert!(!is_relative_path("C:\\a.duckdb"));
+        assert!(!is_relative_path("C:/a.duckdb"));
+        assert!(!is_relative_path("\\\\server\\share\\a.duckdb"));
+        assert!(!is_relative_path(""));
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!("q:\\data\\a.db", normalize_path("Q:/Data/./x/../A.db"));
+        assert_eq!("q:\\a.db", normalize_path("Q:\\..\\..\\a.db"));
+        assert_eq!("q:\\data\\a.db", normalize_path("\\\\?\\Q:\\Data\\a.db"));
+        assert_eq!("\\\\srv\\share\\a.db", normalize_path("\\\\srv\\share\\..\\a.db"));
+        assert_eq!("\\\\srv\\share\\a.db", normalize_path("\\\\?\\UNC\\srv\\share\\a.db"));
+        env::set_var("PATHS_TEST_NORMALIZE", "Q:\\Data");
+        assert_eq!("q:\\data\\a.db", normalize_path("%PATHS_TEST_NORMALIZE%\\a.db"));
+    }
+
+    #[test]
+    fn normalize_relative() {
+        assert_eq!("data\\a.db", normalize_path(".\\Data\\A.db"));
+        assert_eq!("..\\a.db", normalize_path("data\\..\\..\\a.db"));
+        assert_eq!("\\a.db", normalize_path("\\dir\\..\\a.db"));
+    }
+}
@@ -0,0 +1,369 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs::File;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use super::*;
+use registry::Bitness;
+
+const PE_OFFSET_POSITION: u64 = 0x3c;
+const MACHINE_I386: u16 = 0x014c;
+const MACHINE_AMD64: u16 = 0x8664;
+const MACHINE_ARM64: u16 = 0xaa64;
+const FIXED_FILE_INFO_SIGNATURE: [u8; 4] = [0xbd, 0x04, 0xef, 0xfe];
+const COFF_HEADER_SIZE: usize = 24;
+const SECTION_HEADER_SIZE: usize = 40;
+const RESOURCE_SECTION_NAME: &[u8; 5] = b".rsrc";
+const OPTIONAL_HEADER_MAGIC_PE32: u16 = 0x10b;
+const OPTIONAL_HEADER_MAGIC_PE32_PLUS: u16 = 0x20b;
+const EXPORT_DIRECTORY_SIZE: usize = 40;
+const MAX_EXPORT_NAME_LENGTH: u64 = 256;
+
+pub fn dll_bitness(path: &str) -> Result<Bitness, ConfigError> {
+    let mut file = open_dll(path)?;
+    let coff = read_coff_header(&mut file, path)?;
+    match u16::from_le_bytes([coff.header[4], coff.header[5]]) {
+        MACHINE_I386 => Ok(Bitness::X86),
+        MACHINE_AMD64 => Ok(Bitness::X64),
+        // ARM64 DLLs cannot be loaded by x64 applications even though
+        // they are registered in the same 64-bit registry view
+        MACHINE_ARM64 => Err(ConfigError::from_string(format!(
+            "ARM64 DLLs are not supported, path: '{}'", path))),
+        machine => Err(ConfigError::from_string(format!(
+            "Unsupported DLL machine type: 0x{:04x}, path: '{}'", machine, path)))
+    }
+}
+
+// Only the resource section is read, VS_FIXEDFILEINFO is looked up in it
+// by its signature instead of walking the resource directory, DLL version
+// resources contain only one.
+pub fn dll_file_version(path: &str) -> Result<String, ConfigError> {
+    let mut file = open_dll(path)?;
+    let coff = read_coff_header(&mut file, path)?;
+    let sections = read_section_table(&mut file, &coff)?;
+    let not_found = || ConfigError::from_string(format!(
+        "Version resource not found in DLL file, path: '{}'", path));
+    let resources = sections.iter()
+        .find(|s| RESOURCE_SECTION_NAME == &s.name[0..RESOURCE_SECTION_NAME.len()])
+        .ok_or_else(not_found)?;
+    file.seek(SeekFrom::Start(resources.raw_pointer as u64))?;
+    let mut bytes = vec!();
+    file.take(resources.raw_size as u64).read_to_end(&mut bytes)?;
+    let pos = bytes.windows(FIXED_FILE_INFO_SIGNATURE.len())
+        .position(|w| FIXED_FILE_INFO_SIGNATURE == w)
+        .ok_or_else(not_found)?;
+    let info = bytes.get(pos + 8..pos + 16).ok_or_else(|| ConfigError::from_string(format!(
+        "Invalid version resource in DLL file, path: '{}'", path)))?;
+    let ms = u32::from_le_bytes([info[0], info[1], info[2], info[3]]);
+    let ls = u32::from_le_bytes([info[4], info[5], info[6], info[7]]);
+    Ok(format!("v{}.{}.{}", ms >> 16, ms & 0xffff, ls >> 16))
+}
+
+// Names of the exported functions are looked up through the export
+// directory, the optional header only needs to be read up to it.
+pub fn dll_exports_function(path: &str, function: &str) -> Result<bool, ConfigError> {
+    let mut file = open_dll(path)?;
+    let coff = read_coff_header(&mut file, path)?;
+    let mut optional_header = vec!(0u8; coff.optional_header_size() as usize);
+    file.read_exact(&mut optional_header)?;
+    let directories_offset = match optional_header.get(0..2).map(|m| u16::from_le_bytes([m[0], m[1]])) {
+        Some(OPTIONAL_HEADER_MAGIC_PE32) => 96,
+        Some(OPTIONAL_HEADER_MAGIC_PE32_PLUS) => 112,
+        _ => return Err(ConfigError::from_string(format!(
+            "Invalid DLL file, optional header not found, path: '{}'", path)))
+    };
+    let export_rva = match optional_header.get(directories_offset..directories_offset + 4) {
+        Some(rva) => read_u32(rva, 0),
+        None => return Ok(false)
+    };
+    if 0 == export_rva {
+        return Ok(false);
+    }
+    let sections = read_section_table(&mut file, &coff)?;
+    let invalid = || ConfigError::from_string(format!(
+        "Invalid export directory in DLL file, path: '{}'", path));
+    let directory_offset = rva_to_offset(&sections, export_rva).ok_or_else(invalid)?;
+    file.seek(SeekFrom::Start(directory_offset))?;
+    let mut directory = [0u8; EXPORT_DIRECTORY_SIZE];
+    file.read_exact(&mut directory)?;
+    let names_count = read_u32(&directory, 24) as usize;
+    if 0 == names_count {
+        return Ok(false);
+    }
+    let names_offset = rva_to_offset(&sections, read_u32(&directory, 32)).ok_or_else(invalid)?;
+    file.seek(SeekFrom::Start(names_offset))?;
+    let mut name_rvas = vec!();
+    (&mut file).take(names_count as u64 * 4).read_to_end(&mut name_rvas)?;
+    if name_rvas.len() != names_count * 4 {
+        return Err(invalid());
+    }
+    for rva in name_rvas.chunks(4) {
+        let name_offset = rva_to_offset(&sections, read_u32(rva, 0)).ok_or_else(invalid)?;
+        file.seek(SeekFrom::Start(name_offset))?;
+        let mut name = vec!();
+        (&mut file).take(MAX_EXPORT_NAME_LENGTH).read_to_end(&mut name)?;
+        if let Some(end) = name.iter().position(|b| 0 == *b) {
+            if function.as_bytes() == &name[..end] {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+struct CoffHeader {
+    offset: u64,
+    header: [u8; COFF_HEADER_SIZE],
+}
+
+impl CoffHeader {
+    fn section_count(&self) -> usize {
+        u16::from_le_bytes([self.header[6], self.header[7]]) as usize
+    }
+
+    fn optional_header_size(&self) -> u64 {
+        u16::from_le_bytes([self.header[20], self.header[21]]) as u64
+    }
+}
+
+struct SectionHeader {
+    name: [u8; 8],
+    virtual_size: u32,
+    virtual_address: u32,
+    raw_size: u32,
+    raw_pointer: u32,
+}
+
+fn read_u32(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+}
+
+fn open_dll(path: &str) -> Result<File, ConfigError> {
+    File::open(path).map_err(|e| ConfigError::from_string(format!(
+        "Cannot open DLL file, path: '{}', message: {}", path, e)))
+}
+
+// PE signature followed by the COFF file header
+fn read_coff_header(file: &mut File, path: &str) -> Result<CoffHeader, ConfigError> {
+    let mut mz = [0u8; 2];
+    file.read_exact(&mut mz)?;
+    if b"MZ" != &mz {
+        return Err(ConfigError::from_string(format!(
+            "Invalid DLL file, DOS header not found, path: '{}'", path)));
+    }
+    file.seek(SeekFrom::Start(PE_OFFSET_POSITION))?;
+    let mut offset_bytes = [0u8; 4];
+    file.read_exact(&mut offset_bytes)?;
+    let offset = u32::from_le_bytes(offset_bytes) as u64;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut header = [0u8; COFF_HEADER_SIZE];
+    file.read_exact(&mut header)?;
+    if b"PE\0\0" != &header[0..4] {
+        return Err(ConfigError::from_string(format!(
+            "Invalid DLL file, PE header not found, path: '{}'", path)));
+    }
+    Ok(CoffHeader {
+        offset,
+        header,
+    })
+}
+
+fn read_section_table(file: &mut File, coff: &CoffHeader) -> Result<Vec<SectionHeader>, ConfigError> {
+    file.seek(SeekFrom::Start(coff.offset + COFF_HEADER_SIZE as u64 + coff.optional_header_size()))?;
+    let mut res = vec!();
+    for _ in 0..coff.section_count() {
+        let mut section = [0u8; SECTION_HEADER_SIZE];
+        file.read_exact(&mut section)?;
+        let mut name = [0u8; 8];
+        name.copy_from_slice(&section[0..8]);
+        res.push(SectionHeader {
+            name,
+            virtual_size: read_u32(&section, 8),
+            virtual_address: read_u32(&section, 12),
+            raw_size: read_u32(&section, 16),
+            raw_pointer: read_u32(&section, 20),
+        });
+    }
+    Ok(res)
+}
+
+fn rva_to_offset(sections: &[SectionHeader], rva: u32) -> Option<u64> {
+    sections.iter()
+        .find(|s| rva >= s.virtual_address && rva - s.virtual_address < s.virtual_size.max(s.raw_size))
+        .map(|s| (rva - s.virtual_address) as u64 + s.raw_pointer as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use super::*;
+
+    const PE_OFFSET: usize = 0x40;
+
+    // Minimal PE image: DOS header, COFF header, optional header and
+    // the section table followed by the sections data, RVAs of the
+    // sections are the same as their file offsets.
+    fn pe_bytes(machine: u16, optional_header: &[u8], sections: &[(&[u8], &[u8])]) -> Vec<u8> {
+        let mut bytes = vec!(0u8; PE_OFFSET);
+        bytes[0..2].copy_from_slice(b"MZ");
+        bytes[PE_OFFSET_POSITION as usize..PE_OFFSET_POSITION as usize + 4].copy_from_slice(&(PE_OFFSET as u32).to_le_bytes());
+        bytes.extend_from_slice(b"PE\0\0");
+        bytes.extend_from_slice(&machine.to_le_bytes());
+        bytes.extend_from_slice(&(sections.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 12]);
+        bytes.extend_from_slice(&(optional_header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 2]);
+        bytes.extend_from_slice(optional_header);
+        let mut data_pointer = bytes.len() + sections.len() * SECTION_HEADER_SIZE;
+        for (name, data) in sections {
+            let mut header = [0u8; SECTION_HEADER_SIZE];
+            header[0..name.len()].copy_from_slice(name);
+            header[8..12].copy_from_slice(&(data.len() as u32).to_le_bytes());
+            header[12..16].copy_from_slice(&(data_pointer as u32).to_le_bytes());
+            header[16..20].copy_from_slice(&(data.len() as u32).to_le_bytes());
+            header[20..24].copy_from_slice(&(data_pointer as u32).to_le_bytes());
+            bytes.extend_from_slice(&header);
+            data_pointer += data.len();
+        }
+        for (_, data) in sections {
+            bytes.extend_from_slice(data);
+        }
+        bytes
+    }
+
+    fn write_dll(name: &str, bytes: &[u8]) -> String {
+        let path = env::temp_dir().join(format!("pe_file_test_{}_{}.dll", name, std::process::id()));
+        fs::write(&path, bytes).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    // Optional header with only the export data directory and
+    // an export section placed right after the section table
+    fn dll_with_exports(machine: u16, names: &[&str]) -> Vec<u8> {
+        let (magic, directories) = if MACHINE_I386 == machine {
+            (OPTIONAL_HEADER_MAGIC_PE32, 96)
+        } else {
+            (OPTIONAL_HEADER_MAGIC_PE32_PLUS, 112)
+        };
+        let mut optional_header = vec!(0u8; directories + 16);
+        optional_header[0..2].copy_from_slice(&magic.to_le_bytes());
+        optional_header[directories - 4..directories].copy_from_slice(&16u32.to_le_bytes());
+        let section_pointer = PE_OFFSET + COFF_HEADER_SIZE + optional_header.len() + SECTION_HEADER_SIZE;
+        let names_pointer = section_pointer + EXPORT_DIRECTORY_SIZE;
+        let mut name_pointer = names_pointer + names.len() * 4;
+        let mut data = vec!(0u8; EXPORT_DIRECTORY_SIZE);
+        data[24..28].copy_from_slice(&(names.len() as u32).to_le_bytes());
+        data[32..36].copy_from_slice(&(names_pointer as u32).to_le_bytes());
+        for name in names {
+            data.extend_from_slice(&(name_pointer as u32).to_le_bytes());
+            name_pointer += name.len() + 1;
+        }
+        for name in names {
+            data.extend_from_slice(name.as_bytes());
+            data.push(0);
+        }
+        optional_header[directories..directories + 4].copy_from_slice(&(section_pointer as u32).to_le_bytes());
+        optional_header[directories + 4..directories + 8].copy_from_slice(&(data.len() as u32).to_le_bytes());
+        pe_bytes(machine, &optional_header, &[(b".edata", &data)])
+    }
+
+    fn exports_of(name: &str, bytes: &[u8], function: &str) -> Result<bool, ConfigError> {
+        let path = write_dll(name, bytes);
+        let res = dll_exports_function(&path, function);
+        fs::remove_file(&path).unwrap();
+        res
+    }
+
+    fn bitness_of(name: &str, bytes: &[u8]) -> Result<Bitness, ConfigError> {
+        let path = write_dll(name, bytes);
+        let res = dll_bitness(&path);
+        fs::remove_file(&path).unwrap();
+        res
+    }
+
+    fn version_of(name: &str, bytes: &[u8]) -> Result<String, ConfigError> {
+        let path = write_dll(name, bytes);
+        let res = dll_file_version(&path);
+        fs::remove_file(&path).unwrap();
+        res
+    }
+
+    #[test]
+    fn machine_types() {
+        assert_eq!(Bitness::X86, bitness_of("x86", &pe_bytes(MACHINE_I386, &[], &[])).unwrap());
+        assert_eq!(Bitness::X64, bitness_of("x64", &pe_bytes(MACHINE_AMD64, &[], &[])).unwrap());
+        let err = bitness_of("arm64", &pe_bytes(MACHINE_ARM64, &[], &[])).err().unwrap();
+        assert!(err.to_string().starts_with("ARM64 DLLs are not supported"));
+        let err = bitness_of("ia64", &pe_bytes(0x0200, &[], &[])).err().unwrap();
+        assert!(err.to_string().starts_with("Unsupported DLL machine type: 0x0200"));
+    }
+
+    #[test]
+    fn invalid_files() {
+        let err = bitness_of("not_pe", b"not a DLL file").err().unwrap();
+        assert!(err.to_string().starts_with("Invalid DLL file, DOS header not found"));
+        let mut no_signature = pe_bytes(MACHINE_AMD64, &[], &[]);
+        no_signature[PE_OFFSET..PE_OFFSET + 2].copy_from_slice(b"NE");
+        let err = bitness_of("no_signature", &no_signature).err().unwrap();
+        assert!(err.to_string().starts_with("Invalid DLL file, PE header not found"));
+        let truncated = pe_bytes(MACHINE_AMD64, &[], &[]);
+        assert!(bitness_of("truncated", &truncated[..PE_OFFSET + 6]).is_err());
+        assert!(bitness_of("truncated_dos", &truncated[..0x20]).is_err());
+        assert!(dll_bitness("Q:\\missing\\duckdb_odbc.dll").err().unwrap().to_string().starts_with("Cannot open DLL file"));
+    }
+
+    #[test]
+    fn version_resource() {
+        let mut resource = vec!(0u8; 16);
+        resource.extend_from_slice(&FIXED_FILE_INFO_SIGNATURE);
+        resource.extend_from_slice(&0x0001_0000u32.to_le_bytes());
+        resource.extend_from_slice(&0x0001_0004u32.to_le_bytes());
+        resource.extend_from_slice(&0x0001_0000u32.to_le_bytes());
+        let dll = pe_bytes(MACHINE_AMD64, &[], &[(b".text", &[0u8; 16]), (b".rsrc", &resource)]);
+        assert_eq!("v1.4.1", version_of("version", &dll).unwrap());
+        let err = version_of("no_resources", &pe_bytes(MACHINE_AMD64, &[], &[(b".text", &[0u8; 16])])).err().unwrap();
+        assert!(err.to_string().starts_with("Version resource not found"));
+        let no_version = pe_bytes(MACHINE_AMD64, &[], &[(b".rsrc", &resource[..16])]);
+        let err = version_of("no_version", &no_version).err().unwrap();
+        assert!(err.to_string().starts_with("Version resource not found"));
+        let truncated = pe_bytes(MACHINE_AMD64, &[], &[(b".rsrc", &resource[..24])]);
+        let err = version_of("truncated_version", &truncated).err().unwrap();
+        assert!(err.to_string().starts_with("Invalid version resource"));
+    }
+
+    #[test]
+    fn exported_functions() {
+        let setup = dll_with_exports(MACHINE_AMD64, &["ConfigDSN", "ConfigDriver"]);
+        assert!(exports_of("setup", &setup, "ConfigDSN").unwrap());
+        let setup_x86 = dll_with_exports(MACHINE_I386, &["ConfigDriver", "ConfigDSN"]);
+        assert!(exports_of("setup_x86", &setup_x86, "ConfigDSN").unwrap());
+        assert!(!exports_of("setup_config", &setup, "Config").unwrap());
+        let driver = dll_with_exports(MACHINE_AMD64, &["SQLConnect", "SQLDriverConnect"]);
+        assert!(!exports_of("driver", &driver, "ConfigDSN").unwrap());
+        assert!(!exports_of("no_exports", &dll_with_exports(MACHINE_AMD64, &[]), "ConfigDSN").unwrap());
+        let mut no_directory = setup.clone();
+        let directory_pos = PE_OFFSET + COFF_HEADER_SIZE + 112;
+        no_directory[directory_pos..directory_pos + 4].copy_from_slice(&0u32.to_le_bytes());
+        assert!(!exports_of("no_directory", &no_directory, "ConfigDSN").unwrap());
+        assert!(exports_of("no_optional_header", &pe_bytes(MACHINE_AMD64, &[], &[]), "ConfigDSN").is_err());
+        assert!(exports_of("truncated_exports", &setup[..setup.len() - 30], "ConfigDSN").is_err());
+    }
+}
@@ -0,0 +1,358 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use std::fs;
+use std::path::Path;
+
+use duckdb_setting::parse_bytes;
+use duckdb_setting::unquote;
+use duckdb_setting::values_match;
+
+// Machine-wide location, only administrators can change files there.
+pub const POLICY_FILE: &str = "%ProgramData%\\DuckDB\\ODBC Config\\policy.txt";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleKind {
+    Forbidden,
+    Fixed(String),
+    Range(Option<f64>, Option<f64>),
+    PathPrefixes(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyRule {
+    pub line: usize,
+    pub text: String,
+    pub setting: String,
+    pub kind: RuleKind,
+}
+
+impl PolicyRule {
+    // Value None means that the setting is removed from the DSN.
+    pub fn check(&self, value: Option<&str>) -> Option<String> {
+        let value = match value.map(|v| unquote(v.trim())) {
+            Some(v) => v,
+            None => return match &self.kind {
+                RuleKind::Fixed(fixed) => Some(format!("setting must be set to: '{}'", fixed)),
+                _ => None
+            }
+        };
+        match &self.kind {
+            RuleKind::Forbidden => Some("setting is forbidden".to_string()),
+            RuleKind::Fixed(fixed) => if values_match(value, fixed) {
+                None
+            } else {
+                Some(format!("value must be: '{}'", fixed))
+            },
+            RuleKind::Range(min, max) => match parse_amount(value) {
+                Some(amount) => if min.map(|m| amount < m).unwrap_or(false) || max.map(|m| amount > m).unwrap_or(false) {
+                    Some(format!("value: '{}' is outside of the allowed range", value))
+                } else {
+                    None
+                },
+                None => Some(format!("value: '{}' is not a number or a size", value))
+            },
+            RuleKind::PathPrefixes(prefixes) => {
+                let path = paths::normalize_path(value);
+                if value.is_empty() || prefixes.iter().any(|p| path_has_prefix(&path, &paths::normalize_path(p))) {
+                    None
+                } else if paths::is_relative_path(&path) {
+                    Some(format!("relative path: '{}' depends on the working directory of the application \
+                        and is not under an approved relative folder", value))
+                } else {
+                    Some(format!("path: '{}' is not under an approved folder", value))
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+    pub path: String,
+    pub rules: Vec<PolicyRule>,
+}
+
+impl Policy {
+    // Missing policy file means that nothing is restricted.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = paths::expand_env_vars(POLICY_FILE);
+        if !Path::new(&path).is_file() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(&path).map_err(|e| ConfigError::from_string(format!(
+            "Cannot read policy file, path: '{}', message: {}", path, e)))?;
+        let rules = parse_policy(&text).map_err(|e| ConfigError::from_string(format!(
+            "Invalid policy file, path: '{}', message: {}", path, e)))?;
+        Ok(Self {
+            path,
+            rules,
+        })
+    }
+
+    pub fn check(&self, setting: &str, value: Option<&str>) -> Result<(), ConfigError> {
+        for rule in self.rules.iter().filter(|r| r.setting.eq_ignore_ascii_case(setting)) {
+            if let Some(problem) = rule.check(value) {
+                return Err(ConfigError::from_string(format!(
+                    "Change of setting: '{}' is blocked by the policy rule: '{}' (line {} of '{}'), {}",
+                    setting, rule.text, rule.line, self.path, problem)));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn check_all(&self, settings: &[(String, String)]) -> Result<(), ConfigError> {
+        let errors: Vec<ConfigError> = settings.iter()
+            .filter_map(|(name, value)| self.check(name, Some(value)).err())
+            .collect();
+        join_errors(errors)
+    }
+
+    // Settings are the complete list of values of a new DSN,
+    // so rules for settings missing from it are checked too.
+    pub fn check_complete(&self, settings: &[(String, String)]) -> Result<(), ConfigError> {
+        let mut errors: Vec<ConfigError> = settings.iter()
+            .filter_map(|(name, value)| self.check(name, Some(value)).err())
+            .collect();
+        let mut missing: Vec<&str> = self.rules.iter()
+            .map(|r| r.setting.as_str())
+            .filter(|name| !settings.iter().any(|(st, _)| st.eq_ignore_ascii_case(name)))
+            .collect();
+        missing.sort_unstable_by_key(|name| name.to_lowercase());
+        missing.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        errors.extend(missing.iter().filter_map(|name| self.check(name, None).err()));
+        join_errors(errors)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ComplianceFinding {
+    pub dsn: String,
+    pub dsn_type: String,
+    pub setting: String,
+    pub value: String,
+    pub rule: String,
+    pub problem: String,
+}
+
+impl ComplianceFinding {
+    pub fn report_row(&self) -> Vec<String> {
+        vec!(self.dsn.clone(), self.dsn_type.clone(), self.setting.clone(), self.value.clone(),
+            self.rule.clone(), self.problem.clone())
+    }
+}
+
+pub fn check_change(setting: &str, value: Option<&str>) -> Result<(), ConfigError> {
+    Policy::load()?.check(setting, value)
+}
+
+pub fn compliance_report(policy: &Policy, dsns: &[RegistryDsn]) -> Vec<ComplianceFinding> {
+    let mut res = vec!();
+    for dsn in dsns {
+        for rule in &policy.rules {
+            let st = dsn.settings.iter().find(|s| s.name.eq_ignore_ascii_case(&rule.setting));
+            if let Some(problem) = rule.check(st.map(|s| s.value.as_str())) {
+                res.push(ComplianceFinding {
+                    dsn: dsn.display_name(),
+                    dsn_type: dsn.dsn_type.label().to_string(),
+                    setting: rule.setting.clone(),
+                    value: st.map(|s| s.value.clone()).unwrap_or_default(),
+                    rule: format!("{} (line {})", rule.text, rule.line),
+                    problem,
+                });
+            }
+        }
+    }
+    res
+}
+
+pub fn report_summary(policy: &Policy, dsns: &[RegistryDsn], findings: &[ComplianceFinding]) -> String {
+    let mut affected: Vec<&str> = findings.iter().map(|f| f.dsn.as_str()).collect();
+    affected.sort_unstable();
+    affected.dedup();
+    format!("Policy file: '{}', rules: {}, data sources checked: {}, non-compliant: {}, violations: {}",
+        policy.path, policy.rules.len(), dsns.len(), affected.len(), findings.len())
+}
+
+// Rules, one per line:
+//   forbid <setting>
+//   fixed <setting> = <value>
+//   range <setting> = [min]..[max]
+//   prefix <setting> = <folder>[; <folder>...]
+pub fn parse_policy(text: &str) -> Result<Vec<PolicyRule>, ConfigError> {
+    let mut res = vec!();
+    for (idx, line) in text.lines().enumerate() {
+        let trimmed = line.trim().trim_start_matches('\u{feff}');
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let invalid = |msg: &str| ConfigError::from_string(format!("line {}: {}: '{}'", idx + 1, msg, trimmed));
+        let (keyword, rest) = match trimmed.find(char::is_whitespace) {
+            Some(pos) => (trimmed[..pos].to_lowercase(), trimmed[pos..].trim()),
+            None => return Err(invalid("setting name is required"))
+        };
+        let (setting, arg) = match rest.find('=') {
+            Some(pos) => (rest[..pos].trim(), Some(rest[pos + 1..].trim())),
+            None => (rest, None)
+        };
+        let kind = match (keyword.as_str(), arg) {
+            ("forbid", None) => RuleKind::Forbidden,
+            ("fixed", Some(value)) => RuleKind::Fixed(unquote(value).to_string()),
+            ("range", Some(range)) => {
+                let (min, max) = match range.find("..") {
+                    Some(pos) => (range[..pos].trim(), range[pos + 2..].trim()),
+                    None => return Err(invalid("range must be specified as 'min..max'"))
+                };
+                let bound = |b: &str| if b.is_empty() {
+                    Ok(None)
+                } else {
+                    parse_amount(b).map(Some).ok_or_else(|| invalid("range bound is not a number or a size"))
+                };
+                RuleKind::Range(bound(min)?, bound(max)?)
+            },
+            ("prefix", Some(list)) => RuleKind::PathPrefixes(list.split(';')
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect()),
+            ("forbid", Some(_)) => return Err(invalid("'forbid' rule does not take a value")),
+            ("fixed", None) | ("range", None) | ("prefix", None) => return Err(invalid("value is required")),
+            _ => return Err(invalid("unknown rule, expected 'forbid', 'fixed', 'range' or 'prefix'"))
+        };
+        if setting.is_empty() {
+            return Err(invalid("setting name is required"));
+        }
+        res.push(PolicyRule {
+            line: idx + 1,
+            text: trimmed.to_string(),
+            setting: setting.to_string(),
+            kind,
+        });
+    }
+    Ok(res)
+}
+
+fn parse_amount(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok().or_else(|| parse_bytes(value))
+}
+
+fn join_errors(errors: Vec<ConfigError>) -> Result<(), ConfigError> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    Err(ConfigError::from_string(messages.join("\r\n")))
+}
+
+// Paths are normalized before the check, so only the leading parent
+// directory references of relative paths can remain, these could
+// leave the approved folder. Relative folders (including '.') approve
+// relative paths by their text.
+fn path_has_prefix(path: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('\\');
+    let under_prefix = if prefix.is_empty() {
+        paths::is_relative_path(path) && !path.starts_with('\\')
+    } else {
+        path == prefix || path.starts_with(&format!("{}\\", prefix))
+    };
+    under_prefix && !path.split('\\').any(|c| ".." == c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY: &str = "# comment
+forbid allow_unsigned_extensions
+fixed access_mode = 'READ_ONLY'
+range threads = 1..8
+range memory_limit = ..16GB
+prefix database = Q:\\Data; shared
+";
+
+    fn rule<'a>(rules: &'a [PolicyRule], setting: &str) -> &'a PolicyRule {
+        rules.iter().find(|r| setting == r.setting).unwrap()
+    }
+
+    #[test]
+    fn parse_rules() {
+        let rules = parse_policy(POLICY).unwrap();
+        assert_eq!(5, rules.len());
+        assert_eq!(RuleKind::Forbidden, rules[0].kind);
+        assert_eq!(2, rules[0].line);
+        assert_eq!(RuleKind::Fixed("READ_ONLY".to_string()), rules[1].kind);
+        assert_eq!(RuleKind::Range(Some(1.0), Some(8.0)), rules[2].kind);
+        assert_eq!(RuleKind::PathPrefixes(vec!("Q:\\Data".to_string(), "shared".to_string())), rules[4].kind);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for text in ["forbid", "forbid threads = 1", "fixed threads", "range threads = 1-8",
+            "range threads = a..b", "allow threads = 1", "fixed = 1"] {
+            assert!(parse_policy(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn check_values() {
+        let rules = parse_policy(POLICY).unwrap();
+        assert!(rule(&rules, "allow_unsigned_extensions").check(Some("false")).is_some());
+        assert!(rule(&rules, "allow_unsigned_extensions").check(None).is_none());
+        let fixed = rule(&rules, "access_mode");
+        assert!(fixed.check(Some("read_only")).is_none());
+        assert!(fixed.check(Some("READ_WRITE")).is_some());
+        assert!(fixed.check(None).is_some());
+        let threads = rule(&rules, "threads");
+        assert!(threads.check(Some("8")).is_none());
+        assert!(threads.check(Some("9")).is_some());
+        assert!(threads.check(Some("many")).is_some());
+        let memory = rule(&rules, "memory_limit");
+        assert!(memory.check(Some("8GB")).is_none());
+        assert!(memory.check(Some("32GB")).is_some());
+    }
+
+    #[test]
+    fn check_path_prefixes() {
+        let rules = parse_policy(POLICY).unwrap();
+        let prefix = rule(&rules, "database");
+        assert!(prefix.check(Some("q:/data/sales.duckdb")).is_none());
+        assert!(prefix.check(Some("Q:\\Data\\x\\..\\sales.duckdb")).is_none());
+        assert!(prefix.check(Some("Q:\\Data\\..\\sales.duckdb")).is_some());
+        assert!(prefix.check(Some("Q:\\Database.duckdb")).is_some());
+        assert!(prefix.check(Some("shared\\sales.duckdb")).is_none());
+        assert!(prefix.check(Some("shared\\..\\..\\sales.duckdb")).is_some());
+        assert!(prefix.check(Some("sales.duckdb")).is_some());
+        let dot = parse_policy("prefix database = .").unwrap();
+        assert!(dot[0].check(Some("sales.duckdb")).is_none());
+        assert!(dot[0].check(Some("..\\sales.duckdb")).is_some());
+        assert!(dot[0].check(Some("Q:\\sales.duckdb")).is_some());
+    }
+
+    #[test]
+    fn check_complete_settings() {
+        let policy = Policy {
+            path: "policy.txt".to_string(),
+            rules: parse_policy(POLICY).unwrap(),
+        };
+        let settings = vec!(("threads".to_string(), "4".to_string()));
+        assert!(policy.check_all(&settings).is_ok());
+        let err = policy.check_complete(&settings).unwrap_err().to_string();
+        assert!(err.contains("'access_mode'"));
+        assert_eq!(1, err.lines().count());
+        let settings = vec!(("threads".to_string(), "4".to_string()), ("ACCESS_MODE".to_string(), "read_only".to_string()));
+        assert!(policy.check_complete(&settings).is_ok());
+    }
+}
@@ -0,0 +1,316 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub const PRESET_FILE_EXTENSION: &str = "preset";
+pub const PRESETS_DIR: &str = "%APPDATA%\\DuckDB\\ODBC Config\\presets";
+// Copies of the bundled presets as they were last written out
+pub const BUNDLED_PRESETS_DIR: &str = "%APPDATA%\\DuckDB\\ODBC Config\\bundled_presets";
+
+// Settings that identify the data source rather than tune it,
+// they are never read from or written to presets.
+const NON_PRESET_SETTINGS: [&str; 3] = [
+    registry::DRIVER_SETTING_NAME,
+    registry::DATABASE_SETTING_NAME,
+    registry::SESSION_INIT_SQL_FILE_SETTING_NAME,
+];
+
+#[derive(Default, Debug, Clone)]
+pub struct Preset {
+    pub name: String,
+    pub description: String,
+    pub settings: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PresetChange {
+    pub name: String,
+    pub current: String,
+    pub new: String,
+}
+
+impl PresetChange {
+    pub fn report_row(&self) -> Vec<String> {
+        let current = if self.current.is_empty() {
+            "(not set)".to_string()
+        } else {
+            self.current.clone()
+        };
+        vec!(self.name.clone(), current, self.new.clone())
+    }
+}
+
+pub fn bundled_presets() -> Vec<Preset> {
+    vec!(
+        preset("Read-only analyst", "Read-only access without external files and with locked configuration", &[
+            ("access_mode", "READ_ONLY"),
+            ("enable_external_access", "false"),
+            ("lock_configuration", "true"),
+        ]),
+        preset("Low-memory laptop", "Small memory footprint for machines with 8GB of RAM or less", &[
+            ("memory_limit", "2GB"),
+            ("threads", "2"),
+            ("preserve_insertion_order", "false"),
+        ]),
+        preset("Offline", "No extension downloads, already installed extensions are loaded automatically", &[
+            ("autoinstall_known_extensions", "false"),
+            ("autoload_known_extensions", "true"),
+            ("allow_community_extensions", "false"),
+        ]),
+        preset("Locked-down extensions", "Only already installed signed core extensions can be loaded", &[
+            ("autoinstall_known_extensions", "false"),
+            ("autoload_known_extensions", "false"),
+            ("allow_community_extensions", "false"),
+            ("allow_unsigned_extensions", "false"),
+        ]),
+    )
+}
+
+pub fn presets_dir() -> PathBuf {
+    PathBuf::from(paths::expand_env_vars(PRESETS_DIR))
+}
+
+// Bundled presets are written out so they can be edited and removed like
+// the ones saved by the user. Newer bundled versions replace only the files
+// that were not changed by the user and do not bring back removed ones.
+pub fn ensure_presets_dir() -> Result<PathBuf, ConfigError> {
+    let dir = presets_dir();
+    let bundled_dir = PathBuf::from(paths::expand_env_vars(BUNDLED_PRESETS_DIR));
+    for d in [&dir, &bundled_dir] {
+        fs::create_dir_all(d).map_err(|e| ConfigError::from_string(format!(
+            "Cannot create presets directory, path: '{}', message: {}", d.to_string_lossy(), e)))?;
+    }
+    write_bundled_presets(&dir, &bundled_dir, &bundled_presets())?;
+    Ok(dir)
+}
+
+fn write_bundled_presets(dir: &Path, bundled_dir: &Path, bundled: &[Preset]) -> Result<(), ConfigError> {
+    for pr in bundled {
+        let text = render_preset(pr);
+        let bundled_path = preset_path(bundled_dir, &pr.name);
+        let previous = fs::read_to_string(&bundled_path).ok();
+        if Some(&text) == previous.as_ref() {
+            continue;
+        }
+        let path = preset_path(dir, &pr.name);
+        let update = match fs::read_to_string(&path) {
+            Ok(current) => Some(&current) == previous.as_ref(),
+            Err(_) => previous.is_none()
+        };
+        if update {
+            write_preset(&path, pr)?;
+        }
+        write_preset(&bundled_path, pr)?;
+    }
+    Ok(())
+}
+
+pub fn preset_path(dir: &Path, name: &str) -> PathBuf {
+    let file_name: String = name.chars()
+        .map(|c| if c.is_alphanumeric() || ' ' == c || '-' == c || '_' == c { c } else { '_' })
+        .collect();
+    dir.join(format!("{}.{}", file_name.trim(), PRESET_FILE_EXTENSION))
+}
+
+pub fn read_preset(path: &Path) -> Result<Preset, ConfigError> {
+    let text = fs::read_to_string(path).map_err(|e| ConfigError::from_string(format!(
+        "Cannot read preset file, path: '{}', message: {}", path.to_string_lossy(), e)))?;
+    let name = path.file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    parse_preset(&name, &text)
+}
+
+pub fn parse_preset(name: &str, text: &str) -> Result<Preset, ConfigError> {
+    let mut res = Preset {
+        name: name.to_string(),
+        ..Default::default()
+    };
+    for (idx, line) in text.lines().enumerate() {
+        let trimmed = line.trim().trim_start_matches('\u{feff}');
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Some(comment) = trimmed.strip_prefix('#') {
+            if res.description.is_empty() && res.settings.is_empty() {
+                res.description = comment.trim().to_string();
+            }
+            continue;
+        }
+        let (st_name, value) = match trimmed.find('=') {
+            Some(pos) => (trimmed[..pos].trim(), trimmed[pos + 1..].trim()),
+            None => return Err(ConfigError::from_string(format!(
+                "Invalid preset line {}, expected 'name = value': '{}'", idx + 1, trimmed)))
+        };
+        if st_name.is_empty() || NON_PRESET_SETTINGS.contains(&st_name) {
+            continue;
+        }
+        res.settings.retain(|(n, _)| n != st_name);
+        res.settings.push((st_name.to_string(), value.to_string()));
+    }
+    Ok(res)
+}
+
+pub fn render_preset(preset: &Preset) -> String {
+    let mut lines: Vec<String> = vec!();
+    if !preset.description.is_empty() {
+        lines.push(format!("# {}", preset.description));
+    }
+    for (name, value) in &preset.settings {
+        lines.push(format!("{} = {}", name, value));
+    }
+    lines.push("".to_string());
+    lines.join("\r\n")
+}
+
+pub fn write_preset(path: &Path, preset: &Preset) -> Result<(), ConfigError> {
+    fs::write(path, render_preset(preset)).map_err(|e| ConfigError::from_string(format!(
+        "Cannot write preset file, path: '{}', message: {}", path.to_string_lossy(), e)))
+}
+
+pub fn preset_from_settings(name: &str, description: &str, settings: &[DuckDbSetting]) -> Preset {
+    let values = settings.iter()
+        .filter(|s| !s.dsn_value.is_empty() && !NON_PRESET_SETTINGS.contains(&s.name.as_str()))
+        .filter(|s| !s.dsn_value.trim().eq_ignore_ascii_case(s.default_value.trim()))
+        .map(|s| (s.name.clone(), s.dsn_value.clone()))
+        .collect();
+    Preset {
+        name: name.to_string(),
+        description: description.to_string(),
+        settings: values,
+    }
+}
+
+pub fn preset_changes(preset: &Preset, settings: &[DuckDbSetting]) -> Vec<PresetChange> {
+    preset.settings.iter()
+        .filter_map(|(name, value)| {
+            let current = settings.iter()
+                .find(|s| s.name == *name)
+                .map(|s| s.dsn_value.clone())
+                .unwrap_or_default();
+            if current.trim().eq_ignore_ascii_case(value.trim()) {
+                None
+            } else {
+                Some(PresetChange {
+                    name: name.clone(),
+                    current,
+                    new: value.clone(),
+                })
+            }
+        })
+        .collect()
+}
+
+fn preset(name: &str, description: &str, values: &[(&str, &str)]) -> Preset {
+    Preset {
+        name: name.to_string(),
+        description: description.to_string(),
+        settings: values.iter()
+            .map(|(n, v)| (n.to_string(), v.to_string()))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    fn test_dirs(name: &str) -> (PathBuf, PathBuf) {
+        let base = env::temp_dir().join(format!("presets_test_{}_{}", name, process::id()));
+        let _ = fs::remove_dir_all(&base);
+        let (dir, bundled_dir) = (base.join("presets"), base.join("bundled_presets"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::create_dir_all(&bundled_dir).unwrap();
+        (dir, bundled_dir)
+    }
+
+    #[test]
+    fn parse() {
+        let text = "\u{feff}# Small memory footprint\r\n# second comment\r\n\r\n\
+            memory_limit = 2GB\r\nthreads=2\r\ndatabase = Q:\\test.duckdb\r\nmemory_limit = '1GB'\r\n";
+        let preset = parse_preset("Low-memory", text).unwrap();
+        assert_eq!("Low-memory", preset.name);
+        assert_eq!("Small memory footprint", preset.description);
+        assert_eq!(vec!(("threads".to_string(), "2".to_string()), ("memory_limit".to_string(), "'1GB'".to_string())),
+            preset.settings);
+        let err = parse_preset("Invalid", "threads 2").err().unwrap();
+        assert_eq!("Invalid preset line 1, expected 'name = value': 'threads 2'", err.to_string());
+    }
+
+    #[test]
+    fn render() {
+        let preset = preset("Test", "Test preset", &[("threads", "2"), ("memory_limit", "2GB")]);
+        let text = render_preset(&preset);
+        assert_eq!("# Test preset\r\nthreads = 2\r\nmemory_limit = 2GB\r\n", text);
+        let parsed = parse_preset("Test", &text).unwrap();
+        assert_eq!(preset.description, parsed.description);
+        assert_eq!(preset.settings, parsed.settings);
+        assert_eq!(Path::new("Q:\\presets").join("a_b_ c.preset"), preset_path(Path::new("Q:\\presets"), "a/b: c"));
+    }
+
+    #[test]
+    fn changes() {
+        let mut threads = DuckDbSetting::new("threads", "8", "BIGINT", "GLOBAL", "");
+        threads.dsn_value = "2".to_string();
+        let memory_limit = DuckDbSetting::new("memory_limit", "80% of RAM", "VARCHAR", "GLOBAL", "");
+        let preset = preset("Test", "", &[("threads", "2"), ("memory_limit", "2GB")]);
+        let changes = preset_changes(&preset, &[threads.clone(), memory_limit]);
+        assert_eq!(1, changes.len());
+        assert_eq!(vec!("memory_limit", "(not set)", "2GB"), changes[0].report_row());
+        let database = DuckDbSetting {
+            name: registry::DATABASE_SETTING_NAME.to_string(),
+            dsn_value: "Q:\\test.duckdb".to_string(),
+            ..DuckDbSetting::default()
+        };
+        let mut default_threads = DuckDbSetting::new("threads", "8", "BIGINT", "GLOBAL", "");
+        default_threads.dsn_value = "8".to_string();
+        assert!(preset_from_settings("Test", "", &[database.clone(), default_threads]).settings.is_empty());
+        assert_eq!(vec!(("threads".to_string(), "2".to_string())),
+            preset_from_settings("Test", "", &[database, threads]).settings);
+    }
+
+    #[test]
+    fn bundled_updates() {
+        let (dir, bundled_dir) = test_dirs("bundled");
+        let old = vec!(
+            preset("Unchanged", "", &[("threads", "2")]),
+            preset("Modified", "", &[("threads", "2")]),
+            preset("Removed", "", &[("threads", "2")]),
+        );
+        write_bundled_presets(&dir, &bundled_dir, &old).unwrap();
+        assert_eq!(old[0].settings, read_preset(&preset_path(&dir, "Unchanged")).unwrap().settings);
+        let modified = preset("Modified", "", &[("threads", "3")]);
+        write_preset(&preset_path(&dir, "Modified"), &modified).unwrap();
+        fs::remove_file(preset_path(&dir, "Removed")).unwrap();
+        let new: Vec<Preset> = old.iter()
+            .map(|pr| preset(&pr.name, "", &[("threads", "4")]))
+            .collect();
+        write_bundled_presets(&dir, &bundled_dir, &new).unwrap();
+        let read = |name: &str| read_preset(&preset_path(&dir, name)).map(|pr| pr.settings);
+        assert_eq!(new[0].settings, read("Unchanged").unwrap());
+        assert_eq!(modified.settings, read("Modified").unwrap());
+        assert!(read("Removed").is_err());
+        assert_eq!(new[1].settings, read_preset(&preset_path(&bundled_dir, "Modified")).unwrap().settings);
+        fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+    }
+}
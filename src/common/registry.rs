@@ -16,10 +16,38 @@
 
 use super::*;
 
+use std::path::Path;
+
 use winreg::RegKey;
+use winreg::RegValue;
 use winreg::enums;
 
-pub const DRIVER_KEY_PATH: &str = "SOFTWARE\\ODBC\\ODBCINST.INI\\DuckDB Driver";
+pub const ODBCINST_INI_SUBPATH: &str = "SOFTWARE\\ODBC\\ODBCINST.INI";
+pub const DRIVER_NAME: &str = "DuckDB Driver";
+pub const DRIVERS_LISTING_SUBPATH: &str = "ODBC Drivers";
+pub const DRIVERS_LISTING_INSTALLED: &str = "Installed";
+pub const DRIVER_SETUP_SETTING_NAME: &str = "Setup";
+pub const DRIVER_USAGE_COUNT_SETTING_NAME: &str = "UsageCount";
+pub const DRIVER_SETUP_DLL_NAME: &str = "duckdb_odbc_setup.dll";
+const CONFIG_DSN_FUNCTION_NAME: &str = "ConfigDSN";
+pub const DRIVER_ATTRIBUTE_NAMES: [&str; 8] = [
+    DRIVER_SETTING_NAME,
+    DRIVER_SETUP_SETTING_NAME,
+    "APILevel",
+    "ConnectFunctions",
+    "DriverODBCVer",
+    "FileUsage",
+    "SQLLevel",
+    DRIVER_USAGE_COUNT_SETTING_NAME,
+];
+// defaults used when repairing the driver entry, existing values are preserved
+const DRIVER_DEFAULT_ATTRIBUTES: [(&str, &str); 5] = [
+    ("APILevel", "1"),
+    ("ConnectFunctions", "YYY"),
+    ("DriverODBCVer", "03.00"),
+    ("FileUsage", "0"),
+    ("SQLLevel", "1"),
+];
 pub const ODBC_INI_SUBPATH: &str = "SOFTWARE\\ODBC\\ODBC.INI";
 pub const DRIVER_SETTING_NAME: &str = "Driver";
 pub const DATABASE_SETTING_NAME: &str = "database";
@@ -27,12 +55,70 @@ pub const DATABASE_SETTING_DESCRIPTION: &str = "Path to the database file";
 pub const SESSION_INIT_SQL_FILE_SETTING_NAME: &str = "session_init_sql_file";
 pub const SESSION_INIT_SQL_FILE_SETTING_DESCRIPTION: &str = "Path to the session init SQL file";
 pub const DS_LISTING_SUBPATH: &str = "ODBC Data Sources";
-pub const DRIVER_LISTING_LABEL: &str = "DuckDB Driver";
+pub const DRIVER_DLL_NAME: &str = "duckdb_odbc.dll";
+
+// items of REG_MULTI_SZ are shown one per line, line breaks
+// cannot appear inside items, so no escaping is needed
+pub const MULTI_SZ_SEPARATOR: char = '\n';
+pub const UNREADABLE_VALUE_MARKER: &str = "(unreadable)";
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum RegistryValueType {
+    #[default]
+    SZ,
+    EXPAND,
+    MULTI,
+    DWORD,
+    #[allow(non_camel_case_types)]
+    DWORD_BIG_ENDIAN,
+    QWORD,
+    BINARY,
+    // value that cannot be converted according to its registry type,
+    // it is shown but never written back
+    UNREADABLE,
+}
+
+impl RegistryValueType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RegistryValueType::SZ => "REG_SZ",
+            RegistryValueType::EXPAND => "REG_EXPAND_SZ",
+            RegistryValueType::MULTI => "REG_MULTI_SZ",
+            RegistryValueType::DWORD => "REG_DWORD",
+            RegistryValueType::DWORD_BIG_ENDIAN => "REG_DWORD_BIG_ENDIAN",
+            RegistryValueType::QWORD => "REG_QWORD",
+            RegistryValueType::BINARY => "REG_BINARY",
+            RegistryValueType::UNREADABLE => UNREADABLE_VALUE_MARKER,
+        }
+    }
+
+    pub fn is_string(&self) -> bool {
+        matches!(self, RegistryValueType::SZ | RegistryValueType::EXPAND)
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, RegistryValueType::DWORD | RegistryValueType::DWORD_BIG_ENDIAN | RegistryValueType::QWORD)
+    }
+
+    fn from_reg_type(vtype: &enums::RegType) -> Self {
+        match vtype {
+            enums::REG_SZ => RegistryValueType::SZ,
+            enums::REG_EXPAND_SZ => RegistryValueType::EXPAND,
+            enums::REG_MULTI_SZ => RegistryValueType::MULTI,
+            enums::REG_DWORD => RegistryValueType::DWORD,
+            enums::REG_DWORD_BIG_ENDIAN => RegistryValueType::DWORD_BIG_ENDIAN,
+            enums::REG_QWORD => RegistryValueType::QWORD,
+            _ => RegistryValueType::BINARY,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct RegistrySetting {
     pub name: String,
     pub value: String,
+    pub value_type: RegistryValueType,
 }
 
 #[derive(Debug, Clone)]
@@ -41,11 +127,75 @@ pub enum DsnType {
     SYSTEM,
 }
 
+impl DsnType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DsnType::USER => "User",
+            DsnType::SYSTEM => "System",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bitness {
+    X64,
+    X86,
+}
+
+impl Bitness {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Bitness::X64 => "64-bit",
+            Bitness::X86 => "32-bit",
+        }
+    }
+
+    fn view_flag(&self) -> u32 {
+        match self {
+            Bitness::X64 => enums::KEY_WOW64_64KEY,
+            Bitness::X86 => enums::KEY_WOW64_32KEY,
+        }
+    }
+}
+
+// Any registered DSN, not only DuckDB ones, with the driver label
+// from the data sources listing.
+#[derive(Debug, Clone)]
+pub struct ExistingDsn {
+    pub name: String,
+    pub dsn_type: DsnType,
+    pub view: Option<Bitness>,
+    pub driver: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct RegistryDsn {
     pub name: String,
     pub dsn_type: DsnType,
+    pub bitness: Bitness,
     pub settings: Vec<RegistrySetting>,
+    pub driver: Option<DuckDbDriver>,
+}
+
+impl RegistryDsn {
+    pub fn display_name(&self) -> String {
+        let name = display_name(&self.name, &self.bitness);
+        if self.is_orphaned() {
+            format!("{} (orphaned)", name)
+        } else {
+            name
+        }
+    }
+
+    pub fn driver_path(&self) -> Option<&str> {
+        self.settings.iter()
+            .find(|s| DRIVER_SETTING_NAME == s.name)
+            .map(|s| s.value.as_str())
+    }
+
+    pub fn is_orphaned(&self) -> bool {
+        self.driver.is_none()
+    }
 }
 
 impl Default for RegistryDsn {
@@ -53,7 +203,52 @@ impl Default for RegistryDsn {
         Self {
             name: "".to_string(),
             dsn_type: DsnType::USER,
+            bitness: Bitness::X64,
             settings: vec!(),
+            driver: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuckDbDriver {
+    pub name: String,
+    pub bitness: Bitness,
+    pub path: String,
+}
+
+impl DuckDbDriver {
+    pub fn display_name(&self) -> String {
+        display_name(&self.name, &self.bitness)
+    }
+}
+
+impl Default for DuckDbDriver {
+    fn default() -> Self {
+        Self {
+            name: "".to_string(),
+            bitness: Bitness::X64,
+            path: "".to_string(),
+        }
+    }
+}
+
+// Data source with string settings and a driver, used as a fixture
+// by the tests of the modules that work with DSN settings.
+#[cfg(test)]
+impl RegistryDsn {
+    pub fn with_settings(name: &str, settings: &[(&str, &str)]) -> Self {
+        Self {
+            name: name.to_string(),
+            settings: settings.iter()
+                .map(|(n, v)| RegistrySetting {
+                    name: n.to_string(),
+                    value: v.to_string(),
+                    value_type: RegistryValueType::SZ,
+                })
+                .collect(),
+            driver: Some(DuckDbDriver::default()),
+            ..RegistryDsn::default()
         }
     }
 }
@@ -64,22 +259,284 @@ pub enum Root {
     HKCU,
 }
 
-pub fn duckdb_driver_path() -> Result<String, ConfigError> {
-    let hklm = RegKey::predef(enums::HKEY_LOCAL_MACHINE);
-    let driver_key = match hklm.open_subkey(DRIVER_KEY_PATH) {
-        Ok(key) => key,
-        Err(e) => return Err(ConfigError::from_string(format!(
-            "DuckDB Driver not found in registry, path: 'HKLM\\{}', message: {}", DRIVER_KEY_PATH, e)))
+pub fn display_name(name: &str, bitness: &Bitness) -> String {
+    match bitness {
+        Bitness::X64 => name.to_string(),
+        Bitness::X86 => format!("{} ({})", name, bitness.label()),
+    }
+}
+
+pub fn list_duckdb_drivers() -> Result<Vec<DuckDbDriver>, ConfigError> {
+    let mut res: Vec<DuckDbDriver> = vec!();
+    for bitness in [Bitness::X64, Bitness::X86].iter() {
+        let names = match list_subkeys(Root::HKLM, bitness, ODBCINST_INI_SUBPATH) {
+            Ok(names) => names,
+            Err(_) => continue
+        };
+        for name in names {
+            let driver_subpath = format!("{}\\{}", ODBCINST_INI_SUBPATH, name);
+            let settings = match list_values(Root::HKLM, bitness, &driver_subpath) {
+                Ok(settings) => settings,
+                Err(_) => continue
+            };
+            let path = match settings.iter().find(|rs| DRIVER_SETTING_NAME == rs.name) {
+                Some(rs) => rs.value.clone(),
+                None => continue
+            };
+            if !(is_duckdb_driver_name(&name) || is_duckdb_driver_dll(&path)) {
+                continue;
+            }
+            // on 32-bit Windows both views point to the same keys
+            if !res.iter().any(|d| d.name == name && d.path == path) {
+                res.push(DuckDbDriver { name, bitness: bitness.clone(), path });
+            }
+        }
+    }
+    if res.is_empty() {
+        return Err(ConfigError::from_string(format!(
+            "DuckDB Driver is not registered, path: 'HKLM\\{}'. \
+            It can be registered using 'File -> DuckDB Driver' menu.", ODBCINST_INI_SUBPATH)));
+    }
+    Ok(res)
+}
+
+pub fn driver_attributes(name: &str, bitness: &Bitness) -> Result<Vec<RegistrySetting>, ConfigError> {
+    let driver_subpath = format!("{}\\{}", ODBCINST_INI_SUBPATH, name);
+    let mut res = list_values(Root::HKLM, bitness, &driver_subpath)?;
+    res.sort_by_key(|rs| DRIVER_ATTRIBUTE_NAMES.iter()
+        .position(|name| *name == rs.name)
+        .unwrap_or(DRIVER_ATTRIBUTE_NAMES.len()));
+    Ok(res)
+}
+
+// Same as SQLInstallDriverEx, UsageCount is incremented for every registration,
+// returns the new usage count.
+pub fn register_driver(name: &str, bitness: &Bitness, dll_path: &str) -> Result<u32, ConfigError> {
+    if name.trim().is_empty() || name.contains('\\') {
+        return Err(ConfigError::from_string(format!("Invalid driver name: '{}'", name)));
+    }
+    let dll_bitness = pe_file::dll_bitness(dll_path)?;
+    if dll_bitness != *bitness {
+        return Err(ConfigError::from_string(format!(
+            "Driver DLL is {}, it cannot be registered in the {} registry view, path: '{}'",
+            dll_bitness.label(), bitness.label(), dll_path)));
+    }
+    let odbcinst_key = open_key(Root::HKLM, bitness, ODBCINST_INI_SUBPATH, enums::KEY_READ | enums::KEY_WRITE)?;
+    let (driver_key, _) = odbcinst_key.create_subkey_with_flags(name, enums::KEY_READ | enums::KEY_WRITE)?;
+    driver_key.set_value(DRIVER_SETTING_NAME, &dll_path)?;
+    let existing_setup: Option<String> = driver_key.get_value(DRIVER_SETUP_SETTING_NAME).ok();
+    match find_setup_dll(dll_path, existing_setup.as_deref()) {
+        Some(setup_path) => driver_key.set_value(DRIVER_SETUP_SETTING_NAME, &setup_path)?,
+        None => if existing_setup.is_some() {
+            driver_key.delete_value(DRIVER_SETUP_SETTING_NAME)?;
+        }
+    }
+    for (name, value) in DRIVER_DEFAULT_ATTRIBUTES.iter() {
+        if driver_key.get_raw_value(name).is_err() {
+            driver_key.set_value(name, value)?;
+        }
+    }
+    let usage_count: u32 = driver_key.get_value(DRIVER_USAGE_COUNT_SETTING_NAME).unwrap_or(0) + 1;
+    driver_key.set_value(DRIVER_USAGE_COUNT_SETTING_NAME, &usage_count)?;
+    let (listing_key, _) = odbcinst_key.create_subkey_with_flags(DRIVERS_LISTING_SUBPATH, enums::KEY_SET_VALUE)?;
+    listing_key.set_value(name, &DRIVERS_LISTING_INSTALLED)?;
+    Ok(usage_count)
+}
+
+// ODBC Administrator calls ConfigDSN from the Setup DLL, it is exported either
+// by the driver itself or by a separate setup DLL installed next to it. Setup
+// that does not point to such DLL is not written at all.
+fn find_setup_dll(dll_path: &str, existing_setup: Option<&str>) -> Option<String> {
+    let sibling = Path::new(dll_path).with_file_name(DRIVER_SETUP_DLL_NAME).to_string_lossy().to_string();
+    let mut candidates = vec!(dll_path.to_string(), sibling);
+    candidates.extend(existing_setup.map(|s| s.to_string()));
+    candidates.into_iter()
+        .find(|path| pe_file::dll_exports_function(path, CONFIG_DSN_FUNCTION_NAME).unwrap_or(false))
+}
+
+pub fn unregister_driver(name: &str, bitness: &Bitness) -> Result<u32, ConfigError> {
+    let odbcinst_key = open_key(Root::HKLM, bitness, ODBCINST_INI_SUBPATH, enums::KEY_READ | enums::KEY_WRITE)?;
+    let driver_key = odbcinst_key.open_subkey_with_flags(name, enums::KEY_READ | enums::KEY_WRITE)?;
+    let usage_count: u32 = driver_key.get_value(DRIVER_USAGE_COUNT_SETTING_NAME).unwrap_or(1);
+    if usage_count > 1 {
+        driver_key.set_value(DRIVER_USAGE_COUNT_SETTING_NAME, &(usage_count - 1))?;
+        return Ok(usage_count - 1);
+    }
+    odbcinst_key.delete_subkey_with_flags(name, bitness.view_flag())?;
+    if let Ok(listing_key) = odbcinst_key.open_subkey_with_flags(DRIVERS_LISTING_SUBPATH, enums::KEY_SET_VALUE) {
+        let _ = listing_key.delete_value(name);
+    }
+    Ok(0)
+}
+
+pub fn non_engine_setting_description(name: &str) -> String {
+    if DATABASE_SETTING_NAME == name {
+        DATABASE_SETTING_DESCRIPTION.to_string()
+    } else if SESSION_INIT_SQL_FILE_SETTING_NAME == name {
+        SESSION_INIT_SQL_FILE_SETTING_DESCRIPTION.to_string()
+    } else {
+        "".to_string()
+    }
+}
+
+pub fn driver_bitness_warning(dsn: &RegistryDsn) -> Option<String> {
+    let driver_path = dsn.driver_path()?;
+    match pe_file::dll_bitness(driver_path) {
+        Ok(bitness) => if bitness != dsn.bitness {
+            Some(format!("DSN '{}' is registered in the {} registry view, but its driver is a {} DLL: '{}'",
+                dsn.name, dsn.bitness.label(), bitness.label(), driver_path))
+        } else {
+            None
+        },
+        Err(e) => Some(format!("Cannot check the driver DLL of DSN '{}': {}", dsn.name, e))
+    }
+}
+
+pub fn load_duckdb_dsns() -> Result<Vec<RegistryDsn>, ConfigError> {
+    let drivers = list_duckdb_drivers()?;
+    let mut res: Vec<RegistryDsn> = vec!();
+    for bitness in [Bitness::X64, Bitness::X86].iter() {
+        let registered = drivers.iter().any(|d| d.bitness == *bitness);
+        let system_dsns = match list_subkeys(Root::HKLM, bitness, ODBC_INI_SUBPATH) {
+            Ok(list) => list,
+            Err(e) => if registered {
+                return Err(e)
+            } else {
+                continue
+            }
+        };
+        let listing_path = format!("{}\\{}", ODBC_INI_SUBPATH, DS_LISTING_SUBPATH);
+        let listing = list_values(Root::HKLM, bitness, &listing_path).unwrap_or_default();
+        for name in system_dsns {
+            if DS_LISTING_SUBPATH == name {
+                continue;
+            }
+            let dsn_subpath = format!("{}\\{}", ODBC_INI_SUBPATH, name);
+            let settings = list_values(Root::HKLM, bitness, &dsn_subpath)?;
+            if let Some(dsn) = duckdb_dsn(name, DsnType::SYSTEM, Some(bitness), settings, &listing, &drivers) {
+                // orphaned DSNs on 32-bit Windows are found in both views
+                let duplicate = res.iter().any(|d| d.name == dsn.name && d.driver_path() == dsn.driver_path());
+                if !duplicate {
+                    res.push(dsn);
+                }
+            }
+        }
+    }
+    // user DSNs are shared between registry views,
+    // bitness is determined by the driver they point to
+    if let Ok(user_dsns) = list_subkeys(Root::HKCU, &Bitness::X64, ODBC_INI_SUBPATH) {
+        let listing_path = format!("{}\\{}", ODBC_INI_SUBPATH, DS_LISTING_SUBPATH);
+        let listing = list_values(Root::HKCU, &Bitness::X64, &listing_path).unwrap_or_default();
+        for name in user_dsns {
+            if DS_LISTING_SUBPATH == name {
+                continue;
+            }
+            let dsn_subpath = format!("{}\\{}", ODBC_INI_SUBPATH, name);
+            let settings = list_values(Root::HKCU, &Bitness::X64, &dsn_subpath)?;
+            if let Some(dsn) = duckdb_dsn(name, DsnType::USER, None, settings, &listing, &drivers) {
+                res.push(dsn);
+            }
+        }
+    }
+    Ok(res)
+}
+
+pub fn list_all_dsns() -> Vec<ExistingDsn> {
+    let mut res: Vec<ExistingDsn> = vec!();
+    let listing_path = format!("{}\\{}", ODBC_INI_SUBPATH, DS_LISTING_SUBPATH);
+    let sources = [
+        (Root::HKLM, DsnType::SYSTEM, Bitness::X64, Some(Bitness::X64)),
+        (Root::HKLM, DsnType::SYSTEM, Bitness::X86, Some(Bitness::X86)),
+        (Root::HKCU, DsnType::USER, Bitness::X64, None),
+    ];
+    for (root, dsn_type, bitness, view) in sources.iter() {
+        let mut names = list_subkeys(root.clone(), bitness, ODBC_INI_SUBPATH).unwrap_or_default();
+        let listing = list_values(root.clone(), bitness, &listing_path).unwrap_or_default();
+        names.extend(listing.iter().map(|rs| rs.name.clone()));
+        for name in names {
+            let duplicate = DS_LISTING_SUBPATH == name || res.iter().any(|d| d.name == name &&
+                matches!((&d.dsn_type, dsn_type), (DsnType::USER, DsnType::USER) | (DsnType::SYSTEM, DsnType::SYSTEM)) &&
+                d.view == *view);
+            if duplicate {
+                continue;
+            }
+            let driver = listing.iter()
+                .find(|rs| rs.name == name)
+                .map(|rs| rs.value.clone())
+                .unwrap_or_default();
+            res.push(ExistingDsn {
+                name,
+                dsn_type: dsn_type.clone(),
+                view: view.clone(),
+                driver,
+            });
+        }
+    }
+    res
+}
+
+pub fn repoint_dsn_driver(dsn: &RegistryDsn) -> Result<DuckDbDriver, ConfigError> {
+    let root = match dsn.dsn_type {
+        DsnType::USER => Root::HKCU,
+        DsnType::SYSTEM => Root::HKLM,
     };
-    match driver_key.get_value(DRIVER_SETTING_NAME) {
-        Ok(path) => Ok(path),
-        Err(e) => Err(ConfigError::from_string(format!(
-            "Error reading the 'Driver' value from registry key: 'HKLM\\{}', message: {}", DRIVER_KEY_PATH, e)))
+    let odbc_ini_key = open_key(root, &dsn.bitness, ODBC_INI_SUBPATH, enums::KEY_READ | enums::KEY_WRITE)?;
+    let (listing_key, _) = odbc_ini_key.create_subkey_with_flags(DS_LISTING_SUBPATH, enums::KEY_READ | enums::KEY_SET_VALUE)?;
+    let listing_label: String = listing_key.get_value(&dsn.name).unwrap_or_default();
+    let drivers: Vec<DuckDbDriver> = list_duckdb_drivers()?.into_iter()
+        .filter(|d| d.bitness == dsn.bitness)
+        .collect();
+    // prefer the driver the DSN is listed with, then the default one
+    let driver = match drivers.iter()
+        .find(|d| d.name.eq_ignore_ascii_case(&listing_label))
+        .or_else(|| drivers.iter().find(|d| DRIVER_NAME == d.name))
+        .or_else(|| drivers.first()) {
+        Some(driver) => driver.clone(),
+        None => return Err(ConfigError::from_string(format!(
+            "DuckDB Driver is not registered in the {} registry view", dsn.bitness.label())))
+    };
+    set_dsn_value(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name, DRIVER_SETTING_NAME, &driver.path)?;
+    listing_key.set_value(&dsn.name, &driver.name)?;
+    Ok(driver)
+}
+
+fn is_duckdb_driver_name(name: &str) -> bool {
+    name.to_lowercase().starts_with("duckdb")
+}
+
+fn is_duckdb_driver_dll(path: &str) -> bool {
+    let dll_name = path.rsplit(['\\', '/']).next().unwrap_or("");
+    dll_name.eq_ignore_ascii_case(DRIVER_DLL_NAME)
+}
+
+fn duckdb_dsn(name: String, dsn_type: DsnType, view: Option<&Bitness>, settings: Vec<RegistrySetting>,
+              listing: &[RegistrySetting], drivers: &[DuckDbDriver]) -> Option<RegistryDsn> {
+    let driver_value = settings.iter()
+        .find(|rs| DRIVER_SETTING_NAME == rs.name)
+        .map(|rs| rs.value.clone())
+        .unwrap_or_default();
+    let registered = drivers.iter()
+        .filter(|d| view.is_none() || view == Some(&d.bitness))
+        .find(|d| d.path.eq_ignore_ascii_case(&driver_value) || d.name.eq_ignore_ascii_case(&driver_value));
+    if let Some(driver) = registered {
+        let bitness = driver.bitness.clone();
+        return Some(RegistryDsn { name, dsn_type, bitness, settings, driver: Some(driver.clone()) });
     }
+    let listed = listing.iter()
+        .any(|rs| rs.name == name && (is_duckdb_driver_name(&rs.value) ||
+            drivers.iter().any(|d| d.name.eq_ignore_ascii_case(&rs.value))));
+    if !(listed || is_duckdb_driver_dll(&driver_value) || is_duckdb_driver_name(&driver_value)) {
+        return None;
+    }
+    let bitness = match view {
+        Some(bitness) => bitness.clone(),
+        None => pe_file::dll_bitness(&driver_value).unwrap_or(Bitness::X64)
+    };
+    Some(RegistryDsn { name, dsn_type, bitness, settings, driver: None })
 }
 
-pub fn list_subkeys(root: Root, path: &str) -> Result<Vec<String>, ConfigError> {
-    let key = open_key(root, path, enums::KEY_READ)?;
+pub fn list_subkeys(root: Root, bitness: &Bitness, path: &str) -> Result<Vec<String>, ConfigError> {
+    let key = open_key(root, bitness, path, enums::KEY_READ)?;
     let res = key.enum_keys()
         .filter_map(|r| match r {
             Ok(r) => Some(r),
@@ -89,13 +546,23 @@ pub fn list_subkeys(root: Root, path: &str) -> Result<Vec<String>, ConfigError>
     Ok(res)
 }
 
-pub fn list_values(root: Root, path: &str) -> Result<Vec<RegistrySetting>, ConfigError> {
-    let key = open_key(root, path, enums::KEY_READ)?;
+pub fn list_values(root: Root, bitness: &Bitness, path: &str) -> Result<Vec<RegistrySetting>, ConfigError> {
+    let key = open_key(root, bitness, path, enums::KEY_READ)?;
     let res = key.enum_values()
         .filter_map(|r| match r {
             Ok((name, rval)) => {
-                let value = format!("{}", rval);
-                let rs = RegistrySetting { name, value };
+                let rs = match value_from_raw(&rval) {
+                    Ok(value) => RegistrySetting {
+                        name,
+                        value,
+                        value_type: RegistryValueType::from_reg_type(&rval.vtype),
+                    },
+                    Err(_) => RegistrySetting {
+                        name,
+                        value: format!("{} {}", UNREADABLE_VALUE_MARKER, rval),
+                        value_type: RegistryValueType::UNREADABLE,
+                    },
+                };
                 Some(rs)
             },
             Err(_) => None,
@@ -104,17 +571,18 @@ pub fn list_values(root: Root, path: &str) -> Result<Vec<RegistrySetting>, Confi
     Ok(res)
 }
 
-pub fn create_dsn(dsn_type: DsnType, name: &str,  database: &str, session_init_sql_file: &str) -> Result<(), ConfigError> {
+pub fn create_dsn(dsn_type: DsnType, driver: &DuckDbDriver, name: &str,  database: &str, session_init_sql_file: &str) -> Result<(), ConfigError> {
+    let bitness = &driver.bitness;
     let root = match dsn_type {
         DsnType::USER => Root::HKCU,
         DsnType::SYSTEM => Root::HKLM,
     };
-    let odbc_ini_key = match open_key(root, ODBC_INI_SUBPATH, enums::KEY_READ) {
+    let odbc_ini_key = match open_key(root, bitness, ODBC_INI_SUBPATH, enums::KEY_READ) {
         Ok(key) => key,
         Err(e) => {
             match dsn_type {
                 DsnType::USER => {
-                    let software_key = open_key(Root::HKCU, "SOFTWARE", enums::KEY_WRITE)?;
+                    let software_key = open_key(Root::HKCU, bitness, "SOFTWARE", enums::KEY_WRITE)?;
                     let (key, _) = software_key.create_subkey("ODBC\\ODBC.INI")?;
                     let _ = key.create_subkey(DS_LISTING_SUBPATH)?;
                     key
@@ -132,57 +600,184 @@ pub fn create_dsn(dsn_type: DsnType, name: &str,  database: &str, session_init_s
     }
     odbc_ini_key.create_subkey(name)?;
     let dsn_key = odbc_ini_key.open_subkey_with_flags(name, enums::KEY_SET_VALUE)?;
-    let driver_path = duckdb_driver_path()?;
-    dsn_key.set_value(DRIVER_SETTING_NAME, &driver_path)?;
-    dsn_key.set_value(DATABASE_SETTING_NAME, &database.to_string())?;
-    dsn_key.set_value(SESSION_INIT_SQL_FILE_SETTING_NAME, &session_init_sql_file.to_string())?;
+    dsn_key.set_value(DRIVER_SETTING_NAME, &driver.path)?;
+    dsn_key.set_raw_value(DATABASE_SETTING_NAME, &value_to_raw(database, &path_value_type(database))?)?;
+    dsn_key.set_raw_value(SESSION_INIT_SQL_FILE_SETTING_NAME, &value_to_raw(
+        session_init_sql_file, &path_value_type(session_init_sql_file))?)?;
     let listing_key = odbc_ini_key.open_subkey_with_flags(DS_LISTING_SUBPATH, enums::KEY_SET_VALUE)?;
-    listing_key.set_value(name, &DRIVER_LISTING_LABEL)?;
+    listing_key.set_value(name, &driver.name)?;
     Ok(())
 }
 
-pub fn delete_dsn(dsn_type: DsnType, name: &str) -> Result<(), ConfigError> {
+pub fn delete_dsn(dsn_type: DsnType, bitness: &Bitness, name: &str) -> Result<(), ConfigError> {
     let root = match dsn_type {
         DsnType::USER => Root::HKCU,
         DsnType::SYSTEM => Root::HKLM,
     };
-    let odbc_ini_key = open_key(root, ODBC_INI_SUBPATH, enums::KEY_WRITE)?;
+    let odbc_ini_key = open_key(root, bitness, ODBC_INI_SUBPATH, enums::KEY_WRITE)?;
     odbc_ini_key.delete_subkey(name)?;
     let listing_key = odbc_ini_key.open_subkey_with_flags(DS_LISTING_SUBPATH, enums::KEY_SET_VALUE)?;
     listing_key.delete_value(name)?;
     Ok(())
 }
 
-pub fn set_dsn_value(dsn_type: DsnType, dsn_name: &str, st_name: &str, value: &str) -> Result<(), ConfigError>{
+pub fn set_dsn_value(dsn_type: DsnType, bitness: &Bitness, dsn_name: &str, st_name: &str, value: &str) -> Result<(), ConfigError>{
+    let root = match dsn_type {
+        DsnType::USER => Root::HKCU,
+        DsnType::SYSTEM => Root::HKLM,
+    };
+    let dsn_path = format!("{}\\{}", ODBC_INI_SUBPATH, dsn_name);
+    let dsn_key = open_key(root, bitness, &dsn_path, enums::KEY_QUERY_VALUE | enums::KEY_SET_VALUE)?;
+    let value_type = match dsn_key.get_raw_value(st_name) {
+        Ok(rval) => RegistryValueType::from_reg_type(&rval.vtype),
+        Err(_) => RegistryValueType::SZ
+    };
+    dsn_key.set_raw_value(st_name, &value_to_raw(value, &value_type)?)?;
+    Ok(())
+}
+
+pub fn set_dsn_value_with_type(dsn_type: DsnType, bitness: &Bitness, dsn_name: &str, st_name: &str, value: &str,
+                               value_type: &RegistryValueType) -> Result<(), ConfigError>{
     let root = match dsn_type {
         DsnType::USER => Root::HKCU,
         DsnType::SYSTEM => Root::HKLM,
     };
     let dsn_path = format!("{}\\{}", ODBC_INI_SUBPATH, dsn_name);
-    let dsn_key = open_key(root, &dsn_path, enums::KEY_SET_VALUE)?;
-    dsn_key.set_value(st_name, &value.to_string())?;
+    let dsn_key = open_key(root, bitness, &dsn_path, enums::KEY_SET_VALUE)?;
+    dsn_key.set_raw_value(st_name, &value_to_raw(value, value_type)?)?;
     Ok(())
 }
 
-pub fn delete_dsn_value(dsn_type: DsnType, dsn_name: &str, st_name: &str) -> Result<(), ConfigError>{
+pub fn delete_dsn_value(dsn_type: DsnType, bitness: &Bitness, dsn_name: &str, st_name: &str) -> Result<(), ConfigError>{
     let root = match dsn_type {
         DsnType::USER => Root::HKCU,
         DsnType::SYSTEM => Root::HKLM,
     };
     let dsn_path = format!("{}\\{}", ODBC_INI_SUBPATH, dsn_name);
-    let dsn_key = open_key(root, &dsn_path, enums::KEY_SET_VALUE)?;
+    let dsn_key = open_key(root, bitness, &dsn_path, enums::KEY_SET_VALUE)?;
     dsn_key.delete_value(st_name)?;
     Ok(())
 }
 
-fn open_key(root: Root, path: &str, perms: u32) -> Result<RegKey, ConfigError> {
+pub fn path_value_type(value: &str) -> RegistryValueType {
+    if paths::has_env_vars(value) {
+        RegistryValueType::EXPAND
+    } else {
+        RegistryValueType::SZ
+    }
+}
+
+pub fn join_multi_sz(items: &[String]) -> String {
+    items.join(&MULTI_SZ_SEPARATOR.to_string())
+}
+
+// Windows terminates REG_MULTI_SZ with an empty string, so empty items
+// cannot be stored and empty lines are skipped the same way RegEdit does.
+pub fn split_multi_sz(value: &str) -> Vec<String> {
+    value.split(MULTI_SZ_SEPARATOR)
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn value_from_raw(rval: &RegValue) -> Result<String, ConfigError> {
+    use winreg::types::FromRegValue;
+    let res = match RegistryValueType::from_reg_type(&rval.vtype) {
+        RegistryValueType::SZ | RegistryValueType::EXPAND => String::from_reg_value(rval)?,
+        RegistryValueType::MULTI => {
+            let list: Vec<String> = Vec::from_reg_value(rval)?;
+            join_multi_sz(&list)
+        },
+        RegistryValueType::DWORD => u32::from_reg_value(rval)?.to_string(),
+        RegistryValueType::DWORD_BIG_ENDIAN => match rval.bytes.as_slice() {
+            [b0, b1, b2, b3] => u32::from_be_bytes([*b0, *b1, *b2, *b3]).to_string(),
+            _ => return Err(ConfigError::from_string(format!(
+                "Invalid REG_DWORD_BIG_ENDIAN value length: {}", rval.bytes.len())))
+        },
+        RegistryValueType::QWORD => u64::from_reg_value(rval)?.to_string(),
+        RegistryValueType::BINARY => rval.bytes.iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<String>>()
+            .join(" "),
+        RegistryValueType::UNREADABLE => return Err(ConfigError::from("Unreadable registry value")),
+    };
+    Ok(res)
+}
+
+fn value_to_raw(value: &str, value_type: &RegistryValueType) -> Result<RegValue, ConfigError> {
+    use winreg::types::ToRegValue;
+    let res = match value_type {
+        RegistryValueType::SZ => value.to_reg_value(),
+        RegistryValueType::EXPAND => {
+            let mut rval = value.to_reg_value();
+            rval.vtype = enums::REG_EXPAND_SZ;
+            rval
+        },
+        RegistryValueType::MULTI => split_multi_sz(value).to_reg_value(),
+        RegistryValueType::DWORD => match value.trim().parse::<u32>() {
+            Ok(num) => num.to_reg_value(),
+            Err(e) => return Err(ConfigError::from_string(format!(
+                "Invalid REG_DWORD value: '{}', message: {}", value, e)))
+        },
+        RegistryValueType::DWORD_BIG_ENDIAN => match value.trim().parse::<u32>() {
+            Ok(num) => RegValue { bytes: num.to_be_bytes().to_vec(), vtype: enums::REG_DWORD_BIG_ENDIAN },
+            Err(e) => return Err(ConfigError::from_string(format!(
+                "Invalid REG_DWORD_BIG_ENDIAN value: '{}', message: {}", value, e)))
+        },
+        RegistryValueType::QWORD => match value.trim().parse::<u64>() {
+            Ok(num) => num.to_reg_value(),
+            Err(e) => return Err(ConfigError::from_string(format!(
+                "Invalid REG_QWORD value: '{}', message: {}", value, e)))
+        },
+        RegistryValueType::BINARY => {
+            let mut bytes = vec!();
+            for hex in value.split_whitespace() {
+                match u8::from_str_radix(hex, 16) {
+                    Ok(b) => bytes.push(b),
+                    Err(e) => return Err(ConfigError::from_string(format!(
+                        "Invalid REG_BINARY value: '{}', message: {}", value, e)))
+                }
+            }
+            RegValue { bytes, vtype: enums::REG_BINARY }
+        },
+        RegistryValueType::UNREADABLE => return Err(ConfigError::from_string(format!(
+            "Value: '{}' was not readable according to its registry type and cannot be written back", value))),
+    };
+    Ok(res)
+}
+
+fn open_key(root: Root, bitness: &Bitness, path: &str, perms: u32) -> Result<RegKey, ConfigError> {
     let root_key = match root {
         Root::HKLM => RegKey::predef(enums::HKEY_LOCAL_MACHINE),
         Root::HKCU => RegKey::predef(enums::HKEY_CURRENT_USER),
     };
-    match root_key.open_subkey_with_flags(path, perms) {
+    match root_key.open_subkey_with_flags(path, perms | bitness.view_flag()) {
         Ok(key) => Ok(key),
         Err(e) => Err(ConfigError::from_string(format!(
-            "Cannot open registry key, path: '{:?}\\{}', message: {}", root, path, e)))
+            "Cannot open registry key, path: '{:?}\\{}', view: {}, message: {}", root, path, bitness.label(), e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn multi_sz_round_trip() {
+        for list in [items(&[]), items(&["a", "b"]), items(&["C:\\Data\\x", "a;b", "\\\\server\\share\\", "%APPDATA%\\;"])] {
+            assert_eq!(list, split_multi_sz(&join_multi_sz(&list)));
+        }
+    }
+
+    #[test]
+    fn multi_sz_lines() {
+        assert_eq!("C:\\Data\\x\nD:\\", join_multi_sz(&items(&["C:\\Data\\x", "D:\\"])));
+        assert_eq!(items(&["C:\\Data\\x", "b"]), split_multi_sz("C:\\Data\\x\r\n\r\nb\n"));
+        assert!(split_multi_sz("").is_empty());
     }
 }
@@ -0,0 +1,209 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use duckdb_setting::parse_bool;
+use duckdb_setting::unquote;
+
+const MASKED_VALUE: &str = "********";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskLevel {
+    High,
+    Medium,
+}
+
+impl RiskLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RiskLevel::High => "high",
+            RiskLevel::Medium => "medium",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum RiskyWhen {
+    Enabled,
+    NotEmpty,
+}
+
+pub struct RiskySetting {
+    pub name: &'static str,
+    pub level: RiskLevel,
+    pub reason: &'static str,
+    when: RiskyWhen,
+    secret: bool,
+}
+
+const RISKY_SETTINGS: [RiskySetting; 9] = [
+    RiskySetting { name: "allow_unsigned_extensions", level: RiskLevel::High, when: RiskyWhen::Enabled, secret: false,
+        reason: "extensions without a valid signature can run arbitrary native code" },
+    RiskySetting { name: "allow_community_extensions", level: RiskLevel::Medium, when: RiskyWhen::Enabled, secret: false,
+        reason: "community extensions are not reviewed by DuckDB maintainers" },
+    RiskySetting { name: "allow_extensions_metadata_mismatch", level: RiskLevel::Medium, when: RiskyWhen::Enabled, secret: false,
+        reason: "extensions built for a different DuckDB version or platform can be loaded" },
+    RiskySetting { name: "allow_unredacted_secrets", level: RiskLevel::High, when: RiskyWhen::Enabled, secret: false,
+        reason: "secret values can be printed in query results" },
+    RiskySetting { name: "enable_external_access", level: RiskLevel::Medium, when: RiskyWhen::Enabled, secret: false,
+        reason: "queries can read and write local files and network locations" },
+    RiskySetting { name: "custom_extension_repository", level: RiskLevel::High, when: RiskyWhen::NotEmpty, secret: false,
+        reason: "extensions are installed from a non-default endpoint" },
+    RiskySetting { name: "autoinstall_extension_repository", level: RiskLevel::High, when: RiskyWhen::NotEmpty, secret: false,
+        reason: "autoloaded extensions are installed from a non-default endpoint" },
+    RiskySetting { name: "password", level: RiskLevel::High, when: RiskyWhen::NotEmpty, secret: true,
+        reason: "password is stored in plain text in the registry" },
+    RiskySetting { name: "http_proxy_password", level: RiskLevel::High, when: RiskyWhen::NotEmpty, secret: true,
+        reason: "proxy password is stored in plain text in the registry" },
+];
+
+impl RiskySetting {
+    pub fn is_risky_value(&self, value: &str) -> bool {
+        let value = unquote(value.trim());
+        match self.when {
+            RiskyWhen::Enabled => parse_bool(value).unwrap_or(false),
+            RiskyWhen::NotEmpty => !value.is_empty() && !"NULL".eq_ignore_ascii_case(value),
+        }
+    }
+
+    pub fn display_value(&self, value: &str) -> String {
+        if self.secret {
+            MASKED_VALUE.to_string()
+        } else {
+            value.to_string()
+        }
+    }
+
+    pub fn warning(&self) -> String {
+        format!("Security risk ({}): {}", self.level.label(), self.reason)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RiskFinding {
+    pub dsn: String,
+    pub dsn_type: String,
+    pub setting: String,
+    pub value: String,
+    pub level: RiskLevel,
+    pub reason: String,
+}
+
+impl RiskFinding {
+    pub fn report_row(&self) -> Vec<String> {
+        vec!(self.dsn.clone(), self.dsn_type.clone(), self.setting.clone(), self.value.clone(),
+            self.level.label().to_string(), self.reason.clone())
+    }
+}
+
+pub fn risky_setting(name: &str) -> Option<&'static RiskySetting> {
+    RISKY_SETTINGS.iter().find(|s| s.name.eq_ignore_ascii_case(name))
+}
+
+pub fn risk_level(name: &str) -> Option<RiskLevel> {
+    risky_setting(name).map(|s| s.level)
+}
+
+pub fn security_report(dsns: &[RegistryDsn]) -> Vec<RiskFinding> {
+    let mut res: Vec<RiskFinding> = dsns.iter()
+        .flat_map(|dsn| dsn.settings.iter().map(move |st| (dsn, st)))
+        .filter_map(|(dsn, st)| {
+            let risky = risky_setting(&st.name)?;
+            if !risky.is_risky_value(&st.value) {
+                return None;
+            }
+            Some(RiskFinding {
+                dsn: dsn.display_name(),
+                dsn_type: dsn.dsn_type.label().to_string(),
+                setting: st.name.clone(),
+                value: risky.display_value(&st.value),
+                level: risky.level,
+                reason: risky.reason.to_string(),
+            })
+        })
+        .collect();
+    res.sort_by_key(|f| f.level);
+    res
+}
+
+pub fn report_summary(dsns: &[RegistryDsn], findings: &[RiskFinding]) -> String {
+    let mut affected: Vec<&str> = findings.iter().map(|f| f.dsn.as_str()).collect();
+    affected.sort_unstable();
+    affected.dedup();
+    let high = findings.iter().filter(|f| RiskLevel::High == f.level).count();
+    format!("Data sources checked: {}, with risky settings: {}, findings: {} ({} high risk)",
+        dsns.len(), affected.len(), findings.len(), high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn risky_settings() {
+        assert_eq!(Some(RiskLevel::High), risk_level("Allow_Unsigned_Extensions"));
+        assert_eq!(Some(RiskLevel::Medium), risk_level("enable_external_access"));
+        assert_eq!(None, risk_level("threads"));
+        assert_eq!(None, risk_level("allow_unsigned"));
+        assert_eq!("Security risk (high): password is stored in plain text in the registry",
+            risky_setting("password").unwrap().warning());
+    }
+
+    #[test]
+    fn risky_values() {
+        let enabled = risky_setting("allow_unsigned_extensions").unwrap();
+        assert!(enabled.is_risky_value("true"));
+        assert!(enabled.is_risky_value(" 'TRUE' "));
+        assert!(enabled.is_risky_value("1"));
+        assert!(!enabled.is_risky_value("false"));
+        assert!(!enabled.is_risky_value("'false'"));
+        assert!(!enabled.is_risky_value(""));
+        assert!(!enabled.is_risky_value("maybe"));
+        let not_empty = risky_setting("custom_extension_repository").unwrap();
+        assert!(not_empty.is_risky_value("https://example.com/extensions"));
+        assert!(!not_empty.is_risky_value(""));
+        assert!(!not_empty.is_risky_value("''"));
+        assert!(!not_empty.is_risky_value("NULL"));
+        assert!(!not_empty.is_risky_value("'null'"));
+    }
+
+    #[test]
+    fn display_values() {
+        assert_eq!(MASKED_VALUE, risky_setting("password").unwrap().display_value("secret"));
+        assert_eq!(MASKED_VALUE, risky_setting("HTTP_PROXY_PASSWORD").unwrap().display_value("secret"));
+        assert_eq!("'true'", risky_setting("enable_external_access").unwrap().display_value("'true'"));
+    }
+
+    #[test]
+    fn report_order() {
+        let dsns = vec!(
+            RegistryDsn::with_settings("a", &[("enable_external_access", "true"), ("password", "secret")]),
+            RegistryDsn::with_settings("b", &[("threads", "4"), ("allow_community_extensions", "false"),
+                ("allow_unsigned_extensions", "'true'")]),
+            RegistryDsn::with_settings("c", &[("enable_external_access", "false")]),
+        );
+        let findings = security_report(&dsns);
+        let rows: Vec<(&str, &str, RiskLevel)> = findings.iter()
+            .map(|f| (f.dsn.as_str(), f.setting.as_str(), f.level))
+            .collect();
+        assert_eq!(vec!(("a", "password", RiskLevel::High), ("b", "allow_unsigned_extensions", RiskLevel::High),
+            ("a", "enable_external_access", RiskLevel::Medium)), rows);
+        assert_eq!(vec!("a", "User", "password", MASKED_VALUE, "high", "password is stored in plain text in the registry"),
+            findings[0].report_row());
+        assert_eq!("Data sources checked: 3, with risky settings: 2, findings: 3 (2 high risk)",
+            report_summary(&dsns, &findings));
+    }
+}
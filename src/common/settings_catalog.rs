@@ -0,0 +1,122 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+use std::fs;
+use std::path::Path;
+
+// Generated by: python resources\duckdb_settings.py --tsv "<driver name>" > <driver dir>\duckdb_settings.tsv
+pub const CATALOG_FILE_NAME: &str = "duckdb_settings.tsv";
+
+pub fn settings_catalog(driver: Option<&DuckDbDriver>) -> Vec<DuckDbSetting> {
+    if let Some(drv) = driver {
+        let dll_path = paths::expand_env_vars(&drv.path);
+        if let Some(dir) = Path::new(&dll_path).parent() {
+            if let Ok(text) = fs::read_to_string(dir.join(CATALOG_FILE_NAME)) {
+                let settings = parse_catalog(&text);
+                if !settings.is_empty() {
+                    return settings;
+                }
+            }
+        }
+    }
+    all_settings()
+}
+
+fn parse_catalog(text: &str) -> Vec<DuckDbSetting> {
+    text.lines()
+        .filter_map(|line| {
+            let parts: Vec<String> = line.split('\t')
+                .map(unescape)
+                .collect();
+            if parts.len() != 5 {
+                return None;
+            }
+            Some(DuckDbSetting::new(&parts[0], &parts[1], &parts[2], &parts[3], &parts[4]))
+        })
+        .collect()
+}
+
+fn unescape(field: &str) -> String {
+    let mut res = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(ch) = chars.next() {
+        if '\\' != ch {
+            res.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => res.push('\t'),
+            Some('n') => res.push('\n'),
+            Some(other) => res.push(other),
+            None => res.push('\\'),
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const CATALOG: &str = "threads\t8\tBIGINT\tGLOBAL\tThe number of total threads used by the system.\n\
+        custom_user_agent\t\tVARCHAR\tGLOBAL\tMetadata from DuckDB callers, e.g. \\\\tab: \\t\n\
+        malformed\tline\n";
+
+    #[test]
+    fn parse() {
+        let settings = parse_catalog(CATALOG);
+        assert_eq!(2, settings.len());
+        assert_eq!("threads", settings[0].name);
+        assert_eq!("8", settings[0].default_value);
+        assert_eq!("BIGINT", settings[0].input_type);
+        assert_eq!("", settings[1].default_value);
+        assert_eq!("Metadata from DuckDB callers, e.g. \\tab: \t", settings[1].description);
+    }
+
+    #[test]
+    fn unescape_fields() {
+        assert_eq!("a\tb\nc\\d", unescape("a\\tb\\nc\\\\d"));
+        assert_eq!("trailing\\", unescape("trailing\\"));
+    }
+
+    #[test]
+    fn catalog_next_to_driver() {
+        let dir = env::temp_dir().join(format!("settings_catalog_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let dir_st = dir.to_string_lossy().to_string();
+        let driver = DuckDbDriver {
+            path: dir.join("duckdb_odbc.dll").to_string_lossy().to_string(),
+            ..DuckDbDriver::default()
+        };
+        assert_eq!(all_settings().len(), settings_catalog(Some(&driver)).len());
+        fs::write(dir.join(CATALOG_FILE_NAME), "malformed\n").unwrap();
+        assert_eq!(all_settings().len(), settings_catalog(Some(&driver)).len());
+        fs::write(dir.join(CATALOG_FILE_NAME), CATALOG).unwrap();
+        assert_eq!(2, settings_catalog(Some(&driver)).len());
+        env::set_var("SETTINGS_CATALOG_TEST_DIR", &dir_st);
+        let driver = DuckDbDriver {
+            path: "%SETTINGS_CATALOG_TEST_DIR%/duckdb_odbc.dll".to_string(),
+            ..DuckDbDriver::default()
+        };
+        assert_eq!(2, settings_catalog(Some(&driver)).len());
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(all_settings().len(), settings_catalog(None).len());
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+use wildmatch::WildMatch;
+
+pub const UNSET_LABEL: &str = "(unset)";
+pub const CSV_FILE_EXTENSION: &str = "csv";
+
+#[derive(Debug, Clone)]
+pub struct MatrixRow {
+    pub name: String,
+    pub default_value: String,
+    pub values: Vec<Option<String>>,
+}
+
+impl MatrixRow {
+    pub fn differs(&self) -> bool {
+        match self.values.split_first() {
+            Some((first, rest)) => rest.iter().any(|v| v.as_deref().map(str::trim) != first.as_deref().map(str::trim)),
+            None => false
+        }
+    }
+
+    pub fn cell(&self, idx: usize) -> &str {
+        match self.values.get(idx) {
+            Some(Some(value)) => value.as_str(),
+            _ => UNSET_LABEL
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SettingsMatrix {
+    pub columns: Vec<String>,
+    pub rows: Vec<MatrixRow>,
+}
+
+impl SettingsMatrix {
+    pub fn visible_rows(&self, filter: &str, only_differences: bool) -> Vec<&MatrixRow> {
+        self.rows.iter()
+            .filter(|r| setting_matches_filter(&r.name, filter))
+            .filter(|r| !only_differences || r.differs())
+            .collect()
+    }
+
+    pub fn render_csv(&self, rows: &[&MatrixRow]) -> String {
+        let mut header = vec!(csv_field("Setting"), csv_field("Default"));
+        header.extend(self.columns.iter().map(|c| csv_field(c)));
+        let mut lines = vec!(header.join(","));
+        for row in rows {
+            let mut fields = vec!(csv_field(&row.name), csv_field(&row.default_value));
+            fields.extend((0..self.columns.len()).map(|idx| csv_field(row.cell(idx))));
+            lines.push(fields.join(","));
+        }
+        // BOM lets Excel detect UTF-8 when the file is opened directly
+        format!("\u{feff}{}\r\n", lines.join("\r\n"))
+    }
+}
+
+// Catalog settings come first in catalog order, values that are not
+// in the catalog are appended sorted by name, same as in the settings list
+pub fn build_matrix(dsns: &[RegistryDsn], catalog: &[DuckDbSetting]) -> SettingsMatrix {
+    let mut names: Vec<(String, String)> = catalog.iter()
+        .map(|s| (s.name.clone(), s.default_value.clone()))
+        .collect();
+    let mut extra: Vec<&str> = dsns.iter()
+        .flat_map(|d| d.settings.iter())
+        .map(|s| s.name.as_str())
+        .filter(|name| registry::DRIVER_SETTING_NAME != *name)
+        .filter(|name| !names.iter().any(|(n, _)| n.eq_ignore_ascii_case(name)))
+        .collect();
+    extra.sort_by_key(|n| n.to_lowercase());
+    extra.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    names.extend(extra.into_iter().map(|n| (n.to_string(), "".to_string())));
+    let rows = names.into_iter()
+        .map(|(name, default_value)| {
+            let values = dsns.iter()
+                .map(|d| d.settings.iter()
+                    .find(|s| s.name.eq_ignore_ascii_case(&name))
+                    .map(|s| s.value.clone()))
+                .collect();
+            MatrixRow {
+                name,
+                default_value,
+                values,
+            }
+        })
+        .collect();
+    SettingsMatrix {
+        columns: dsns.iter().map(|d| d.display_name()).collect(),
+        rows,
+    }
+}
+
+pub fn setting_matches_filter(name: &str, filter: &str) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+    if name.starts_with(filter) {
+        return true;
+    }
+    WildMatch::new(filter).matches(name)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_rows() {
+        let dsns = vec!(
+            RegistryDsn::with_settings("a", &[("Driver", "Q:\\duckdb_odbc.dll"), ("threads", "4"), ("zeta", "1")]),
+            RegistryDsn::with_settings("b", &[("THREADS", "4 "), ("Alpha", "x")]),
+        );
+        let catalog = vec!(
+            DuckDbSetting::new("threads", "8", "BIGINT", "GLOBAL", ""),
+            DuckDbSetting::new("memory_limit", "80% of RAM", "VARCHAR", "GLOBAL", ""),
+        );
+        let matrix = build_matrix(&dsns, &catalog);
+        assert_eq!(vec!("a", "b"), matrix.columns);
+        let names: Vec<&str> = matrix.rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(vec!("threads", "memory_limit", "Alpha", "zeta"), names);
+        assert!(!matrix.rows[0].differs());
+        assert!(!matrix.rows[1].differs());
+        assert!(matrix.rows[2].differs());
+        assert_eq!(UNSET_LABEL, matrix.rows[2].cell(0));
+        assert_eq!("x", matrix.rows[2].cell(1));
+        let visible: Vec<&str> = matrix.visible_rows("", true).iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(vec!("Alpha", "zeta"), visible);
+        assert_eq!(2, matrix.visible_rows("*a", false).len());
+    }
+
+    #[test]
+    fn filters() {
+        assert!(setting_matches_filter("threads", ""));
+        assert!(setting_matches_filter("threads", "thr"));
+        assert!(setting_matches_filter("memory_limit", "*limit"));
+        assert!(setting_matches_filter("memory_limit", "memory_?imit"));
+        assert!(!setting_matches_filter("memory_limit", "limit"));
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!("plain", csv_field("plain"));
+        assert_eq!("\"a,b\"", csv_field("a,b"));
+        assert_eq!("\"'a' \"\"b\"\"\"", csv_field("'a' \"b\""));
+        let matrix = build_matrix(&[RegistryDsn::with_settings("a,b", &[("threads", "4")])], &[]);
+        assert_eq!("\u{feff}Setting,Default,\"a,b\"\r\nthreads,,4\r\n",
+            matrix.render_csv(&matrix.visible_rows("", false)));
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use std::mem;
+use std::thread;
+
+#[repr(C)]
+struct MemoryStatusEx {
+    length: u32,
+    memory_load: u32,
+    total_phys: u64,
+    avail_phys: u64,
+    total_page_file: u64,
+    avail_page_file: u64,
+    total_virtual: u64,
+    avail_virtual: u64,
+    avail_extended_virtual: u64,
+}
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn GlobalMemoryStatusEx(buffer: *mut MemoryStatusEx) -> i32;
+}
+
+pub fn cpu_count() -> Option<usize> {
+    thread::available_parallelism().ok().map(|n| n.get())
+}
+
+pub fn physical_memory() -> Option<u64> {
+    let mut status: MemoryStatusEx = unsafe { mem::zeroed() };
+    status.length = mem::size_of::<MemoryStatusEx>() as u32;
+    let success = unsafe { GlobalMemoryStatusEx(&mut status) };
+    if 0 != success {
+        Some(status.total_phys)
+    } else {
+        None
+    }
+}
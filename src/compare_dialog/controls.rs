@@ -0,0 +1,144 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct CompareDialogControls {
+    layout: CompareDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) left_label: nwg::Label,
+    pub(super) left_combo: nwg::ComboBox<String>,
+    pub(super) right_label: nwg::Label,
+    pub(super) right_combo: nwg::ComboBox<String>,
+    pub(super) equal_checkbox: nwg::CheckBox,
+    pub(super) summary_label: nwg::Label,
+    pub(super) diff_view: nwg::ListView,
+
+    pub(super) copy_to_right_button: nwg::Button,
+    pub(super) copy_to_left_button: nwg::Button,
+    pub(super) copy_clipboard_button: nwg::Button,
+    pub(super) close_button: nwg::Button,
+}
+
+impl ui::Controls for CompareDialogControls {
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((760, 480))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("Compare Data Sources")
+            .build(&mut self.window)?;
+
+        nwg::Label::builder()
+            .text("Left:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.left_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.left_combo)?;
+        nwg::Label::builder()
+            .text("Right:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.right_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.right_combo)?;
+        nwg::CheckBox::builder()
+            .check_state(nwg::CheckBoxState::Unchecked)
+            .text("Show equal values")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.equal_checkbox)?;
+
+        nwg::Label::builder()
+            .text("")
+            .font(Some(&self.font_normal))
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.summary_label)?;
+
+        nwg::ListView::builder()
+            .parent(&self.window)
+            .list_style(nwg::ListViewStyle::Detailed)
+            .focus(true)
+            .ex_flags(nwg::ListViewExFlags::GRID | nwg::ListViewExFlags::FULL_ROW_SELECT)
+            .build(&mut self.diff_view)?;
+        self.diff_view.set_headers_enabled(true);
+
+        nwg::Button::builder()
+            .text("Copy to right")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.copy_to_right_button)?;
+        nwg::Button::builder()
+            .text("Copy to left")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.copy_to_left_button)?;
+        nwg::Button::builder()
+            .text("Copy to clipboard")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.copy_clipboard_button)?;
+        nwg::Button::builder()
+            .text("Close")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.close_button)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.left_combo)
+            .control(&self.right_combo)
+            .control(&self.equal_checkbox)
+            .control(&self.diff_view)
+            .control(&self.copy_to_right_button)
+            .control(&self.copy_to_left_button)
+            .control(&self.copy_clipboard_button)
+            .control(&self.close_button)
+            .build();
+    }
+}
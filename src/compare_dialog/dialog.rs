@@ -0,0 +1,211 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct CompareDialog {
+    pub(super) c: CompareDialogControls,
+
+    args: CompareDialogArgs,
+    diff: Option<DsnDiff>,
+    visible_rows: Vec<usize>,
+    changed: bool,
+}
+
+impl CompareDialog {
+    pub(super) fn on_selection_changed(&mut self, _: nwg::EventData) {
+        let left = self.c.left_combo.selection().and_then(|idx| self.args.dsns.get(idx));
+        let right = self.c.right_combo.selection().and_then(|idx| self.args.dsns.get(idx));
+        self.diff = match (left, right) {
+            (Some(l), Some(r)) => {
+                let catalog = settings_catalog(l.driver.as_ref());
+                Some(dsn_diff::diff_dsns(l, r, &catalog))
+            },
+            _ => None
+        };
+        self.reload_diff_view();
+    }
+
+    pub(super) fn on_copy_to_right(&mut self, _: nwg::EventData) {
+        self.copy_values(true);
+    }
+
+    pub(super) fn on_copy_to_left(&mut self, _: nwg::EventData) {
+        self.copy_values(false);
+    }
+
+    pub(super) fn copy_to_clipboard(&mut self, _: nwg::EventData) {
+        if let Some(diff) = &self.diff {
+            let include_equal = self.c.equal_checkbox.check_state() == nwg::CheckBoxState::Checked;
+            let _ = set_clipboard(formats::Unicode, &diff.render_text(include_equal));
+        }
+    }
+
+    fn copy_values(&mut self, to_right: bool) {
+        let (diff, target_idx) = match (&self.diff, self.c.left_combo.selection(), self.c.right_combo.selection()) {
+            (Some(diff), Some(left_idx), Some(right_idx)) => (diff, if to_right { right_idx } else { left_idx }),
+            _ => return
+        };
+        let target = &self.args.dsns[target_idx];
+        let rows: Vec<&dsn_diff::DiffRow> = self.c.diff_view.selected_items().into_iter()
+            .filter_map(|idx| self.visible_rows.get(idx))
+            .map(|row_idx| &diff.rows[*row_idx])
+            .filter(|row| DiffKind::Equal != row.kind && registry::DRIVER_SETTING_NAME != row.name)
+            .collect();
+        if rows.is_empty() {
+            return;
+        }
+        let policy_check = Policy::load().and_then(|pl| {
+            rows.iter().try_for_each(|row| {
+                let source = if to_right { &row.left } else { &row.right };
+                pl.check(&row.name, source.as_ref().map(|st| st.value.as_str()))
+            })
+        });
+        if let Err(e) = policy_check {
+            ui::message_box_error(&e.to_string());
+            return;
+        }
+        let listing: Vec<String> = rows.iter()
+            .map(|row| {
+                let source = if to_right { &row.left } else { &row.right };
+                match source {
+                    Some(st) => format!("{} = {}", row.name, st.value),
+                    None => format!("{} (will be removed)", row.name)
+                }
+            })
+            .collect();
+        let confirmed = ui::message_box_warning_yn(&format!(
+            "Following values will be written to the data source: '{}':\r\n\r\n{}\r\n\r\nWould you like to proceed?",
+            target.display_name(), listing.join("\r\n")));
+        if !confirmed {
+            return;
+        }
+        for row in rows {
+            let source = if to_right { &row.left } else { &row.right };
+            let res = match source {
+                Some(st) => registry::set_dsn_value_with_type(
+                    target.dsn_type.clone(), &target.bitness, &target.name, &st.name, &st.value, &st.value_type),
+                None => registry::delete_dsn_value(target.dsn_type.clone(), &target.bitness, &target.name, &row.name)
+            };
+            if let Err(e) = res {
+                ui::message_box_error(&format!(
+                    "Error writing DSN value, DSN: '{}', setting name: '{}', message: '{}'", target.name, row.name, e));
+                break;
+            }
+        }
+        self.changed = true;
+        match registry::load_duckdb_dsns() {
+            Ok(dsns) => self.args.dsns = dsns,
+            Err(e) => ui::message_box_error(&format!("Error reloading data sources, message: '{}'", e))
+        }
+        self.on_selection_changed(nwg::EventData::NoData);
+    }
+
+    fn reload_diff_view(&mut self) {
+        let dv = &self.c.diff_view;
+        dv.set_redraw(false);
+        dv.clear();
+        self.visible_rows.clear();
+        let diff = match &self.diff {
+            Some(diff) => diff,
+            None => {
+                self.c.summary_label.set_text("");
+                dv.set_redraw(true);
+                return;
+            }
+        };
+        let include_equal = self.c.equal_checkbox.check_state() == nwg::CheckBoxState::Checked;
+        for (row_idx, row) in diff.rows.iter().enumerate() {
+            if !include_equal && DiffKind::Equal == row.kind {
+                continue;
+            }
+            let idx = self.visible_rows.len() as i32;
+            let cells = [
+                row.name.as_str(),
+                row.left_value(),
+                row.right_value(),
+                row.default_value.as_deref().unwrap_or(""),
+                row.kind.label(),
+            ];
+            for (col_idx, cell) in cells.iter().enumerate() {
+                dv.insert_item(nwg::InsertListViewItem {
+                    index: Some(idx),
+                    column_index: col_idx as i32,
+                    text: Some(cell.to_string()),
+                    image: None
+                });
+            }
+            self.visible_rows.push(row_idx);
+        }
+        dv.set_redraw(true);
+        self.c.summary_label.set_text(&diff.summary());
+    }
+}
+
+impl ui::PopupDialog<CompareDialogArgs, CompareDialogResult> for CompareDialog {
+    fn popup(args: CompareDialogArgs) -> ui::PopupJoinHandle<CompareDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        let columns = [("Setting", 180), ("Left", 170), ("Right", 170), ("Default", 110), ("Difference", 80)];
+        for (idx, (name, width)) in columns.iter().enumerate() {
+            self.c.diff_view.insert_column(nwg::InsertListViewColumn{
+                index: Some(idx as i32),
+                fmt: Some(nwg::ListViewColumnFlags::LEFT),
+                width: Some(*width),
+                text: Some(name.to_string())
+            });
+        }
+        let labels: Vec<String> = self.args.dsns.iter()
+            .map(|d| d.display_name())
+            .collect();
+        let left_idx = labels.iter()
+            .position(|l| *l == self.args.left)
+            .unwrap_or(0);
+        let right_idx = if left_idx + 1 < labels.len() { left_idx + 1 } else { 0 };
+        self.c.left_combo.set_collection(labels.clone());
+        self.c.right_combo.set_collection(labels);
+        if !self.args.dsns.is_empty() {
+            self.c.left_combo.set_selection(Some(left_idx));
+            self.c.right_combo.set_selection(Some(right_idx));
+        }
+        self.on_selection_changed(nwg::EventData::NoData);
+    }
+
+    fn result(&mut self) -> CompareDialogResult {
+        CompareDialogResult::new(self.changed)
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct CompareDialogEvents {
+    pub(super) events: Vec<ui::Event<CompareDialog>>
+}
+
+impl ui::Events<CompareDialogControls> for CompareDialogEvents {
+    fn build(&mut self, c: &CompareDialogControls) -> Result<(), nwg::NwgError> {
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowClose)
+            .handler(CompareDialog::close)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnResizeEnd)
+            .handler(CompareDialog::on_resize)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.left_combo)
+            .event(nwg::Event::OnComboxBoxSelection)
+            .handler(CompareDialog::on_selection_changed)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.right_combo)
+            .event(nwg::Event::OnComboxBoxSelection)
+            .handler(CompareDialog::on_selection_changed)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.equal_checkbox)
+            .event(nwg::Event::OnButtonClick)
+            .handler(CompareDialog::on_selection_changed)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.copy_to_right_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(CompareDialog::on_copy_to_right)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.copy_to_left_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(CompareDialog::on_copy_to_left)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.copy_clipboard_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(CompareDialog::copy_to_clipboard)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.close_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(CompareDialog::close)
+            .build(&mut self.events)?;
+
+        Ok(())
+    }
+}
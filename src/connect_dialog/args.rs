@@ -0,0 +1,42 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub struct ConnectDialogArgs {
+    notice_sender:  ui::SyncNoticeSender,
+    pub(super) dsn_name: String,
+}
+
+impl ConnectDialogArgs {
+    pub fn new(notice: &ui::SyncNotice, dsn_name: &str) -> Self {
+        Self {
+            notice_sender: notice.sender(),
+            dsn_name: dsn_name.to_string(),
+        }
+    }
+
+    pub(super) fn conn_str(&self) -> String {
+        format!("DSN={{{}}};", self.dsn_name)
+    }
+}
+
+impl ui::PopupArgs for ConnectDialogArgs {
+    fn notify_parent(&self) {
+        self.notice_sender.send()
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub struct ConnectDialog {
+    pub(super) c: ConnectDialogControls,
+
+    args: ConnectDialogArgs,
+    effective_settings: ConnectDialogResult,
+    connect_join_handle: ui::PopupJoinHandle<ConnectResult>,
+}
+
+impl ConnectDialog {
+    pub fn on_connect_complete(&mut self, _: nwg::EventData) {
+        self.c.complete_notice.receive();
+        let res = self.connect_join_handle.join();
+        self.stop_progress_bar(res.success);
+        self.effective_settings = ConnectDialogResult::new(res.effective_settings);
+        let label = if res.success {
+            "Connection successful"
+        } else {
+            "Connection failed"
+        };
+        self.c.label.set_text(label);
+        self.c.details_box.set_text(&res.message);
+        self.c.copy_clipboard_button.set_enabled(true);
+        self.c.close_button.set_enabled(true);
+    }
+
+    pub fn copy_to_clipboard(&mut self, _: nwg::EventData) {
+        let text = self.c.details_box.text();
+        let _ = set_clipboard(formats::Unicode, &text);
+    }
+
+    pub fn stop_progress_bar(&self, success: bool) {
+        self.c.progress_bar.set_marquee(false, 0);
+        self.c.progress_bar.remove_flags(nwg::ProgressBarFlags::MARQUEE);
+        self.c.progress_bar.set_pos(1);
+        if !success {
+            self.c.progress_bar.set_state(nwg::ProgressBarState::Error)
+        }
+    }
+
+    fn test_connection(conn_str: &str) -> Result<(String, Vec<DuckDbSetting>), ConfigError> {
+        let start = Instant::now();
+        let conn = OdbcConnection::connect(conn_str)?;
+        let connect_millis = start.elapsed().as_millis();
+        let query_start = Instant::now();
+        let rows = conn.query("SELECT version()")?;
+        let query_millis = query_start.elapsed().as_millis();
+        let version = rows.first()
+            .and_then(|row| row.first().cloned())
+            .flatten()
+            .unwrap_or_default();
+        let message = format!("Connection string: {}\r\nDuckDB version: {}\r\nConnect time: {} ms\r\nQuery time: {} ms",
+            conn_str, version, connect_millis, query_millis);
+        let settings = match Self::effective_settings(&conn) {
+            Ok(settings) => settings,
+            Err(e) => return Ok((format!("{}\r\n\r\nError reading effective settings: {}", message, e), vec!()))
+        };
+        Ok((message, settings))
+    }
+
+    fn effective_settings(conn: &OdbcConnection) -> Result<Vec<DuckDbSetting>, ConfigError> {
+        let rows = conn.query("SELECT name, value, input_type, scope, description FROM duckdb_settings()")?;
+        let res = rows.into_iter()
+            .filter(|row| 5 == row.len())
+            .map(|row| {
+                let fields: Vec<String> = row.into_iter()
+                    .map(|field| field.unwrap_or_default())
+                    .collect();
+                let mut st = DuckDbSetting::new(&fields[0], "", &fields[2], &fields[3], &fields[4]);
+                st.effective_value = Some(fields[1].clone());
+                st
+            })
+            .collect();
+        Ok(res)
+    }
+}
+
+impl ui::PopupDialog<ConnectDialogArgs, ConnectDialogResult> for ConnectDialog {
+    fn popup(args: ConnectDialogArgs) -> ui::PopupJoinHandle<ConnectDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        self.c.label.set_text(&format!("Connecting to '{}' ...", self.args.dsn_name));
+        let sender = self.c.complete_notice.sender();
+        let conn_str = self.args.conn_str();
+        let join_handle = thread::spawn(move || {
+            let res = match ConnectDialog::test_connection(&conn_str) {
+                Ok((message, settings)) => ConnectResult::success(message, settings),
+                Err(e) => ConnectResult::failure(format!("{}", e))
+            };
+            sender.send();
+            res
+        });
+        self.connect_join_handle = ui::PopupJoinHandle::from(join_handle);
+    }
+
+    fn result(&mut self) -> ConnectDialogResult {
+        self.effective_settings.clone()
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
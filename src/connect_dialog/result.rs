@@ -0,0 +1,53 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct ConnectDialogResult {
+    pub effective_settings: Vec<DuckDbSetting>,
+}
+
+impl ConnectDialogResult {
+    pub fn new(effective_settings: Vec<DuckDbSetting>) -> Self {
+        Self { effective_settings }
+    }
+}
+
+#[derive(Default)]
+pub struct ConnectResult {
+    pub success: bool,
+    pub message: String,
+    pub effective_settings: Vec<DuckDbSetting>,
+}
+
+impl ConnectResult {
+    pub fn success(message: String, effective_settings: Vec<DuckDbSetting>) -> Self {
+        Self {
+            success: true,
+            message,
+            effective_settings,
+        }
+    }
+
+    pub fn failure(message: String) -> Self {
+        Self {
+            success: false,
+            message,
+            effective_settings: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub(super) struct DriverDialogControls {
+    layout: DriverDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) bitness_label: nwg::Label,
+    pub(super) bitness_combo: nwg::ComboBox<String>,
+    pub(super) name_label: nwg::Label,
+    pub(super) name_combo: nwg::ComboBox<String>,
+    pub(super) attributes_view: nwg::ListView,
+    pub(super) dll_label: nwg::Label,
+    pub(super) dll_input: nwg::TextInput,
+    pub(super) dll_button: nwg::Button,
+    pub(super) dll_chooser: nwg::FileDialog,
+
+    pub(super) register_button: nwg::Button,
+    pub(super) unregister_button: nwg::Button,
+    pub(super) close_button: nwg::Button,
+}
+
+impl ui::Controls for DriverDialogControls {
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((480, 350))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("DuckDB Driver")
+            .build(&mut self.window)?;
+
+        nwg::Label::builder()
+            .text("Platform:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.bitness_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .collection(vec!(
+                Bitness::X64.label().to_string(),
+                Bitness::X86.label().to_string(),
+            ))
+            .selected_index(Some(0))
+            .parent(&self.window)
+            .build(&mut self.bitness_combo)?;
+
+        nwg::Label::builder()
+            .text("Driver name:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.name_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.name_combo)?;
+
+        nwg::ListView::builder()
+            .parent(&self.window)
+            .item_count(8)
+            .list_style(nwg::ListViewStyle::Detailed)
+            .ex_flags(nwg::ListViewExFlags::GRID | nwg::ListViewExFlags::FULL_ROW_SELECT)
+            .build(&mut self.attributes_view)?;
+        self.attributes_view.set_headers_enabled(true);
+        self.attributes_view.insert_column(nwg::InsertListViewColumn{
+            index: Some(0),
+            fmt: Some(nwg::ListViewColumnFlags::LEFT),
+            width: Some(130),
+            text: Some("Attribute".to_string())
+        });
+        self.attributes_view.insert_column(nwg::InsertListViewColumn{
+            index: Some(1),
+            fmt: Some(nwg::ListViewColumnFlags::LEFT),
+            width: Some(220),
+            text: Some("Value".to_string())
+        });
+        self.attributes_view.insert_column(nwg::InsertListViewColumn{
+            index: Some(2),
+            fmt: Some(nwg::ListViewColumnFlags::LEFT),
+            width: Some(90),
+            text: Some("Value type".to_string())
+        });
+
+        nwg::Label::builder()
+            .text("Driver DLL:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.dll_label)?;
+        nwg::TextInput::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.dll_input)?;
+        nwg::Button::builder()
+            .text("Choose")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.dll_button)?;
+        nwg::FileDialog::builder()
+            .title("Choose driver DLL")
+            .action(nwg::FileDialogAction::Open)
+            .filters("DLL(*.dll)|Any(*.*)")
+            .build(&mut self.dll_chooser)?;
+
+        nwg::Button::builder()
+            .text("Register / Repair")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.register_button)?;
+        nwg::Button::builder()
+            .text("Unregister")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.unregister_button)?;
+        nwg::Button::builder()
+            .text("Close")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.close_button)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.bitness_combo)
+            .control(&self.name_combo)
+            .control(&self.attributes_view)
+            .control(&self.dll_input)
+            .control(&self.dll_button)
+            .control(&self.register_button)
+            .control(&self.unregister_button)
+            .control(&self.close_button)
+            .build();
+    }
+}
@@ -0,0 +1,177 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub struct DriverDialog {
+    pub(super) c: DriverDialogControls,
+
+    args: DriverDialogArgs,
+    result: DriverDialogResult,
+}
+
+impl DriverDialog {
+    pub(super) fn on_bitness_changed(&mut self, _: nwg::EventData) {
+        self.reload_names(None);
+        self.reload_attributes();
+    }
+
+    pub(super) fn on_name_changed(&mut self, _: nwg::EventData) {
+        self.reload_attributes();
+    }
+
+    pub(super) fn on_choose_dll_file(&mut self, _: nwg::EventData) {
+        if self.c.dll_chooser.run(Some(&self.c.window)) {
+            if let Ok(file) = self.c.dll_chooser.get_selected_item() {
+                let fpath_st = file.to_string_lossy().to_string();
+                self.c.dll_input.set_text(&fpath_st);
+            }
+        }
+    }
+
+    pub(super) fn on_register_button(&mut self, _: nwg::EventData) {
+        let dll_path = self.c.dll_input.text().trim().to_string();
+        if dll_path.is_empty() {
+            return;
+        }
+        let bitness = self.bitness();
+        let name = self.driver_name();
+        let confirmed = ui::message_box_warning_yn(&format!(
+            "'{}' will be registered in the {} registry view using DLL: '{}', its usage count will be \
+            incremented if it is already registered. Would you like to proceed?",
+            name, bitness.label(), dll_path));
+        if !confirmed {
+            return;
+        }
+        match registry::register_driver(&name, &bitness, &dll_path) {
+            Ok(_) => self.result = DriverDialogResult::changed(),
+            Err(e) => ui::message_box_error(&format!(
+                "Error registering driver, platform: {}, DLL path: '{}', message: {}", bitness.label(), dll_path, e))
+        }
+        self.reload_names(Some(&name));
+        self.reload_attributes();
+    }
+
+    pub(super) fn on_unregister_button(&mut self, _: nwg::EventData) {
+        let bitness = self.bitness();
+        let name = self.driver_name();
+        let confirmed = ui::message_box_warning_yn(&format!(
+            "Usage count of '{}' in the {} registry view will be decremented, driver entry will be removed \
+            when it reaches zero. Would you like to proceed?", name, bitness.label()));
+        if !confirmed {
+            return;
+        }
+        match registry::unregister_driver(&name, &bitness) {
+            Ok(_) => self.result = DriverDialogResult::changed(),
+            Err(e) => ui::message_box_error(&format!(
+                "Error unregistering driver, platform: {}, message: {}", bitness.label(), e))
+        }
+        self.reload_names(Some(&name));
+        self.reload_attributes();
+    }
+
+    fn bitness(&self) -> Bitness {
+        if Some(Bitness::X86.label().to_string()) == self.c.bitness_combo.selection_string() {
+            Bitness::X86
+        } else {
+            Bitness::X64
+        }
+    }
+
+    fn driver_name(&self) -> String {
+        self.c.name_combo.selection_string().unwrap_or_else(|| registry::DRIVER_NAME.to_string())
+    }
+
+    // Default driver name is always offered so it can be registered
+    // when no DuckDB drivers are installed in the selected view.
+    fn reload_names(&mut self, selected: Option<&str>) {
+        let bitness = self.bitness();
+        let mut names: Vec<String> = registry::list_duckdb_drivers().unwrap_or_default().into_iter()
+            .filter(|d| d.bitness == bitness)
+            .map(|d| d.name)
+            .collect();
+        if !names.iter().any(|n| registry::DRIVER_NAME == n) {
+            names.insert(0, registry::DRIVER_NAME.to_string());
+        }
+        let idx = selected
+            .and_then(|sel| names.iter().position(|n| n == sel))
+            .unwrap_or(0);
+        self.c.name_combo.set_collection(names);
+        self.c.name_combo.set_selection(Some(idx));
+    }
+
+    fn reload_attributes(&mut self) {
+        let av = &self.c.attributes_view;
+        av.clear();
+        let attributes = registry::driver_attributes(&self.driver_name(), &self.bitness()).unwrap_or_default();
+        let mut idx = 0;
+        for name in registry::DRIVER_ATTRIBUTE_NAMES.iter() {
+            let (value, value_type) = match attributes.iter().find(|rs| rs.name == *name) {
+                Some(rs) => (rs.value.clone(), rs.value_type.label().to_string()),
+                None => ("<missing>".to_string(), "".to_string())
+            };
+            av.insert_items_row(Some(idx), &[name.to_string(), value, value_type]);
+            idx += 1;
+        }
+        for rs in attributes.iter().filter(|rs| !registry::DRIVER_ATTRIBUTE_NAMES.contains(&rs.name.as_str())) {
+            av.insert_items_row(Some(idx), &[rs.name.clone(), rs.value.clone(), rs.value_type.label().to_string()]);
+            idx += 1;
+        }
+        let driver_path = attributes.iter()
+            .find(|rs| registry::DRIVER_SETTING_NAME == rs.name)
+            .map(|rs| rs.value.clone())
+            .unwrap_or_default();
+        self.c.dll_input.set_text(&driver_path);
+        self.c.unregister_button.set_enabled(!attributes.is_empty());
+    }
+}
+
+impl ui::PopupDialog<DriverDialogArgs, DriverDialogResult> for DriverDialog {
+    fn popup(args: DriverDialogArgs) -> ui::PopupJoinHandle<DriverDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        self.result = DriverDialogResult::unchanged();
+        self.reload_names(None);
+        self.reload_attributes();
+        ui::shake_window(&self.c.window);
+    }
+
+    fn result(&mut self) -> DriverDialogResult {
+        self.result.clone()
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
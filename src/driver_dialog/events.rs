@@ -0,0 +1,72 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub(super) struct DriverDialogEvents {
+    pub(super) events: Vec<ui::Event<DriverDialog>>
+}
+
+impl ui::Events<DriverDialogControls> for DriverDialogEvents {
+    fn build(&mut self, c: &DriverDialogControls) -> Result<(), nwg::NwgError> {
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowClose)
+            .handler(DriverDialog::close)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnResizeEnd)
+            .handler(DriverDialog::on_resize)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.bitness_combo)
+            .event(nwg::Event::OnComboxBoxSelection)
+            .handler(DriverDialog::on_bitness_changed)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.name_combo)
+            .event(nwg::Event::OnComboxBoxSelection)
+            .handler(DriverDialog::on_name_changed)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.dll_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(DriverDialog::on_choose_dll_file)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.register_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(DriverDialog::on_register_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.unregister_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(DriverDialog::on_unregister_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.close_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(DriverDialog::close)
+            .build(&mut self.events)?;
+
+        Ok(())
+    }
+}
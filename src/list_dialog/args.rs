@@ -0,0 +1,41 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct ListDialogArgs {
+    notice_sender:  ui::SyncNoticeSender,
+    pub(super) setting: String,
+    pub(super) list_setting: Option<ListSetting>,
+    pub(super) value: String,
+}
+
+impl ListDialogArgs {
+    pub fn new(notice: &ui::SyncNotice, setting: &str, list_setting: &ListSetting, value: &str) -> Self {
+        Self {
+            notice_sender: notice.sender(),
+            setting: setting.to_string(),
+            list_setting: Some(list_setting.clone()),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl ui::PopupArgs for ListDialogArgs {
+    fn notify_parent(&self) {
+        self.notice_sender.send()
+    }
+}
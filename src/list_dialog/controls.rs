@@ -0,0 +1,183 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct ListDialogControls {
+    layout: ListDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) items_box: nwg::ListBox<String>,
+    pub(super) remove_button: nwg::Button,
+    pub(super) up_button: nwg::Button,
+    pub(super) down_button: nwg::Button,
+
+    pub(super) item_label: nwg::Label,
+    pub(super) item_input: nwg::TextInput,
+    pub(super) choose_button: nwg::Button,
+    pub(super) add_button: nwg::Button,
+    pub(super) dir_chooser: nwg::FileDialog,
+    pub(super) file_chooser: nwg::FileDialog,
+
+    pub(super) known_label: nwg::Label,
+    pub(super) known_combo: nwg::ComboBox<&'static str>,
+    pub(super) add_known_button: nwg::Button,
+
+    pub(super) value_label: nwg::Label,
+    pub(super) value_input: nwg::TextInput,
+
+    pub(super) ok_button: nwg::Button,
+    pub(super) close_button: nwg::Button,
+}
+
+impl ui::Controls for ListDialogControls {
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((480, 380))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("Edit List Value")
+            .build(&mut self.window)?;
+
+        nwg::ListBox::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.items_box)?;
+        nwg::Button::builder()
+            .text("Remove")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.remove_button)?;
+        nwg::Button::builder()
+            .text("Move up")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.up_button)?;
+        nwg::Button::builder()
+            .text("Move down")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.down_button)?;
+
+        nwg::Label::builder()
+            .text("New item:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.item_label)?;
+        nwg::TextInput::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.item_input)?;
+        nwg::Button::builder()
+            .text("Choose")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.choose_button)?;
+        nwg::Button::builder()
+            .text("Add")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.add_button)?;
+        nwg::FileDialog::builder()
+            .title("Choose directory")
+            .action(nwg::FileDialogAction::OpenDirectory)
+            .build(&mut self.dir_chooser)?;
+        nwg::FileDialog::builder()
+            .title("Choose file")
+            .action(nwg::FileDialogAction::Open)
+            .build(&mut self.file_chooser)?;
+
+        nwg::Label::builder()
+            .text("Known value:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.known_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.known_combo)?;
+        nwg::Button::builder()
+            .text("Add")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.add_known_button)?;
+
+        nwg::Label::builder()
+            .text("Value:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.value_label)?;
+        nwg::TextInput::builder()
+            .readonly(true)
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.value_input)?;
+
+        nwg::Button::builder()
+            .text("Use value")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.ok_button)?;
+        nwg::Button::builder()
+            .text("Cancel")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.close_button)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.items_box)
+            .control(&self.remove_button)
+            .control(&self.up_button)
+            .control(&self.down_button)
+            .control(&self.item_input)
+            .control(&self.choose_button)
+            .control(&self.add_button)
+            .control(&self.known_combo)
+            .control(&self.add_known_button)
+            .control(&self.value_input)
+            .control(&self.ok_button)
+            .control(&self.close_button)
+            .build();
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct ListDialog {
+    pub(super) c: ListDialogControls,
+
+    args: ListDialogArgs,
+    list_setting: Option<ListSetting>,
+    items: Vec<String>,
+    result: ListDialogResult,
+}
+
+impl ListDialog {
+    pub(super) fn on_item_selected(&mut self, _: nwg::EventData) {
+        self.update_buttons_state();
+    }
+
+    pub(super) fn on_remove_button(&mut self, _: nwg::EventData) {
+        if let Some(idx) = self.c.items_box.selection() {
+            self.items.remove(idx);
+            let selected = if idx < self.items.len() {
+                Some(idx)
+            } else {
+                idx.checked_sub(1)
+            };
+            self.reload_items(selected);
+        }
+    }
+
+    pub(super) fn on_up_button(&mut self, _: nwg::EventData) {
+        if let Some(idx) = self.c.items_box.selection() {
+            if idx > 0 {
+                self.items.swap(idx, idx - 1);
+                self.reload_items(Some(idx - 1));
+            }
+        }
+    }
+
+    pub(super) fn on_down_button(&mut self, _: nwg::EventData) {
+        if let Some(idx) = self.c.items_box.selection() {
+            if idx + 1 < self.items.len() {
+                self.items.swap(idx, idx + 1);
+                self.reload_items(Some(idx + 1));
+            }
+        }
+    }
+
+    pub(super) fn on_choose_button(&mut self, _: nwg::EventData) {
+        let chooser = match self.list_setting.as_ref().map(|ls| ls.item_kind) {
+            Some(ListItemKind::Directory) => &self.c.dir_chooser,
+            Some(ListItemKind::File) => &self.c.file_chooser,
+            _ => return
+        };
+        if let Ok(dir) = std::env::current_dir() {
+            if let Some(d) = dir.to_str() {
+                let _ = chooser.set_default_folder(d);
+            }
+        }
+        if chooser.run(Some(&self.c.window)) {
+            if let Ok(file) = chooser.get_selected_item() {
+                let item = file.to_string_lossy().to_string();
+                self.add_item(&item);
+            }
+        }
+    }
+
+    pub(super) fn on_add_button(&mut self, _: nwg::EventData) {
+        let item = self.c.item_input.text().trim().to_string();
+        if self.add_item(&item) {
+            self.c.item_input.set_text("");
+        }
+    }
+
+    pub(super) fn on_add_known_button(&mut self, _: nwg::EventData) {
+        if let Some(item) = self.c.known_combo.selection_string() {
+            self.add_item(&item);
+        }
+    }
+
+    pub(super) fn on_ok_button(&mut self, _: nwg::EventData) {
+        self.result = ListDialogResult::success(&self.c.value_input.text());
+        self.close(nwg::EventData::NoData);
+    }
+
+    fn add_item(&mut self, item: &str) -> bool {
+        let format = match &self.list_setting {
+            Some(ls) => ls.format,
+            None => return false
+        };
+        if let Err(e) = list_value::check_item(item, format) {
+            ui::message_box_error(&e.to_string());
+            return false;
+        }
+        if self.items.iter().any(|it| it == item) {
+            ui::message_box_error(&format!("List already contains item: '{}'", item));
+            return false;
+        }
+        self.items.push(item.to_string());
+        self.reload_items(Some(self.items.len() - 1));
+        true
+    }
+
+    fn reload_items(&mut self, selected: Option<usize>) {
+        self.c.items_box.set_collection(self.items.clone());
+        self.c.items_box.set_selection(selected);
+        if let Some(ls) = &self.list_setting {
+            self.c.value_input.set_text(&list_value::render_list(&self.items, ls.format));
+        }
+        self.update_buttons_state();
+    }
+
+    fn update_buttons_state(&self) {
+        let selected = self.c.items_box.selection();
+        self.c.remove_button.set_enabled(selected.is_some());
+        self.c.up_button.set_enabled(matches!(selected, Some(idx) if idx > 0));
+        self.c.down_button.set_enabled(matches!(selected, Some(idx) if idx + 1 < self.items.len()));
+    }
+}
+
+impl ui::PopupDialog<ListDialogArgs, ListDialogResult> for ListDialog {
+    fn popup(args: ListDialogArgs) -> ui::PopupJoinHandle<ListDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        self.c.window.set_text(&format!("Edit List Value: {}", self.args.setting));
+        self.list_setting = self.args.list_setting.clone();
+        let ls = match &self.list_setting {
+            Some(ls) => ls.clone(),
+            None => return
+        };
+        self.c.choose_button.set_enabled(ListItemKind::Text != ls.item_kind);
+        self.c.known_combo.set_collection(ls.known_values.to_vec());
+        self.c.known_combo.set_enabled(!ls.known_values.is_empty());
+        self.c.add_known_button.set_enabled(!ls.known_values.is_empty());
+        self.items = match list_value::parse_list(&self.args.value, ls.format) {
+            Ok(items) => items,
+            Err(e) => {
+                ui::message_box_error(&format!(
+                    "Current value cannot be parsed as a list, it will be replaced:\r\n\r\n{}", e));
+                vec!()
+            }
+        };
+        self.reload_items(None);
+        self.result = ListDialogResult::cancelled();
+    }
+
+    fn result(&mut self) -> ListDialogResult {
+        self.result.clone()
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct ListDialogEvents {
+    pub(super) events: Vec<ui::Event<ListDialog>>
+}
+
+impl ui::Events<ListDialogControls> for ListDialogEvents {
+    fn build(&mut self, c: &ListDialogControls) -> Result<(), nwg::NwgError> {
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowClose)
+            .handler(ListDialog::close)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnResizeEnd)
+            .handler(ListDialog::on_resize)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.items_box)
+            .event(nwg::Event::OnListBoxSelect)
+            .handler(ListDialog::on_item_selected)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.remove_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::on_remove_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.up_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::on_up_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.down_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::on_down_button)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.choose_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::on_choose_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.add_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::on_add_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.add_known_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::on_add_known_button)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.ok_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::on_ok_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.close_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ListDialog::close)
+            .build(&mut self.events)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+#[derive(Default, Clone)]
+pub struct ListDialogResult {
+    pub value: Option<String>
+}
+
+impl ListDialogResult {
+    pub fn success(value: &str) -> Self {
+        Self {
+            value: Some(value.to_string())
+        }
+    }
+
+    pub fn cancelled() -> Self {
+        Self {
+            value: None
+        }
+    }
+}
@@ -54,31 +54,6 @@ impl LoadDsnsDialog {
             self.c.progress_bar.set_state(nwg::ProgressBarState::Error)
         }
     }
-
-    fn load_dsns_from_registry() -> Result<Vec<RegistryDsn>, ConfigError> {
-        let duckdb_driver_path = registry::duckdb_driver_path()?;
-        let system_dsns = registry::list_subkeys(registry::Root::HKLM, registry::ODBC_INI_SUBPATH)?;
-        let mut res: Vec <RegistryDsn> = vec!();
-        for name in system_dsns {
-            let dsn_subpath = format!("{}\\{}", registry::ODBC_INI_SUBPATH, name);
-            let settings = registry::list_values(registry::Root::HKLM, &dsn_subpath)?;
-            if settings.iter().any(|rs| "Driver" == rs.name && duckdb_driver_path == rs.value) {
-                let dsn_type = DsnType::SYSTEM;
-                res.push(RegistryDsn { name, dsn_type, settings })
-            }
-        }
-        if let Ok(user_dsns) = registry::list_subkeys(registry::Root::HKCU, registry::ODBC_INI_SUBPATH) {
-            for name in user_dsns {
-                let dsn_subpath = format!("{}\\{}", registry::ODBC_INI_SUBPATH, name);
-                let settings = registry::list_values(registry::Root::HKCU, &dsn_subpath)?;
-                if settings.iter().any(|rs| "Driver" == rs.name && duckdb_driver_path == rs.value) {
-                    let dsn_type = DsnType::USER;
-                    res.push(RegistryDsn { name, dsn_type, settings })
-                }
-            }
-        }
-        Ok(res)
-    }
 }
 
 impl ui::PopupDialog<LoadDsnsDialogArgs, LoadDsnsDialogResult> for LoadDsnsDialog {
@@ -99,7 +74,7 @@ impl ui::PopupDialog<LoadDsnsDialogArgs, LoadDsnsDialogResult> for LoadDsnsDialo
         let sender = self.c.load_notice.sender();
         let join_handle = thread::spawn(move || {
             let start = Instant::now();
-            let res = match LoadDsnsDialog::load_dsns_from_registry() {
+            let res = match registry::load_duckdb_dsns() {
                 Ok(dsns) => LoadDsnsResult::success(dsns),
                 Err(e) => LoadDsnsResult::failure(format!("{}", e))
             };
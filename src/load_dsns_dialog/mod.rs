@@ -39,8 +39,6 @@ use ui::PopupArgs;
 use ui::PopupDialog;
 
 pub use args::LoadDsnsDialogArgs;
-use common::ConfigError;
-use common::DsnType;
 use common::RegistryDsn;
 use common::registry;
 pub(self) use controls::LoadDsnsDialogControls;
@@ -16,16 +16,34 @@
 
 #![windows_subsystem = "windows"]
 
+mod cli;
 mod common;
 mod about_dialog;
 mod add_dsn_dialog;
 mod app_window;
+mod bulk_dialog;
+mod compare_dialog;
+mod connect_dialog;
+mod driver_dialog;
+mod list_dialog;
 mod load_dsns_dialog;
+mod matrix_dialog;
+mod new_db_dialog;
+mod report_dialog;
+mod search_dialog;
 mod setting_dialog;
 
+use std::env;
+use std::process;
+
 use nwg::NativeUi;
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        process::exit(cli::run(&args));
+    }
+
     nwg::init().expect("Failed to init Native Windows GUI");
     nwg::Font::set_global_family("Segoe UI").expect("Failed to set default font");
 
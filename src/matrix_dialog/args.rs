@@ -0,0 +1,39 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct MatrixDialogArgs {
+    notice_sender:  ui::SyncNoticeSender,
+    pub(super) dsns: Vec<RegistryDsn>,
+    pub(super) filter: String,
+}
+
+impl MatrixDialogArgs {
+    pub fn new(notice: &ui::SyncNotice, dsns: &[RegistryDsn], filter: &str) -> Self {
+        Self {
+            notice_sender: notice.sender(),
+            dsns: dsns.to_vec(),
+            filter: filter.to_string(),
+        }
+    }
+}
+
+impl ui::PopupArgs for MatrixDialogArgs {
+    fn notify_parent(&self) {
+        self.notice_sender.send()
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct MatrixDialogControls {
+    layout: MatrixDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) filter_input: nwg::TextInput,
+    pub(super) filter_button: nwg::Button,
+    pub(super) differences_checkbox: nwg::CheckBox,
+    pub(super) summary_label: nwg::Label,
+    pub(super) matrix_view: nwg::ListView,
+
+    pub(super) export_button: nwg::Button,
+    pub(super) close_button: nwg::Button,
+
+    pub(super) export_chooser: nwg::FileDialog,
+}
+
+impl ui::Controls for MatrixDialogControls {
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((800, 500))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("Settings Matrix")
+            .build(&mut self.window)?;
+
+        nwg::TextInput::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.filter_input)?;
+        nwg::Button::builder()
+            .text("Filter")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.filter_button)?;
+        nwg::CheckBox::builder()
+            .check_state(nwg::CheckBoxState::Unchecked)
+            .text("Only rows with differences")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.differences_checkbox)?;
+
+        nwg::Label::builder()
+            .text("")
+            .font(Some(&self.font_normal))
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.summary_label)?;
+
+        nwg::ListView::builder()
+            .parent(&self.window)
+            .list_style(nwg::ListViewStyle::Detailed)
+            .focus(true)
+            .ex_flags(nwg::ListViewExFlags::GRID | nwg::ListViewExFlags::FULL_ROW_SELECT)
+            .build(&mut self.matrix_view)?;
+        self.matrix_view.set_headers_enabled(true);
+
+        nwg::Button::builder()
+            .text("Export CSV")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.export_button)?;
+        nwg::Button::builder()
+            .text("Close")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.close_button)?;
+
+        nwg::FileDialog::builder()
+            .title("Export settings matrix to CSV file")
+            .action(nwg::FileDialogAction::Save)
+            .filters("CSV(*.csv)|Any(*.*)")
+            .build(&mut self.export_chooser)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.filter_input)
+            .control(&self.filter_button)
+            .control(&self.differences_checkbox)
+            .control(&self.matrix_view)
+            .control(&self.export_button)
+            .control(&self.close_button)
+            .build();
+    }
+}
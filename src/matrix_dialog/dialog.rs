@@ -0,0 +1,125 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct MatrixDialog {
+    pub(super) c: MatrixDialogControls,
+
+    args: MatrixDialogArgs,
+    matrix: SettingsMatrix,
+}
+
+impl MatrixDialog {
+    pub(super) fn on_filter_button(&mut self, _: nwg::EventData) {
+        self.reload_matrix_view();
+    }
+
+    pub(super) fn on_export_button(&mut self, _: nwg::EventData) {
+        if !self.c.export_chooser.run(Some(&self.c.window)) {
+            return;
+        }
+        let mut path = match self.c.export_chooser.get_selected_item() {
+            Ok(file) => file.to_string_lossy().to_string(),
+            Err(_) => return
+        };
+        if !path.to_lowercase().ends_with(&format!(".{}", settings_matrix::CSV_FILE_EXTENSION)) {
+            path = format!("{}.{}", path, settings_matrix::CSV_FILE_EXTENSION);
+        }
+        let (filter, only_differences) = self.filter_state();
+        let rows = self.matrix.visible_rows(&filter, only_differences);
+        match fs::write(&path, self.matrix.render_csv(&rows)) {
+            Ok(_) => self.c.summary_label.set_text(&format!("Settings matrix exported to: '{}'", path)),
+            Err(e) => ui::message_box_error(&format!(
+                "Error writing CSV file, path: '{}', message: {}", path, e))
+        }
+    }
+
+    fn filter_state(&self) -> (String, bool) {
+        let only_differences = self.c.differences_checkbox.check_state() == nwg::CheckBoxState::Checked;
+        (self.c.filter_input.text(), only_differences)
+    }
+
+    fn reload_matrix_view(&self) {
+        let mv = &self.c.matrix_view;
+        mv.set_redraw(false);
+        mv.clear();
+        let (filter, only_differences) = self.filter_state();
+        let rows = self.matrix.visible_rows(&filter, only_differences);
+        for (idx, row) in rows.iter().enumerate() {
+            let mut cells = vec!(row.name.as_str(), row.default_value.as_str());
+            cells.extend((0..self.matrix.columns.len()).map(|col_idx| row.cell(col_idx)));
+            for (col_idx, cell) in cells.iter().enumerate() {
+                mv.insert_item(nwg::InsertListViewItem {
+                    index: Some(idx as i32),
+                    column_index: col_idx as i32,
+                    text: Some(cell.to_string()),
+                    image: None
+                });
+            }
+        }
+        mv.set_redraw(true);
+        let differs_count = rows.iter().filter(|r| r.differs()).count();
+        self.c.summary_label.set_text(&format!(
+            "Data sources: {}, settings shown: {} of {}, with differences: {}",
+            self.matrix.columns.len(), rows.len(), self.matrix.rows.len(), differs_count));
+    }
+}
+
+impl ui::PopupDialog<MatrixDialogArgs, ()> for MatrixDialog {
+    fn popup(args: MatrixDialogArgs) -> ui::PopupJoinHandle<()> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        // all loaded DSNs use the same engine, catalog of the first driver found is used for all columns
+        let driver = self.args.dsns.iter().find_map(|d| d.driver.as_ref());
+        self.matrix = settings_matrix::build_matrix(&self.args.dsns, &settings_catalog(driver));
+        let mut columns = vec!(("Setting".to_string(), 200), ("Default".to_string(), 100));
+        columns.extend(self.matrix.columns.iter().map(|c| (c.clone(), 140)));
+        for (idx, (name, width)) in columns.into_iter().enumerate() {
+            self.c.matrix_view.insert_column(nwg::InsertListViewColumn{
+                index: Some(idx as i32),
+                fmt: Some(nwg::ListViewColumnFlags::LEFT),
+                width: Some(width),
+                text: Some(name)
+            });
+        }
+        self.c.filter_input.set_text(&self.args.filter);
+        self.reload_matrix_view();
+    }
+
+    fn result(&mut self) {}
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
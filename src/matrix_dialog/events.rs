@@ -0,0 +1,65 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct MatrixDialogEvents {
+    pub(super) events: Vec<ui::Event<MatrixDialog>>
+}
+
+impl ui::Events<MatrixDialogControls> for MatrixDialogEvents {
+    fn build(&mut self, c: &MatrixDialogControls) -> Result<(), nwg::NwgError> {
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowClose)
+            .handler(MatrixDialog::close)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnResizeEnd)
+            .handler(MatrixDialog::on_resize)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.filter_input)
+            .event(nwg::Event::OnKeyEnter)
+            .handler(MatrixDialog::on_filter_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.filter_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(MatrixDialog::on_filter_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.differences_checkbox)
+            .event(nwg::Event::OnButtonClick)
+            .handler(MatrixDialog::on_filter_button)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.export_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(MatrixDialog::on_export_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.close_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(MatrixDialog::close)
+            .build(&mut self.events)?;
+
+        Ok(())
+    }
+}
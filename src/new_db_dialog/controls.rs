@@ -0,0 +1,160 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct NewDbDialogControls {
+    layout: NewDbDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) driver_label: nwg::Label,
+    pub(super) driver_input: nwg::TextInput,
+    pub(super) path_label: nwg::Label,
+    pub(super) path_input: nwg::TextInput,
+    pub(super) path_button: nwg::Button,
+    pub(super) path_chooser: nwg::FileDialog,
+    pub(super) storage_label: nwg::Label,
+    pub(super) storage_combo: nwg::ComboBox<String>,
+    pub(super) block_size_label: nwg::Label,
+    pub(super) block_size_combo: nwg::ComboBox<String>,
+
+    pub(super) create_button: nwg::Button,
+    pub(super) cancel_button: nwg::Button,
+
+    pub(super) create_notice: ui::SyncNotice,
+}
+
+impl ui::Controls for NewDbDialogControls {
+
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((420, 200))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("New Database")
+            .build(&mut self.window)?;
+
+        nwg::Label::builder()
+            .text("Driver:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.driver_label)?;
+        nwg::TextInput::builder()
+            .readonly(true)
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.driver_input)?;
+
+        nwg::Label::builder()
+            .text("Database file:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.path_label)?;
+        nwg::TextInput::builder()
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.path_input)?;
+        nwg::Button::builder()
+            .text("Choose")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.path_button)?;
+        nwg::FileDialog::builder()
+            .title("New database file")
+            .action(nwg::FileDialogAction::Save)
+            .filters("DuckDB(*.duckdb)|Any(*.*)")
+            .build(&mut self.path_chooser)?;
+
+        nwg::Label::builder()
+            .text("Storage version:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.storage_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .collection(NewDbDialog::STORAGE_VERSIONS.iter().map(|v| v.to_string()).collect())
+            .selected_index(Some(0))
+            .parent(&self.window)
+            .build(&mut self.storage_combo)?;
+
+        nwg::Label::builder()
+            .text("Block size:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.block_size_label)?;
+        nwg::ComboBox::builder()
+            .font(Some(&self.font_normal))
+            .collection(NewDbDialog::BLOCK_SIZES.iter().map(|v| v.to_string()).collect())
+            .selected_index(Some(0))
+            .parent(&self.window)
+            .build(&mut self.block_size_combo)?;
+
+        nwg::Button::builder()
+            .text("Create")
+            .font(Some(&self.font_normal))
+            .enabled(false)
+            .parent(&self.window)
+            .build(&mut self.create_button)?;
+        nwg::Button::builder()
+            .text("Cancel")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.cancel_button)?;
+
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.create_notice)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.path_input)
+            .control(&self.path_button)
+            .control(&self.storage_combo)
+            .control(&self.block_size_combo)
+            .control(&self.create_button)
+            .control(&self.cancel_button)
+            .build();
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct NewDbDialog {
+    pub(super) c: NewDbDialogControls,
+
+    args: NewDbDialogArgs,
+    result: NewDbDialogResult,
+    creating: bool,
+    create_join_handle: ui::PopupJoinHandle<CreateResult>,
+}
+
+impl NewDbDialog {
+    pub(super) const DEFAULT_OPTION: &'static str = "(driver default)";
+    pub(super) const STORAGE_VERSIONS: [&'static str; 8] = [
+        Self::DEFAULT_OPTION, "v0.10.0", "v1.0.0", "v1.1.0", "v1.2.0", "v1.3.0", "v1.4.0", "latest"];
+    pub(super) const BLOCK_SIZES: [&'static str; 6] = [
+        Self::DEFAULT_OPTION, "16384", "32768", "65536", "131072", "262144"];
+
+    pub(super) fn on_path_input(&mut self, _: nwg::EventData) {
+        let has_path = !self.c.path_input.text().trim().is_empty();
+        self.c.create_button.set_enabled(has_path);
+    }
+
+    pub(super) fn on_choose_path(&mut self, _: nwg::EventData) {
+        if let Ok(dir) = std::env::current_dir() {
+            if let Some(d) = dir.to_str() {
+                let _ = self.c.path_chooser.set_default_folder(d);
+            }
+        }
+        if self.c.path_chooser.run(Some(&self.c.window)) {
+            if let Ok(file) = self.c.path_chooser.get_selected_item() {
+                let fpath_st = db_path::with_db_extension(&file.to_string_lossy());
+                self.c.path_input.set_text(&fpath_st);
+                self.on_path_input(nwg::EventData::NoData);
+            }
+        }
+    }
+
+    pub(super) fn on_create_button(&mut self, _: nwg::EventData) {
+        let path = db_path::with_db_extension(&self.c.path_input.text());
+        self.c.path_input.set_text(&path);
+        let check = db_path::check_database_path(&path, false);
+        if db_path::DbPathKind::LocalFile != check.kind && db_path::DbPathKind::UncPath != check.kind {
+            ui::message_box_error(&format!("Database file path is required, specified: '{}'", path));
+            return;
+        }
+        if let Some(abs) = check.absolute_path {
+            ui::message_box_error(&format!(
+                "Database file path must be absolute, specified: '{}', suggested: '{}'", path, abs));
+            return;
+        }
+        if !check.problems.is_empty() {
+            ui::message_box_error(&format!(
+                "Invalid database path: '{}'\r\n\r\n{}", path, check.problems.join("\r\n")));
+            return;
+        }
+        if Path::new(&path).exists() {
+            let overwrite = ui::message_box_warning_yn(&format!(
+                "File: '{}' already exists, all data in it will be lost. Would you like to overwrite it?", path));
+            if !overwrite {
+                return;
+            }
+        }
+        let driver = self.args.driver.clone();
+        let storage_version = self.selected_option(&self.c.storage_combo);
+        let block_size = self.selected_option(&self.c.block_size_combo);
+        self.set_creating(true);
+        let sender = self.c.create_notice.sender();
+        let join_handle = thread::spawn(move || {
+            let res = match db_path::create_database_file(&driver, &path, &storage_version, &block_size) {
+                Ok(()) => CreateResult::success(),
+                Err(e) => CreateResult::failure(format!("{}", e))
+            };
+            sender.send();
+            res
+        });
+        self.create_join_handle = ui::PopupJoinHandle::from(join_handle);
+    }
+
+    pub(super) fn on_create_complete(&mut self, _: nwg::EventData) {
+        self.c.create_notice.receive();
+        let res = self.create_join_handle.join();
+        self.set_creating(false);
+        let path = self.c.path_input.text();
+        if res.success {
+            self.result = NewDbDialogResult::success(&path);
+            self.close(nwg::EventData::NoData)
+        } else {
+            ui::message_box_error(&format!(
+                "Cannot create database file: '{}', driver: '{}', message: '{}'", path, self.args.driver.display_name(), res.message))
+        }
+    }
+
+    fn set_creating(&mut self, creating: bool) {
+        self.creating = creating;
+        self.c.path_input.set_readonly(creating);
+        self.c.path_button.set_enabled(!creating);
+        self.c.storage_combo.set_enabled(!creating);
+        self.c.block_size_combo.set_enabled(!creating);
+        self.c.create_button.set_enabled(!creating);
+        self.c.cancel_button.set_enabled(!creating);
+        self.c.create_button.set_text(if creating { "Creating..." } else { "Create" });
+    }
+
+    fn selected_option(&self, combo: &nwg::ComboBox<String>) -> String {
+        match combo.selection_string() {
+            Some(st) if Self::DEFAULT_OPTION != st => st,
+            _ => "".to_string()
+        }
+    }
+}
+
+impl ui::PopupDialog<NewDbDialogArgs, NewDbDialogResult> for NewDbDialog {
+    fn popup(args: NewDbDialogArgs) -> ui::PopupJoinHandle<NewDbDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        self.result = NewDbDialogResult::cancelled();
+        self.c.driver_input.set_text(&self.args.driver.display_name());
+        self.c.path_input.set_text(&self.args.path);
+        self.on_path_input(nwg::EventData::NoData);
+        ui::shake_window(&self.c.window);
+    }
+
+    fn result(&mut self) -> NewDbDialogResult {
+        self.result.clone()
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        // the driver is still writing the file
+        if self.creating {
+            return;
+        }
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct NewDbDialogEvents {
+    pub(super) events: Vec<ui::Event<NewDbDialog>>
+}
+
+impl ui::Events<NewDbDialogControls> for NewDbDialogEvents {
+    fn build(&mut self, c: &NewDbDialogControls) -> Result<(), nwg::NwgError> {
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowClose)
+            .handler(NewDbDialog::close)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnResizeEnd)
+            .handler(NewDbDialog::on_resize)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.path_input)
+            .event(nwg::Event::OnTextInput)
+            .handler(NewDbDialog::on_path_input)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.path_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(NewDbDialog::on_choose_path)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.create_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(NewDbDialog::on_create_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.cancel_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(NewDbDialog::close)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.create_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(NewDbDialog::on_create_complete)
+            .build(&mut self.events)?;
+
+        Ok(())
+    }
+}
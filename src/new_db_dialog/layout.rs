@@ -0,0 +1,145 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+const LABEL_WIDTH_PT: u32 = 90;
+
+#[derive(Default)]
+pub(super) struct NewDbDialogLayout {
+    root_layout: nwg::FlexboxLayout,
+    driver_layout: nwg::FlexboxLayout,
+    path_layout: nwg::FlexboxLayout,
+    storage_layout: nwg::FlexboxLayout,
+    block_size_layout: nwg::FlexboxLayout,
+    spacer_layout: nwg::FlexboxLayout,
+    buttons_layout: nwg::FlexboxLayout,
+}
+
+impl ui::Layout<NewDbDialogControls> for NewDbDialogLayout {
+    fn build(&self, c: &NewDbDialogControls) -> Result<(), nwg::NwgError> {
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.driver_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.driver_input)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .build_partial(&self.driver_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.path_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.path_input)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .child(&c.path_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .build_partial(&self.path_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.storage_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.storage_combo)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .build_partial(&self.storage_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.block_size_label)
+            .child_size(ui::size_builder()
+                .width_pt(LABEL_WIDTH_PT)
+                .height_input_form_row()
+                .build())
+            .child(&c.block_size_combo)
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .child_flex_grow(1.0)
+            .build_partial(&self.block_size_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .build_partial(&self.spacer_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .justify_content(ui::JustifyContent::FlexEnd)
+            .auto_spacing(None)
+            .child(&c.create_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child(&c.cancel_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
+            .build_partial(&self.buttons_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Column)
+            .child_layout(&self.driver_layout)
+            .child_layout(&self.path_layout)
+            .child_layout(&self.storage_layout)
+            .child_layout(&self.block_size_layout)
+            .child_layout(&self.spacer_layout)
+            .child_flex_grow(1.0)
+            .child_layout(&self.buttons_layout)
+            .build(&self.root_layout)?;
+
+        Ok(())
+    }
+}
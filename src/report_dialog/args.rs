@@ -0,0 +1,58 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub struct ReportDialogArgs {
+    notice_sender:  ui::SyncNoticeSender,
+    pub(super) title: String,
+    pub(super) summary: String,
+    pub(super) columns: Vec<(String, i32)>,
+    pub(super) rows: Vec<Vec<String>>,
+    pub(super) apply_label: Option<String>,
+    pub(super) toggles: Option<Vec<bool>>,
+}
+
+impl ReportDialogArgs {
+    pub fn new(notice: &ui::SyncNotice, title: &str, summary: &str, columns: Vec<(&str, i32)>,
+               rows: Vec<Vec<String>>, apply_label: Option<&str>) -> Self {
+        Self {
+            notice_sender: notice.sender(),
+            title: title.to_string(),
+            summary: summary.to_string(),
+            columns: columns.into_iter()
+                .map(|(name, width)| (name.to_string(), width))
+                .collect(),
+            rows,
+            apply_label: apply_label.map(|label| label.to_string()),
+            toggles: None,
+        }
+    }
+
+    // Adds leading "Include" column toggled with a double-click,
+    // Apply then returns toggled on rows instead of the selected ones.
+    pub fn with_toggles(mut self, toggles: Vec<bool>) -> Self {
+        self.toggles = Some(toggles);
+        self
+    }
+}
+
+impl ui::PopupArgs for ReportDialogArgs {
+    fn notify_parent(&self) {
+        self.notice_sender.send()
+    }
+}
@@ -0,0 +1,101 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub(super) struct ReportDialogControls {
+    layout: ReportDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) summary_label: nwg::Label,
+    pub(super) report_view: nwg::ListView,
+
+    pub(super) copy_clipboard_button: nwg::Button,
+    pub(super) apply_button: nwg::Button,
+    pub(super) close_button: nwg::Button,
+}
+
+impl ui::Controls for ReportDialogControls {
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((640, 420))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("Report")
+            .build(&mut self.window)?;
+
+        nwg::Label::builder()
+            .text("")
+            .font(Some(&self.font_normal))
+            .v_align(nwg::VTextAlign::Top)
+            .parent(&self.window)
+            .build(&mut self.summary_label)?;
+
+        nwg::ListView::builder()
+            .parent(&self.window)
+            .list_style(nwg::ListViewStyle::Detailed)
+            .focus(true)
+            .ex_flags(nwg::ListViewExFlags::GRID | nwg::ListViewExFlags::FULL_ROW_SELECT)
+            .build(&mut self.report_view)?;
+        self.report_view.set_headers_enabled(true);
+
+        nwg::Button::builder()
+            .text("Copy to clipboard")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.copy_clipboard_button)?;
+        nwg::Button::builder()
+            .text("Apply")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.apply_button)?;
+        nwg::Button::builder()
+            .text("Close")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.close_button)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.report_view)
+            .control(&self.copy_clipboard_button)
+            .control(&self.apply_button)
+            .control(&self.close_button)
+            .build();
+    }
+}
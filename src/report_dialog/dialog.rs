@@ -0,0 +1,158 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub struct ReportDialog {
+    pub(super) c: ReportDialogControls,
+
+    args: ReportDialogArgs,
+    result: ReportDialogResult,
+}
+
+impl ReportDialog {
+    pub(super) fn copy_to_clipboard(&mut self, _: nwg::EventData) {
+        let mut lines: Vec<String> = vec!();
+        let header: Vec<String> = self.args.columns.iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+        lines.push(header.join("\t"));
+        for row in &self.args.rows {
+            lines.push(row.join("\t"));
+        }
+        let text = lines.join("\r\n");
+        let _ = set_clipboard(formats::Unicode, &text);
+    }
+
+    pub(super) fn on_toggle_row(&mut self, ed: nwg::EventData) {
+        let row_idx = if let nwg::EventData::OnListViewItemIndex { row_index: row_idx, .. } = ed {
+            row_idx
+        } else {
+            return;
+        };
+        if let Some(toggles) = &mut self.args.toggles {
+            if row_idx < toggles.len() {
+                toggles[row_idx] = !toggles[row_idx];
+                self.c.report_view.update_item(row_idx, nwg::InsertListViewItem {
+                    index: Some(row_idx as i32),
+                    column_index: 0,
+                    text: Some(Self::toggle_label(toggles[row_idx]).to_string()),
+                    image: None
+                });
+            }
+        }
+    }
+
+    pub(super) fn on_apply_button(&mut self, _: nwg::EventData) {
+        if let Some(toggles) = &self.args.toggles {
+            let included = toggles.iter().enumerate()
+                .filter(|(_, on)| **on)
+                .map(|(idx, _)| idx)
+                .collect();
+            self.result = ReportDialogResult::applied(included);
+            self.close(nwg::EventData::NoData);
+            return;
+        }
+        let mut selected = self.c.report_view.selected_items();
+        if selected.is_empty() {
+            selected = (0..self.args.rows.len()).collect();
+        }
+        self.result = ReportDialogResult::applied(selected);
+        self.close(nwg::EventData::NoData);
+    }
+
+    fn toggle_label(on: bool) -> &'static str {
+        if on {
+            "Yes"
+        } else {
+            "No"
+        }
+    }
+
+    fn reload_report_view(&self) {
+        let rv = &self.c.report_view;
+        let mut columns = self.args.columns.clone();
+        if self.args.toggles.is_some() {
+            columns.insert(0, ("Include".to_string(), 60));
+        }
+        for (idx, (name, width)) in columns.iter().enumerate() {
+            rv.insert_column(nwg::InsertListViewColumn{
+                index: Some(idx as i32),
+                fmt: Some(nwg::ListViewColumnFlags::LEFT),
+                width: Some(*width),
+                text: Some(name.clone())
+            });
+        }
+        rv.set_redraw(false);
+        for (row_idx, row) in self.args.rows.iter().enumerate() {
+            let mut cells = row.clone();
+            if let Some(toggles) = &self.args.toggles {
+                let on = toggles.get(row_idx).cloned().unwrap_or(false);
+                cells.insert(0, Self::toggle_label(on).to_string());
+            }
+            for (col_idx, cell) in cells.iter().enumerate() {
+                rv.insert_item(nwg::InsertListViewItem {
+                    index: Some(row_idx as i32),
+                    column_index: col_idx as i32,
+                    text: Some(cell.clone()),
+                    image: None
+                });
+            }
+        }
+        rv.set_redraw(true);
+    }
+}
+
+impl ui::PopupDialog<ReportDialogArgs, ReportDialogResult> for ReportDialog {
+    fn popup(args: ReportDialogArgs) -> ui::PopupJoinHandle<ReportDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        self.result = ReportDialogResult::cancelled();
+        self.c.window.set_text(&self.args.title);
+        self.c.summary_label.set_text(&ui::wrap_label_text(&self.args.summary, 100));
+        match &self.args.apply_label {
+            Some(label) => self.c.apply_button.set_text(label),
+            None => self.c.apply_button.set_visible(false)
+        }
+        self.reload_report_view();
+    }
+
+    fn result(&mut self) -> ReportDialogResult {
+        self.result.clone()
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
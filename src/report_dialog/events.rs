@@ -0,0 +1,61 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+#[derive(Default)]
+pub(super) struct ReportDialogEvents {
+    pub(super) events: Vec<ui::Event<ReportDialog>>
+}
+
+impl ui::Events<ReportDialogControls> for ReportDialogEvents {
+    fn build(&mut self, c: &ReportDialogControls) -> Result<(), nwg::NwgError> {
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowClose)
+            .handler(ReportDialog::close)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnResizeEnd)
+            .handler(ReportDialog::on_resize)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.report_view)
+            .event(nwg::Event::OnListViewDoubleClick)
+            .handler(ReportDialog::on_toggle_row)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.copy_clipboard_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ReportDialog::copy_to_clipboard)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.apply_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ReportDialog::on_apply_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.close_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(ReportDialog::close)
+            .build(&mut self.events)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[derive(Default, Clone)]
+pub struct ReportDialogResult {
+    pub applied: bool,
+    pub selected: Vec<usize>,
+}
+
+impl ReportDialogResult {
+    pub fn applied(selected: Vec<usize>) -> Self {
+        Self {
+            applied: true,
+            selected,
+        }
+    }
+
+    pub fn cancelled() -> Self {
+        Self {
+            applied: false,
+            selected: vec!(),
+        }
+    }
+}
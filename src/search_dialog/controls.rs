@@ -0,0 +1,146 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct SearchDialogControls {
+    layout: SearchDialogLayout,
+
+    pub(super) font_normal: nwg::Font,
+
+    pub(super) icon: nwg::Icon,
+    pub(super) window: nwg::Window,
+
+    pub(super) name_label: nwg::Label,
+    pub(super) name_input: nwg::TextInput,
+    pub(super) value_label: nwg::Label,
+    pub(super) value_input: nwg::TextInput,
+    pub(super) mode_label: nwg::Label,
+    pub(super) mode_combo: nwg::ComboBox<&'static str>,
+    pub(super) search_button: nwg::Button,
+
+    pub(super) summary_label: nwg::Label,
+    pub(super) results_view: nwg::ListView,
+
+    pub(super) close_button: nwg::Button,
+
+    pub(super) setting_notice: ui::SyncNotice,
+}
+
+impl ui::Controls for SearchDialogControls {
+    fn build(&mut self) -> Result<(), nwg::NwgError> {
+        nwg::Font::builder()
+            .size(ui::font_size_builder()
+                .normal()
+                .build())
+            .build(&mut self.font_normal)?;
+
+        nwg::Icon::builder()
+            .source_embed(Some(&nwg::EmbedResource::load(None)
+                .expect("Error loading embedded resource")))
+            .source_embed_id(2)
+            .build(&mut self.icon)?;
+
+        nwg::Window::builder()
+            .size((700, 480))
+            .icon(Some(&self.icon))
+            .center(true)
+            .title("Search Data Sources")
+            .build(&mut self.window)?;
+
+        nwg::Label::builder()
+            .text("Setting:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.name_label)?;
+        nwg::TextInput::builder()
+            .placeholder_text(Some("Any setting"))
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.name_input)?;
+        nwg::Label::builder()
+            .text("Value:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.value_label)?;
+        nwg::TextInput::builder()
+            .placeholder_text(Some("Any value"))
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.value_input)?;
+        nwg::Label::builder()
+            .text("Match:")
+            .font(Some(&self.font_normal))
+            .h_align(nwg::HTextAlign::Left)
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.mode_label)?;
+        nwg::ComboBox::builder()
+            .collection(vec!(SearchMode::Wildcard.label(), SearchMode::Regex.label()))
+            .selected_index(Some(0))
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.mode_combo)?;
+        nwg::Button::builder()
+            .text("Search")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.search_button)?;
+
+        nwg::Label::builder()
+            .text("")
+            .font(Some(&self.font_normal))
+            .v_align(nwg::VTextAlign::Center)
+            .parent(&self.window)
+            .build(&mut self.summary_label)?;
+
+        nwg::ListView::builder()
+            .parent(&self.window)
+            .list_style(nwg::ListViewStyle::Detailed)
+            .ex_flags(nwg::ListViewExFlags::GRID | nwg::ListViewExFlags::FULL_ROW_SELECT)
+            .build(&mut self.results_view)?;
+        self.results_view.set_headers_enabled(true);
+
+        nwg::Button::builder()
+            .text("Close")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.close_button)?;
+
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.setting_notice)?;
+
+        self.layout.build(self)?;
+
+        Ok(())
+    }
+
+    fn update_tab_order(&self) {
+        ui::tab_order_builder()
+            .control(&self.name_input)
+            .control(&self.value_input)
+            .control(&self.mode_combo)
+            .control(&self.search_button)
+            .control(&self.results_view)
+            .control(&self.close_button)
+            .build();
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub struct SearchDialog {
+    pub(super) c: SearchDialogControls,
+
+    args: SearchDialogArgs,
+    hits: Vec<SearchHit>,
+    changed: bool,
+    setting_dialog_join_handle: ui::PopupJoinHandle<SettingDialogResult>,
+}
+
+impl SearchDialog {
+    pub(super) fn on_search_button(&mut self, _: nwg::EventData) {
+        let mode = match self.c.mode_combo.selection() {
+            Some(1) => SearchMode::Regex,
+            _ => SearchMode::Wildcard
+        };
+        match dsn_search::search_dsns(&self.args.dsns, &self.c.name_input.text(), &self.c.value_input.text(), &mode) {
+            Ok(hits) => self.hits = hits,
+            Err(e) => {
+                ui::message_box_error(&e.to_string());
+                return;
+            }
+        }
+        self.reload_results_view();
+    }
+
+    pub(super) fn open_setting_dialog(&mut self, ed: nwg::EventData) {
+        let row_idx = if let nwg::EventData::OnListViewItemIndex
+        { row_index: row_idx, .. } = ed {
+            row_idx
+        } else {
+            return;
+        };
+        let hit = match self.hits.get(row_idx) {
+            Some(hit) => hit,
+            None => return
+        };
+        let name = &hit.setting.name;
+        let setting = settings_catalog(hit.dsn.driver.as_ref()).into_iter()
+            .find(|s| s.name == *name)
+            .unwrap_or_else(|| DuckDbSetting {
+                name: name.clone(),
+                description: registry::non_engine_setting_description(name),
+                ..Default::default()
+            });
+        self.c.window.set_enabled(false);
+        let args = SettingDialogArgs::new(&self.c.setting_notice, hit.dsn.clone(), setting);
+        self.setting_dialog_join_handle = SettingDialog::popup(args);
+    }
+
+    pub(super) fn await_setting_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.setting_notice.receive();
+        let res = self.setting_dialog_join_handle.join();
+        if res.success {
+            self.changed = true;
+            match registry::load_duckdb_dsns() {
+                Ok(dsns) => self.args.dsns = dsns,
+                Err(e) => ui::message_box_error(&format!("Error reloading data sources, message: '{}'", e))
+            }
+            self.on_search_button(nwg::EventData::NoData);
+        }
+    }
+
+    fn reload_results_view(&self) {
+        let rv = &self.c.results_view;
+        rv.set_redraw(false);
+        rv.clear();
+        for (idx, hit) in self.hits.iter().enumerate() {
+            for (col_idx, cell) in hit.report_row().into_iter().enumerate() {
+                rv.insert_item(nwg::InsertListViewItem {
+                    index: Some(idx as i32),
+                    column_index: col_idx as i32,
+                    text: Some(cell),
+                    image: None
+                });
+            }
+        }
+        rv.set_redraw(true);
+        let mut dsn_names: Vec<String> = self.hits.iter().map(|h| h.dsn.display_name()).collect();
+        dsn_names.dedup();
+        self.c.summary_label.set_text(&format!(
+            "Found: {} values in {} of {} data sources", self.hits.len(), dsn_names.len(), self.args.dsns.len()));
+    }
+}
+
+impl ui::PopupDialog<SearchDialogArgs, SearchDialogResult> for SearchDialog {
+    fn popup(args: SearchDialogArgs) -> ui::PopupJoinHandle<SearchDialogResult> {
+        let join_handle = thread::spawn(move || {
+            let data = Self {
+                args,
+                ..Default::default()
+            };
+            let mut dialog = Self::build_ui(data).expect("Failed to build UI");
+            nwg::dispatch_thread_events();
+            dialog.result()
+        });
+        ui::PopupJoinHandle::from(join_handle)
+    }
+
+    fn init(&mut self) {
+        let columns = [("Data source", 170), ("Type", 60), ("Setting", 180), ("Value", 250)];
+        for (idx, (name, width)) in columns.iter().enumerate() {
+            self.c.results_view.insert_column(nwg::InsertListViewColumn{
+                index: Some(idx as i32),
+                fmt: Some(nwg::ListViewColumnFlags::LEFT),
+                width: Some(*width),
+                text: Some(name.to_string())
+            });
+        }
+        self.c.summary_label.set_text(&format!(
+            "Loaded data sources: {}, double-click a result to edit the value", self.args.dsns.len()));
+    }
+
+    fn result(&mut self) -> SearchDialogResult {
+        SearchDialogResult::new(self.changed)
+    }
+
+    fn close(&mut self, _: nwg::EventData) {
+        self.args.notify_parent();
+        self.c.window.set_visible(false);
+        nwg::stop_thread_dispatch();
+    }
+
+    fn on_resize(&mut self, _: nwg::EventData) {
+        self.c.update_tab_order();
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * Copyright 2025, DuckDB Labs
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use super::*;
+
+#[derive(Default)]
+pub(super) struct SearchDialogEvents {
+    pub(super) events: Vec<ui::Event<SearchDialog>>
+}
+
+impl ui::Events<SearchDialogControls> for SearchDialogEvents {
+    fn build(&mut self, c: &SearchDialogControls) -> Result<(), nwg::NwgError> {
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnWindowClose)
+            .handler(SearchDialog::close)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnResizeEnd)
+            .handler(SearchDialog::on_resize)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.window)
+            .event(nwg::Event::OnKeyEnter)
+            .handler(SearchDialog::on_search_button)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.search_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(SearchDialog::on_search_button)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.results_view)
+            .event(nwg::Event::OnListViewDoubleClick)
+            .handler(SearchDialog::open_setting_dialog)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.close_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(SearchDialog::close)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.setting_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(SearchDialog::await_setting_dialog)
+            .build(&mut self.events)?;
+
+        Ok(())
+    }
+}
@@ -34,11 +34,18 @@ pub(super) struct SettingDialogControls {
     pub(super) fs_path_button: nwg::Button,
     pub(super) fs_path_chooser: nwg::FileDialog,
     pub(super) bool_value_checkbox: nwg::CheckBox,
+    pub(super) expand_checkbox: nwg::CheckBox,
+    pub(super) expanded_label: nwg::Label,
     pub(super) description_label: nwg::Label,
 
+    pub(super) list_button: nwg::Button,
+    pub(super) inspect_button: nwg::Button,
     pub(super) apply_button: nwg::Button,
     pub(super) delete_button: nwg::Button,
     pub(super) close_button: nwg::Button,
+
+    pub(super) inspect_notice: ui::SyncNotice,
+    pub(super) list_notice: ui::SyncNotice,
 }
 
 impl ui::Controls for SettingDialogControls {
@@ -56,7 +63,7 @@ impl ui::Controls for SettingDialogControls {
             .build(&mut self.icon)?;
 
         nwg::Window::builder()
-            .size((480, 210))
+            .size((580, 260))
             .icon(Some(&self.icon))
             .center(true)
             .title("Change Setting")
@@ -111,11 +118,33 @@ impl ui::Controls for SettingDialogControls {
             .parent(&self.window)
             .build(&mut self.bool_value_checkbox)?;
 
+        nwg::CheckBox::builder()
+            .check_state(nwg::CheckBoxState::Unchecked)
+            .text("Expand %VAR% references (REG_EXPAND_SZ)")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.expand_checkbox)?;
+        nwg::Label::builder()
+            .flags(nwg::LabelFlags::VISIBLE | nwg::LabelFlags::ELIPSIS)
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.expanded_label)?;
+
         nwg::Label::builder()
             .font(Some(&self.font_normal))
             .parent(&self.window)
             .build(&mut self.description_label)?;
 
+        nwg::Button::builder()
+            .text("Edit list")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.list_button)?;
+        nwg::Button::builder()
+            .text("Inspect file")
+            .font(Some(&self.font_normal))
+            .parent(&self.window)
+            .build(&mut self.inspect_button)?;
         nwg::Button::builder()
             .text("Apply value")
             .font(Some(&self.font_normal))
@@ -132,6 +161,13 @@ impl ui::Controls for SettingDialogControls {
             .parent(&self.window)
             .build(&mut self.close_button)?;
 
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.inspect_notice)?;
+        ui::notice_builder()
+            .parent(&self.window)
+            .build(&mut self.list_notice)?;
+
         self.layout.build(&self)?;
 
         Ok(())
@@ -144,6 +180,9 @@ impl ui::Controls for SettingDialogControls {
             .control(&self.dsn_value_input)
             .control(&self.fs_path_button)
             .control(&self.bool_value_checkbox)
+            .control(&self.expand_checkbox)
+            .control(&self.list_button)
+            .control(&self.inspect_button)
             .control(&self.apply_button)
             .control(&self.delete_button)
             .control(&self.close_button)
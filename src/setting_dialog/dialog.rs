@@ -23,14 +23,39 @@ pub struct SettingDialog {
 
     args: SettingDialogArgs,
     result: SettingDialogResult,
+    inspect_dialog_join_handle: PopupJoinHandle<ReportDialogResult>,
+    list_dialog_join_handle: PopupJoinHandle<ListDialogResult>,
 }
 
 impl SettingDialog {
     pub(super) fn on_apply_button(&mut self, _: nwg::EventData) {
-        let value = self.c.dsn_value_input.text().trim().to_string();
+        let mut value = self.c.dsn_value_input.text().trim().to_string();
+        if registry::DATABASE_SETTING_NAME == self.args.setting.name {
+            value = match self.confirm_db_path(&value) {
+                Some(path) => path,
+                None => return
+            };
+        }
+        if let Some(ls) = self.list_setting() {
+            if let Err(e) = list_value::parse_list(&value, ls.format) {
+                ui::message_box_error(&format!("Invalid list value: '{}'\r\n\r\n{}", value, e));
+                return;
+            }
+        }
+        if let Err(e) = policy::check_change(&self.args.setting.name, Some(&value)) {
+            ui::message_box_error(&e.to_string());
+            return;
+        }
+        if !self.confirm_risky_value(&value) {
+            return;
+        }
+        if !self.confirm_conflicts(Some(&value)) {
+            return;
+        }
         let dsn = &self.args.dsn;
         let st_name = &self.args.setting.name;
-        match registry::set_dsn_value(dsn.dsn_type.clone(), &dsn.name, st_name, &value) {
+        let value_type = self.value_type();
+        match registry::set_dsn_value_with_type(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name, st_name, &value, &value_type) {
             Ok(_) => {
                 self.result = SettingDialogResult::success();
                 self.close(nwg::EventData::NoData)
@@ -43,12 +68,19 @@ impl SettingDialog {
     pub(super) fn on_delete_button(&mut self, _: nwg::EventData) {
         let dsn = &self.args.dsn;
         let st_name = &self.args.setting.name;
+        if let Err(e) = policy::check_change(st_name, None) {
+            ui::message_box_error(&e.to_string());
+            return;
+        }
+        if !self.confirm_conflicts(None) {
+            return;
+        }
         let confirmed = ui::message_box_warning_yn(&format!(
             "Value: '{}' will be deleted from DSN: '{}'. Would you like to proceed?", st_name, dsn.name));
         if !confirmed {
             return;
         }
-        match registry::delete_dsn_value(dsn.dsn_type.clone(), &dsn.name, st_name) {
+        match registry::delete_dsn_value(dsn.dsn_type.clone(), &dsn.bitness, &dsn.name, st_name) {
             Ok(_) => {
                 self.result = SettingDialogResult::success();
                 self.close(nwg::EventData::NoData)
@@ -58,6 +90,36 @@ impl SettingDialog {
         }
     }
 
+    pub(super) fn on_value_input(&mut self, _: nwg::EventData) {
+        let value_type = self.value_type();
+        let risky = security::risky_setting(&self.args.setting.name)
+            .filter(|rs| rs.is_risky_value(&self.c.dsn_value_input.text()));
+        let conflicts = consistency::check_change(
+            &self.args.dsn, &self.args.setting.name, Some(self.c.dsn_value_input.text().trim()));
+        if let Some(rs) = risky {
+            self.c.expanded_label.set_text(&format!("Warning: {}", rs.warning()));
+        } else if let Some(rule) = conflicts.first() {
+            let more = if conflicts.len() > 1 {
+                format!(" (and {} more)", conflicts.len() - 1)
+            } else {
+                "".to_string()
+            };
+            self.c.expanded_label.set_text(&format!("Conflict: {}{}", rule.message, more));
+        } else if let Some(ls) = self.list_setting() {
+            match list_value::parse_list(&self.c.dsn_value_input.text(), ls.format) {
+                Ok(items) => self.c.expanded_label.set_text(&format!("List items: {}", items.len())),
+                Err(e) => self.c.expanded_label.set_text(&format!("Invalid list: {}", e))
+            }
+        } else if RegistryValueType::EXPAND == value_type {
+            let expanded = paths::expand_env_vars(self.c.dsn_value_input.text().trim());
+            self.c.expanded_label.set_text(&format!("Expanded: {}", expanded));
+        } else if !value_type.is_string() {
+            self.c.expanded_label.set_text(&format!("Stored as: {}", value_type.label()));
+        } else {
+            self.c.expanded_label.set_text("");
+        }
+    }
+
     pub(super) fn on_bool_value_change(&mut self, _: nwg::EventData) {
         let checked = self.c.bool_value_checkbox.check_state() == nwg::CheckBoxState::Checked;
         let value = if checked {
@@ -66,6 +128,7 @@ impl SettingDialog {
             "false"
         };
         self.c.dsn_value_input.set_text(&value);
+        self.on_value_input(nwg::EventData::NoData);
     }
 
     pub(super) fn on_choose_db_file(&mut self, _: nwg::EventData) {
@@ -78,6 +141,134 @@ impl SettingDialog {
             if let Ok(file) = self.c.fs_path_chooser.get_selected_item() {
                 let fpath_st = file.to_string_lossy().to_string();
                 self.c.dsn_value_input.set_text(&fpath_st);
+                self.on_value_input(nwg::EventData::NoData);
+            }
+        }
+    }
+
+    pub(super) fn open_list_dialog(&mut self, _: nwg::EventData) {
+        let ls = match self.list_setting() {
+            Some(ls) => ls,
+            None => return
+        };
+        let args = ListDialogArgs::new(&self.c.list_notice, &self.args.setting.name, &ls, &self.c.dsn_value_input.text());
+        self.c.window.set_enabled(false);
+        self.list_dialog_join_handle = ListDialog::popup(args);
+    }
+
+    pub(super) fn await_list_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.list_notice.receive();
+        let res = self.list_dialog_join_handle.join();
+        if let Some(value) = res.value {
+            self.c.dsn_value_input.set_text(&value);
+            self.on_value_input(nwg::EventData::NoData);
+        }
+    }
+
+    pub(super) fn open_inspect_dialog(&mut self, _: nwg::EventData) {
+        let inspection = init_sql::inspect_file(self.c.dsn_value_input.text().trim());
+        let args = ReportDialogArgs::new(&self.c.inspect_notice, "Session Init SQL File", &inspection.summary(),
+            vec!(("Line", 50), ("Statement", 360), ("Warning", 220)), inspection.report_rows(), None);
+        self.c.window.set_enabled(false);
+        self.inspect_dialog_join_handle = ReportDialog::popup(args);
+    }
+
+    pub(super) fn await_inspect_dialog(&mut self, _: nwg::EventData) {
+        self.c.window.set_enabled(true);
+        self.c.inspect_notice.receive();
+        let _ = self.inspect_dialog_join_handle.join();
+    }
+
+    fn confirm_db_path(&mut self, dbpath: &str) -> Option<String> {
+        let read_only = self.args.dsn.settings.iter()
+            .find(|s| db_path::ACCESS_MODE_SETTING_NAME == s.name)
+            .map(|s| db_path::is_read_only_access_mode(&s.value))
+            .unwrap_or(false);
+        let mut check = db_path::check_database_path(dbpath, read_only);
+        if let Some(abs) = check.absolute_path.clone() {
+            let convert = ui::message_box_warning_yn(&format!(
+                "{}\r\n\r\nWould you like to convert it to the absolute path?", check.relative_warning().unwrap_or_default()));
+            if convert {
+                self.c.dsn_value_input.set_text(&abs);
+                check = db_path::check_database_path(&abs, read_only);
+            }
+        }
+        if !check.problems.is_empty() {
+            ui::message_box_error(&format!(
+                "Invalid database path: '{}'\r\n\r\n{}", check.path, check.problems.join("\r\n")));
+            return None;
+        }
+        if !check.warnings.is_empty() {
+            let proceed = ui::message_box_warning_yn(&format!(
+                "Database path: '{}' ({})\r\n\r\n{}\r\n\r\nWould you like to proceed?",
+                check.path, check.kind.label(), check.warnings.join("\r\n")));
+            if !proceed {
+                return None;
+            }
+        }
+        Some(check.path)
+    }
+
+    // Confirmation is asked only when the value is changed from a safe one,
+    // re-applying an already risky value does not ask again.
+    fn confirm_risky_value(&self, value: &str) -> bool {
+        let rs = match security::risky_setting(&self.args.setting.name) {
+            Some(rs) if rs.is_risky_value(value) => rs,
+            _ => return true
+        };
+        let already_risky = self.args.dsn.settings.iter()
+            .find(|s| s.name == self.args.setting.name)
+            .map(|s| rs.is_risky_value(&s.value))
+            .unwrap_or(false);
+        if already_risky {
+            return true;
+        }
+        ui::message_box_warning_yn(&format!(
+            "Setting: '{}' with value: '{}' weakens the security of the data source: '{}'.\r\n\r\n{}\r\n\r\n\
+            Would you like to proceed?", rs.name, rs.display_value(value), self.args.dsn.name, rs.warning()))
+    }
+
+    fn confirm_conflicts(&self, value: Option<&str>) -> bool {
+        let conflicts = consistency::check_change(&self.args.dsn, &self.args.setting.name, value);
+        if conflicts.is_empty() {
+            return true;
+        }
+        let listing: Vec<String> = conflicts.iter()
+            .map(|rule| format!("{}: {}", rule.severity.label(), rule.message))
+            .collect();
+        ui::message_box_warning_yn(&format!(
+            "Change of setting: '{}' conflicts with other settings of the data source: '{}':\r\n\r\n{}\r\n\r\n\
+            Would you like to proceed?", self.args.setting.name, self.args.dsn.name, listing.join("\r\n")))
+    }
+
+    // REG_MULTI_SZ values are edited as lines whatever the setting type is
+    fn list_setting(&self) -> Option<list_value::ListSetting> {
+        let ls = list_value::list_setting(&self.args.setting.name, &self.args.setting.input_type);
+        if RegistryValueType::MULTI != self.value_type() {
+            return ls;
+        }
+        Some(list_value::ListSetting {
+            format: list_value::ListFormat::Lines,
+            item_kind: ls.as_ref().map(|ls| ls.item_kind).unwrap_or(list_value::ListItemKind::Text),
+            known_values: ls.map(|ls| ls.known_values).unwrap_or(&[]),
+        })
+    }
+
+    // Numeric registry types cannot hold 'true'/'false' written for
+    // BOOLEAN settings, such values are re-written as strings.
+    fn value_type(&self) -> RegistryValueType {
+        let is_bool = "BOOLEAN" == self.args.setting.input_type;
+        let existing = self.args.dsn.settings.iter()
+            .find(|s| s.name == self.args.setting.name)
+            .map(|s| s.value_type.clone())
+            .filter(|vt| !(is_bool && vt.is_numeric()) && RegistryValueType::UNREADABLE != *vt);
+        match existing {
+            Some(vt) if !vt.is_string() => vt,
+            _ => if self.c.expand_checkbox.check_state() == nwg::CheckBoxState::Checked {
+                RegistryValueType::EXPAND
+            } else {
+                RegistryValueType::SZ
             }
         }
     }
@@ -104,23 +295,37 @@ impl ui::PopupDialog<SettingDialogArgs, SettingDialogResult> for SettingDialog {
         if let Some(dsn_st) = self.args.dsn.settings.iter().find(|s| s.name == st.name) {
             self.c.dsn_value_input.set_text(&dsn_st.value);
             self.c.delete_button.set_enabled(true);
+            self.c.expand_checkbox.set_enabled(dsn_st.value_type.is_string());
+            if RegistryValueType::EXPAND == dsn_st.value_type {
+                self.c.expand_checkbox.set_check_state(nwg::CheckBoxState::Checked);
+            }
         } else {
             self.c.delete_button.set_enabled(false);
         }
         if "BOOLEAN" == st.input_type {
             self.c.dsn_value_input.set_readonly(true);
             self.c.bool_value_checkbox.set_enabled(true);
+            self.c.expand_checkbox.set_enabled(false);
         } else {
-            self.c.dsn_value_input.set_readonly(false);
+            // single line input cannot edit REG_MULTI_SZ lines, the list dialog is used instead
+            self.c.dsn_value_input.set_readonly(RegistryValueType::MULTI == self.value_type());
             self.c.bool_value_checkbox.set_enabled(false);
         }
+        self.c.inspect_button.set_enabled(registry::SESSION_INIT_SQL_FILE_SETTING_NAME == st.name);
+        self.c.list_button.set_enabled(self.list_setting().is_some());
         if registry::DATABASE_SETTING_NAME == st.name || registry::SESSION_INIT_SQL_FILE_SETTING_NAME == st.name {
             self.c.fs_path_button.set_enabled(true);
             self.c.delete_button.set_enabled(false);
         } else {
             self.c.fs_path_button.set_enabled(false);
         }
-        let desc_text = ui::wrap_label_text(&self.args.setting.description, 65);
+        self.on_value_input(nwg::EventData::NoData);
+        let setting = &self.args.setting;
+        let description = match setting.risk_level.and_then(|_| security::risky_setting(&setting.name)) {
+            Some(rs) => format!("{}. {}.", setting.description.trim_end_matches('.'), rs.warning()),
+            None => self.args.setting.description.clone()
+        };
+        let desc_text = ui::wrap_label_text(&description, 65);
         self.c.description_label.set_text(&desc_text);
         self.result = SettingDialogResult::failure();
         ui::shake_window(&self.c.window);
@@ -47,6 +47,37 @@ impl ui::Events<SettingDialogControls> for SettingDialogEvents {
             .handler(SettingDialog::on_bool_value_change)
             .build(&mut self.events)?;
 
+        ui::event_builder()
+            .control(&c.dsn_value_input)
+            .event(nwg::Event::OnTextInput)
+            .handler(SettingDialog::on_value_input)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.expand_checkbox)
+            .event(nwg::Event::OnButtonClick)
+            .handler(SettingDialog::on_value_input)
+            .build(&mut self.events)?;
+
+        ui::event_builder()
+            .control(&c.list_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(SettingDialog::open_list_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.list_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(SettingDialog::await_list_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.inspect_button)
+            .event(nwg::Event::OnButtonClick)
+            .handler(SettingDialog::open_inspect_dialog)
+            .build(&mut self.events)?;
+        ui::event_builder()
+            .control(&c.inspect_notice.notice)
+            .event(nwg::Event::OnNotice)
+            .handler(SettingDialog::await_inspect_dialog)
+            .build(&mut self.events)?;
         ui::event_builder()
             .control(&c.apply_button)
             .event(nwg::Event::OnButtonClick)
@@ -23,6 +23,8 @@ pub(super) struct SettingDialogLayout {
     default_value_layout: nwg::FlexboxLayout,
     dsn_value_layout: nwg::FlexboxLayout,
     bool_value_layout: nwg::FlexboxLayout,
+    expand_layout: nwg::FlexboxLayout,
+    expanded_layout: nwg::FlexboxLayout,
     description_layout: nwg::FlexboxLayout,
     buttons_layout: nwg::FlexboxLayout,
 }
@@ -100,6 +102,36 @@ impl ui::Layout<SettingDialogControls> for SettingDialogLayout {
                 .build())
             .build_partial(&self.bool_value_layout)?;
 
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.expand_checkbox)
+            .child_size(ui::size_builder()
+                .width_auto()
+                .height_input_form_row()
+                .build())
+            .child_flex_grow(1.0)
+            .child_margin(ui::margin_builder()
+                .start_no_label_normal()
+                .build())
+            .build_partial(&self.expand_layout)?;
+
+        nwg::FlexboxLayout::builder()
+            .parent(&c.window)
+            .flex_direction(ui::FlexDirection::Row)
+            .auto_spacing(None)
+            .child(&c.expanded_label)
+            .child_size(ui::size_builder()
+                .width_auto()
+                .height_input_form_row()
+                .build())
+            .child_flex_grow(1.0)
+            .child_margin(ui::margin_builder()
+                .start_no_label_normal()
+                .build())
+            .build_partial(&self.expanded_layout)?;
+
         nwg::FlexboxLayout::builder()
             .parent(&c.window)
             .flex_direction(ui::FlexDirection::Row)
@@ -120,11 +152,27 @@ impl ui::Layout<SettingDialogControls> for SettingDialogLayout {
             .flex_direction(ui::FlexDirection::Row)
             .justify_content(ui::JustifyContent::FlexEnd)
             .auto_spacing(None)
+            .child(&c.list_button)
+            .child_size(ui::size_builder()
+                .width_button_normal()
+                .height_button()
+                .build())
+            .child(&c.inspect_button)
+            .child_size(ui::size_builder()
+                .width_button_wide()
+                .height_button()
+                .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
             .child(&c.apply_button)
             .child_size(ui::size_builder()
                 .width_button_wide()
                 .height_button()
                 .build())
+            .child_margin(ui::margin_builder()
+                .start_pt(5)
+                .build())
             .child(&c.delete_button)
             .child_size(ui::size_builder()
                 .width_button_normal()
@@ -150,6 +198,8 @@ impl ui::Layout<SettingDialogControls> for SettingDialogLayout {
             .child_layout(&self.default_value_layout)
             .child_layout(&self.dsn_value_layout)
             .child_layout(&self.bool_value_layout)
+            .child_layout(&self.expand_layout)
+            .child_layout(&self.expanded_layout)
             .child_layout(&self.description_layout)
             .child_flex_grow(1.0)
             .child_layout(&self.buttons_layout)
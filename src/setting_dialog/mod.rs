@@ -37,7 +37,21 @@ use ui::PopupJoinHandle;
 
 use common::DuckDbSetting;
 use common::RegistryDsn;
+use common::RegistryValueType;
+use common::consistency;
+use common::db_path;
+use common::init_sql;
+use common::list_value;
+use common::paths;
+use common::policy;
 use common::registry;
+use common::security;
+use list_dialog::ListDialog;
+use list_dialog::ListDialogArgs;
+use list_dialog::ListDialogResult;
+use report_dialog::ReportDialog;
+use report_dialog::ReportDialogArgs;
+use report_dialog::ReportDialogResult;
 
 pub(super) use args::SettingDialogArgs;
 pub(super) use dialog::SettingDialog;